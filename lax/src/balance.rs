@@ -0,0 +1,63 @@
+//! Balancing of general matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgebal | dgebal | cgebal | zgebal |
+//!
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{One, Zero};
+
+/// Balance a general matrix by a diagonal similarity transformation
+///
+/// `a` is overwritten by $D^{-1} A D$ whose rows and columns are close in
+/// norm, and the diagonal of $D$ is returned. The scale factors are powers
+/// of the radix so that the transformation is exact. Only scaling is done
+/// ([Balance::Scale]), i.e. the rows and columns are not permuted.
+pub trait BalanceImpl: Scalar {
+    fn balance(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self::Real>>;
+}
+
+macro_rules! impl_balance {
+    ($s:ty, $bal:path) => {
+        impl BalanceImpl for $s {
+            fn balance(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self::Real>> {
+                let (n, _) = l.size();
+                let mut scale = vec![Self::Real::zero(); n as usize];
+                let mut ilo = 0;
+                let mut ihi = 0;
+                let mut info = 0;
+                unsafe {
+                    $bal(
+                        Balance::Scale.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        &mut ilo,
+                        &mut ihi,
+                        scale.as_mut_ptr(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                // C-layout matrix is regarded as the transpose in Fortran layout,
+                // and D^{-1} A^T D = (D A D^{-1})^T
+                if let MatrixLayout::C { .. } = l {
+                    for s in &mut scale {
+                        *s = Self::Real::one() / *s;
+                    }
+                }
+                Ok(scale)
+            }
+        }
+    };
+}
+
+impl_balance!(c64, lapack_sys::zgebal_);
+impl_balance!(c32, lapack_sys::cgebal_);
+impl_balance!(f64, lapack_sys::dgebal_);
+impl_balance!(f32, lapack_sys::sgebal_);
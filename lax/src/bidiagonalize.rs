@@ -0,0 +1,188 @@
+//! Reduce general matrix to real bidiagonal form
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgebrd | dgebrd | cgebrd | zgebrd |
+//! | sorgbr | dorgbr | cungbr | zungbr |
+//!
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Result of [BidiagonalizeImpl::bidiagonalize]
+#[derive(Debug, Clone)]
+pub struct BidiagonalOwned<T: Scalar> {
+    /// Diagonal elements of the bidiagonal matrix
+    pub d: Vec<T::Real>,
+    /// Off-diagonal elements of the bidiagonal matrix
+    pub e: Vec<T::Real>,
+    /// Scalar factors of the elementary reflectors representing `Q`
+    pub tauq: Vec<T>,
+    /// Scalar factors of the elementary reflectors representing `P`
+    pub taup: Vec<T>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reduce a general $m \times n$ matrix into real bidiagonal form $A = Q B P^H$
+///
+/// $B$ is upper bidiagonal if $m \ge n$, and lower bidiagonal otherwise.
+/// A row-major matrix is transposed into column-major order in place, i.e.
+/// after [BidiagonalizeImpl::bidiagonalize] `a` always holds the
+/// Householder reflectors in `MatrixLayout::F { col: n, lda: m }`.
+/// With $k = \min(m, n)$, [BidiagonalizeImpl::q_bidiagonal] and
+/// [BidiagonalizeImpl::pt_bidiagonal] generate the $m \times k$ matrix $Q$
+/// and the $k \times n$ matrix $P^H$ from them in column-major order.
+pub trait BidiagonalizeImpl: Scalar {
+    fn bidiagonalize(l: MatrixLayout, a: &mut [Self]) -> Result<BidiagonalOwned<Self>>;
+    fn q_bidiagonal(l: MatrixLayout, a: &[Self], tauq: &[Self]) -> Result<Vec<Self>>;
+    fn pt_bidiagonal(l: MatrixLayout, a: &[Self], taup: &[Self]) -> Result<Vec<Self>>;
+}
+
+macro_rules! impl_bidiagonalize {
+    ($s:ty, $brd:path, $gbr:path) => {
+        impl BidiagonalizeImpl for $s {
+            fn bidiagonalize(l: MatrixLayout, a: &mut [Self]) -> Result<BidiagonalOwned<Self>> {
+                let (l, a_t) = ensure_fortran(l, a);
+                if let Some(a_t) = a_t {
+                    a.copy_from_slice(&a_t);
+                }
+                let (m, n) = l.size();
+                let k = m.min(n) as usize;
+                let mut d = vec_uninit(k);
+                let mut e = vec_uninit(k.saturating_sub(1));
+                let mut tauq = vec_uninit(k);
+                let mut taup = vec_uninit(k);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $brd(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        AsPtr::as_mut_ptr(&mut tauq),
+                        AsPtr::as_mut_ptr(&mut taup),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $brd(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        AsPtr::as_mut_ptr(&mut tauq),
+                        AsPtr::as_mut_ptr(&mut taup),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork.to_i32().unwrap(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe {
+                    BidiagonalOwned {
+                        d: d.assume_init(),
+                        e: e.assume_init(),
+                        tauq: tauq.assume_init(),
+                        taup: taup.assume_init(),
+                    }
+                })
+            }
+
+            fn q_bidiagonal(l: MatrixLayout, a: &[Self], tauq: &[Self]) -> Result<Vec<Self>> {
+                let (m, n) = l.size();
+                let k = m.min(n);
+                // The first k columns hold the reflectors
+                let mut q = a[..(m * k) as usize].to_vec();
+                Self::gbr(b'Q', m, k, n, &mut q, m, tauq)?;
+                Ok(q)
+            }
+
+            fn pt_bidiagonal(l: MatrixLayout, a: &[Self], taup: &[Self]) -> Result<Vec<Self>> {
+                let (m, n) = l.size();
+                let k = m.min(n);
+                // The first k rows hold the reflectors
+                let mut pt = Vec::with_capacity((k * n) as usize);
+                for col in a.chunks(m.max(1) as usize) {
+                    pt.extend_from_slice(&col[..k as usize]);
+                }
+                Self::gbr(b'P', k, n, m, &mut pt, k, taup)?;
+                Ok(pt)
+            }
+        }
+
+        impl GenerateBidiagonal for $s {
+            fn gbr(
+                vect: u8,
+                m: i32,
+                n: i32,
+                k: i32,
+                a: &mut [Self],
+                lda: i32,
+                tau: &[Self],
+            ) -> Result<()> {
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gbr(
+                        &(vect as i8),
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_mut_ptr(a),
+                        &lda.max(1),
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gbr(
+                        &(vect as i8),
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_mut_ptr(a),
+                        &lda.max(1),
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork.to_i32().unwrap(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()
+            }
+        }
+    };
+}
+
+/// Wrapper of `*orgbr`/`*ungbr`
+trait GenerateBidiagonal: Sized {
+    fn gbr(vect: u8, m: i32, n: i32, k: i32, a: &mut [Self], lda: i32, tau: &[Self]) -> Result<()>;
+}
+
+impl_bidiagonalize!(c64, lapack_sys::zgebrd_, lapack_sys::zungbr_);
+impl_bidiagonalize!(c32, lapack_sys::cgebrd_, lapack_sys::cungbr_);
+impl_bidiagonalize!(f64, lapack_sys::dgebrd_, lapack_sys::dorgbr_);
+impl_bidiagonalize!(f32, lapack_sys::sgebrd_, lapack_sys::sorgbr_);
@@ -43,6 +43,79 @@ impl_cholesky_!(c32, lapack_sys::cpotrf_);
 impl_cholesky_!(f64, lapack_sys::dpotrf_);
 impl_cholesky_!(f32, lapack_sys::spotrf_);
 
+/// Compute Cholesky decomposition with complete pivoting according to [UPLO]
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spstrf | dpstrf | cpstrf | zpstrf |
+///
+/// This computes `P^T A P = U^H U` or `P^T A P = L L^H` of a positive
+/// semidefinite matrix, and returns the pivot `piv` such that
+/// `P[piv[k] - 1, k] = 1` and the computed rank of `A`.
+/// The factorization stops at the rank, i.e. the trailing block of the
+/// factor is not referenced, and a rank deficient matrix is not an error.
+/// The diagonal elements below `tol` are regarded as zero,
+/// and `n * eps * max_k A[k, k]` is used if `tol` is negative.
+///
+pub trait CholeskyPivotImpl: Scalar {
+    fn cholesky_pivot(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        tol: Self::Real,
+    ) -> Result<(Pivot, i32)>;
+}
+
+macro_rules! impl_cholesky_pivot {
+    ($s:ty, $pstrf:path) => {
+        impl CholeskyPivotImpl for $s {
+            fn cholesky_pivot(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                tol: Self::Real,
+            ) -> Result<(Pivot, i32)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut piv = vec_uninit(n as usize);
+                let mut rank = 0;
+                let mut work: Vec<MaybeUninit<Self::Real>> = vec_uninit(2 * n as usize);
+                let mut info = 0;
+                unsafe {
+                    $pstrf(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut piv),
+                        &mut rank,
+                        &tol,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                // Positive `info` only means that `A` is rank deficient
+                if info < 0 {
+                    info.as_lapack_result()?;
+                }
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                Ok((unsafe { piv.assume_init() }, rank))
+            }
+        }
+    };
+}
+impl_cholesky_pivot!(c64, lapack_sys::zpstrf_);
+impl_cholesky_pivot!(c32, lapack_sys::cpstrf_);
+impl_cholesky_pivot!(f64, lapack_sys::dpstrf_);
+impl_cholesky_pivot!(f32, lapack_sys::spstrf_);
+
 /// Compute inverse matrix using Cholesky factroization result
 ///
 /// LAPACK correspondance
@@ -91,6 +164,10 @@ impl_inv_cholesky!(f32, lapack_sys::spotri_);
 /// |:-------|:-------|:-------|:-------|
 /// | spotrs | dpotrs | cpotrs | zpotrs |
 ///
+/// `b` is a column-major `n x nrhs` matrix, i.e. `nrhs` right hand sides
+/// are solved at once, where `nrhs = b.len() / n`. This panics if `b.len()`
+/// is not a multiple of `n`.
+///
 pub trait SolveCholeskyImpl: Scalar {
     fn solve_cholesky(l: MatrixLayout, uplo: UPLO, a: &[Self], b: &mut [Self]) -> Result<()>;
 }
@@ -105,7 +182,15 @@ macro_rules! impl_solve_cholesky {
                 b: &mut [Self],
             ) -> Result<()> {
                 let (n, _) = l.size();
-                let nrhs = 1;
+                if n == 0 {
+                    return Ok(());
+                }
+                assert_eq!(
+                    b.len() % n as usize,
+                    0,
+                    "The length of `b` must be a multiple of the size of `a`"
+                );
+                let nrhs = b.len() as i32 / n;
                 let mut info = 0;
                 if matches!(l, MatrixLayout::C { .. }) {
                     uplo = uplo.t();
@@ -0,0 +1,111 @@
+//! Factorize positive-definite symmetric/Hermitian band matrices using Cholesky algorithm
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | spbtrf | dpbtrf | cpbtrf | zpbtrf |
+//! | spbtrs | dpbtrs | cpbtrs | zpbtrs |
+//!
+
+use crate::{error::*, *};
+use cauchy::*;
+
+/// Compute Cholesky decomposition of a band matrix according to [UPLO]
+///
+/// `ab` is the `(kd + 1) x n` column-major band storage of the `uplo`
+/// triangle, i.e. `ab[kd + i - j, j] = a[i, j]` for `UPLO::Upper` and
+/// `ab[i - j, j] = a[i, j]` for `UPLO::Lower`, which is overwritten by the
+/// triangular factor in the same storage.
+pub trait CholeskyBandedImpl: Scalar {
+    fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_cholesky_banded {
+    ($s:ty, $trf:path) => {
+        impl CholeskyBandedImpl for $s {
+            fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()> {
+                let ldab = kd + 1;
+                if ab.len() != (ldab * n) as usize {
+                    return Err(Error::InvalidShape);
+                }
+                let mut info = 0;
+                unsafe {
+                    $trf(
+                        uplo.as_ptr(),
+                        &n,
+                        &kd,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_cholesky_banded!(c64, lapack_sys::zpbtrf_);
+impl_cholesky_banded!(c32, lapack_sys::cpbtrf_);
+impl_cholesky_banded!(f64, lapack_sys::dpbtrf_);
+impl_cholesky_banded!(f32, lapack_sys::spbtrf_);
+
+/// Solve linear equations using the Cholesky decomposition of a band matrix
+///
+/// `ab` is the factor by [CholeskyBandedImpl::cholesky_banded], and `b` is the
+/// column-major `n x nrhs` right hand sides overwritten by the solution.
+pub trait SolveCholeskyBandedImpl: Scalar {
+    fn solve_cholesky_banded(
+        uplo: UPLO,
+        n: i32,
+        kd: i32,
+        ab: &[Self],
+        nrhs: i32,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_solve_cholesky_banded {
+    ($s:ty, $trs:path) => {
+        impl SolveCholeskyBandedImpl for $s {
+            fn solve_cholesky_banded(
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &[Self],
+                nrhs: i32,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let ldab = kd + 1;
+                if ab.len() != (ldab * n) as usize || b.len() != (n * nrhs) as usize {
+                    return Err(Error::InvalidShape);
+                }
+                if n == 0 || nrhs == 0 {
+                    return Ok(());
+                }
+                let mut info = 0;
+                unsafe {
+                    $trs(
+                        uplo.as_ptr(),
+                        &n,
+                        &kd,
+                        &nrhs,
+                        AsPtr::as_ptr(ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_solve_cholesky_banded!(c64, lapack_sys::zpbtrs_);
+impl_solve_cholesky_banded!(c32, lapack_sys::cpbtrs_);
+impl_solve_cholesky_banded!(f64, lapack_sys::dpbtrs_);
+impl_solve_cholesky_banded!(f32, lapack_sys::spbtrs_);
@@ -404,7 +404,7 @@ impl_eig_work_r!(f64, lapack_sys::dgeev_);
 ///
 /// In the C-layout case, we need the conjugates of the left
 /// eigenvectors, so the signs should be reversed.
-fn reconstruct_eigenvectors<T: Scalar>(
+pub(crate) fn reconstruct_eigenvectors<T: Scalar>(
     take_hermite_conjugate: bool,
     eig_im: &[T],
     vr: &[T],
@@ -441,7 +441,11 @@ fn reconstruct_eigenvectors<T: Scalar>(
 }
 
 /// Create complex eigenvalues from real and imaginary parts.
-fn reconstruct_eigs<T: Scalar>(re: &[T], im: &[T], eigs: &mut [MaybeUninit<T::Complex>]) {
+pub(crate) fn reconstruct_eigs<T: Scalar>(
+    re: &[T],
+    im: &[T],
+    eigs: &mut [MaybeUninit<T::Complex>],
+) {
     let n = eigs.len();
     assert_eq!(re.len(), n);
     assert_eq!(im.len(), n);
@@ -0,0 +1,434 @@
+//! Eigenvalue problem for general matrices with condition numbers
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgeevx | dgeevx | cgeevx | zgeevx |
+//!
+
+use crate::eig::{reconstruct_eigenvectors, reconstruct_eigs};
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Eigenvalue problem for general matrix with the reciprocal condition
+/// numbers of the eigenvalues and right eigenvectors
+///
+/// The matrix is balanced only by permuting ([Balance::Permute]) before the
+/// computation, since scaling would change the condition numbers.
+///
+/// Since the condition numbers require both left and right eigenvectors in
+/// LAPACK, a row-major matrix is transposed into a column-major one before
+/// calling LAPACK.
+#[non_exhaustive]
+pub struct EigCondWork<T: Scalar> {
+    /// Layout of the input matrix
+    pub layout: MatrixLayout,
+    /// Problem size
+    pub n: i32,
+
+    /// Eigenvalues
+    pub eigs: Vec<MaybeUninit<T::Complex>>,
+    /// Real part of eigenvalues used in real routines
+    pub eigs_re: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Imaginary part of eigenvalues used in real routines
+    pub eigs_im: Option<Vec<MaybeUninit<T::Real>>>,
+
+    /// Left eigenvectors, only used internally
+    pub vl: Vec<MaybeUninit<T>>,
+    /// Right eigenvectors
+    pub vr: Vec<MaybeUninit<T>>,
+    /// Right eigenvectors reconstructed into complex in real routines
+    pub vc_r: Option<Vec<MaybeUninit<T::Complex>>>,
+
+    /// Permutation of the balancing
+    pub scale: Vec<MaybeUninit<T::Real>>,
+    /// Reciprocal condition numbers of the eigenvalues
+    pub rconde: Vec<MaybeUninit<T::Real>>,
+    /// Reciprocal condition numbers of the right eigenvectors
+    pub rcondv: Vec<MaybeUninit<T::Real>>,
+
+    /// Working memory
+    pub work: Vec<MaybeUninit<T>>,
+    /// Working memory with `T::Real` used in complex routines
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Working memory with `i32` used in real routines
+    pub iwork: Option<Vec<MaybeUninit<i32>>>,
+}
+
+/// Owned result of [EigCondWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigCondOwned<T: Scalar> {
+    /// Eigenvalues
+    pub eigs: Vec<T::Complex>,
+    /// Right eigenvectors normalized to unit 2-norm, in column-major order
+    pub vr: Vec<T::Complex>,
+    /// Reciprocal condition numbers of the eigenvalues
+    pub rconde: Vec<T::Real>,
+    /// Reciprocal condition numbers of the right eigenvectors
+    pub rcondv: Vec<T::Real>,
+    /// `ILO` of the balancing, 1-based as in LAPACK
+    pub ilo: i32,
+    /// `IHI` of the balancing, 1-based as in LAPACK
+    pub ihi: i32,
+    /// Permutation of the balancing
+    pub scale: Vec<T::Real>,
+    /// One-norm of the matrix
+    pub abnrm: T::Real,
+}
+
+/// Reference result of [EigCondWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigCondRef<'work, T: Scalar> {
+    /// Eigenvalues
+    pub eigs: &'work [T::Complex],
+    /// Right eigenvectors normalized to unit 2-norm, in column-major order
+    pub vr: &'work [T::Complex],
+    /// Reciprocal condition numbers of the eigenvalues
+    pub rconde: &'work [T::Real],
+    /// Reciprocal condition numbers of the right eigenvectors
+    pub rcondv: &'work [T::Real],
+    /// `ILO` of the balancing, 1-based as in LAPACK
+    pub ilo: i32,
+    /// `IHI` of the balancing, 1-based as in LAPACK
+    pub ihi: i32,
+    /// Permutation of the balancing
+    pub scale: &'work [T::Real],
+    /// One-norm of the matrix
+    pub abnrm: T::Real,
+}
+
+impl<T> EigCondWork<T>
+where
+    T: Scalar,
+    EigCondWork<T>: EigCondWorkImpl<Elem = T>,
+{
+    /// Create new working memory for eigenvalues compution.
+    pub fn new(l: MatrixLayout) -> Result<Self> {
+        EigCondWorkImpl::new(l)
+    }
+
+    /// Compute eigenvalues, vectors, and condition numbers on this working memory.
+    pub fn calc(&mut self, a: &mut [T]) -> Result<EigCondRef<'_, T>> {
+        EigCondWorkImpl::calc(self, a)
+    }
+
+    /// Compute eigenvalues, vectors, and condition numbers by consuming this working memory.
+    pub fn eval(self, a: &mut [T]) -> Result<EigCondOwned<T>> {
+        EigCondWorkImpl::eval(self, a)
+    }
+}
+
+/// Helper trait for implementing [EigCondWork] methods
+pub trait EigCondWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(l: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(&'work mut self, a: &mut [Self::Elem]) -> Result<EigCondRef<'work, Self::Elem>>;
+    fn eval(self, a: &mut [Self::Elem]) -> Result<EigCondOwned<Self::Elem>>;
+}
+
+macro_rules! impl_eig_cond_work_c {
+    ($c:ty, $evx:path) => {
+        impl EigCondWorkImpl for EigCondWork<$c> {
+            type Elem = $c;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let (n, _) = layout.size();
+                let mut eigs = vec_uninit(n as usize);
+                let mut vl = vec_uninit((n * n) as usize);
+                let mut vr = vec_uninit((n * n) as usize);
+                let mut scale = vec_uninit(n as usize);
+                let mut rconde = vec_uninit(n as usize);
+                let mut rcondv = vec_uninit(n as usize);
+                let mut rwork = vec_uninit(2 * n as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut ilo = 0;
+                let mut ihi = 0;
+                let mut abnrm = 0.0;
+                let mut work_size = [<$c>::zero()];
+                unsafe {
+                    $evx(
+                        Balance::Permute.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &(b'B' as i8),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut vl),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut vr),
+                        &n,
+                        &mut ilo,
+                        &mut ihi,
+                        AsPtr::as_mut_ptr(&mut scale),
+                        &mut abnrm,
+                        AsPtr::as_mut_ptr(&mut rconde),
+                        AsPtr::as_mut_ptr(&mut rcondv),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work: Vec<MaybeUninit<$c>> = vec_uninit(lwork);
+                Ok(Self {
+                    layout,
+                    n,
+                    eigs,
+                    eigs_re: None,
+                    eigs_im: None,
+                    vl,
+                    vr,
+                    vc_r: None,
+                    scale,
+                    rconde,
+                    rcondv,
+                    work,
+                    rwork: Some(rwork),
+                    iwork: None,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+            ) -> Result<EigCondRef<'work, Self::Elem>> {
+                // Transpose if a is C-continuous
+                let (_, mut a_t) = ensure_fortran(self.layout, a);
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut ilo = 0;
+                let mut ihi = 0;
+                let mut abnrm = 0.0;
+                unsafe {
+                    $evx(
+                        Balance::Permute.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &(b'B' as i8),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(a)),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(&mut self.vl),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.vr),
+                        &self.n,
+                        &mut ilo,
+                        &mut ihi,
+                        AsPtr::as_mut_ptr(&mut self.scale),
+                        &mut abnrm,
+                        AsPtr::as_mut_ptr(&mut self.rconde),
+                        AsPtr::as_mut_ptr(&mut self.rcondv),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(EigCondRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    vr: unsafe { self.vr.slice_assume_init_ref() },
+                    rconde: unsafe { self.rconde.slice_assume_init_ref() },
+                    rcondv: unsafe { self.rcondv.slice_assume_init_ref() },
+                    ilo,
+                    ihi,
+                    scale: unsafe { self.scale.slice_assume_init_ref() },
+                    abnrm,
+                })
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<EigCondOwned<Self::Elem>> {
+                let EigCondRef {
+                    ilo, ihi, abnrm, ..
+                } = self.calc(a)?;
+                Ok(EigCondOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    vr: unsafe { self.vr.assume_init() },
+                    rconde: unsafe { self.rconde.assume_init() },
+                    rcondv: unsafe { self.rcondv.assume_init() },
+                    ilo,
+                    ihi,
+                    scale: unsafe { self.scale.assume_init() },
+                    abnrm,
+                })
+            }
+        }
+    };
+}
+
+impl_eig_cond_work_c!(c32, lapack_sys::cgeevx_);
+impl_eig_cond_work_c!(c64, lapack_sys::zgeevx_);
+
+macro_rules! impl_eig_cond_work_r {
+    ($f:ty, $evx:path) => {
+        impl EigCondWorkImpl for EigCondWork<$f> {
+            type Elem = $f;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let (n, _) = layout.size();
+                let mut eigs_re = vec_uninit(n as usize);
+                let mut eigs_im = vec_uninit(n as usize);
+                let mut vl = vec_uninit((n * n) as usize);
+                let mut vr = vec_uninit((n * n) as usize);
+                let mut scale = vec_uninit(n as usize);
+                let mut rconde = vec_uninit(n as usize);
+                let mut rcondv = vec_uninit(n as usize);
+                let mut iwork = vec_uninit(std::cmp::max(1, 2 * n - 2) as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut ilo = 0;
+                let mut ihi = 0;
+                let mut abnrm = 0.0;
+                let mut work_size: [$f; 1] = [0.0];
+                unsafe {
+                    $evx(
+                        Balance::Permute.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &(b'B' as i8),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs_re),
+                        AsPtr::as_mut_ptr(&mut eigs_im),
+                        AsPtr::as_mut_ptr(&mut vl),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut vr),
+                        &n,
+                        &mut ilo,
+                        &mut ihi,
+                        AsPtr::as_mut_ptr(&mut scale),
+                        &mut abnrm,
+                        AsPtr::as_mut_ptr(&mut rconde),
+                        AsPtr::as_mut_ptr(&mut rcondv),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(Self {
+                    layout,
+                    n,
+                    eigs: vec_uninit(n as usize),
+                    eigs_re: Some(eigs_re),
+                    eigs_im: Some(eigs_im),
+                    vl,
+                    vr,
+                    vc_r: Some(vec_uninit((n * n) as usize)),
+                    scale,
+                    rconde,
+                    rcondv,
+                    work,
+                    rwork: None,
+                    iwork: Some(iwork),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+            ) -> Result<EigCondRef<'work, Self::Elem>> {
+                // Transpose if a is C-continuous
+                let (_, mut a_t) = ensure_fortran(self.layout, a);
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut ilo = 0;
+                let mut ihi = 0;
+                let mut abnrm = 0.0;
+                unsafe {
+                    $evx(
+                        Balance::Permute.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &(b'B' as i8),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(a)),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.eigs_re.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.eigs_im.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(&mut self.vl),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.vr),
+                        &self.n,
+                        &mut ilo,
+                        &mut ihi,
+                        AsPtr::as_mut_ptr(&mut self.scale),
+                        &mut abnrm,
+                        AsPtr::as_mut_ptr(&mut self.rconde),
+                        AsPtr::as_mut_ptr(&mut self.rcondv),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.iwork.as_mut().unwrap()),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let eigs_re = self
+                    .eigs_re
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                let eigs_im = self
+                    .eigs_im
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                reconstruct_eigs(eigs_re, eigs_im, &mut self.eigs);
+                let vr = unsafe { self.vr.slice_assume_init_ref() };
+                reconstruct_eigenvectors(false, eigs_im, vr, self.vc_r.as_mut().unwrap());
+
+                Ok(EigCondRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    vr: self
+                        .vc_r
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() })
+                        .unwrap(),
+                    rconde: unsafe { self.rconde.slice_assume_init_ref() },
+                    rcondv: unsafe { self.rcondv.slice_assume_init_ref() },
+                    ilo,
+                    ihi,
+                    scale: unsafe { self.scale.slice_assume_init_ref() },
+                    abnrm,
+                })
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<EigCondOwned<Self::Elem>> {
+                let EigCondRef {
+                    ilo, ihi, abnrm, ..
+                } = self.calc(a)?;
+                Ok(EigCondOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    vr: self.vc_r.map(|v| unsafe { v.assume_init() }).unwrap(),
+                    rconde: unsafe { self.rconde.assume_init() },
+                    rcondv: unsafe { self.rcondv.assume_init() },
+                    ilo,
+                    ihi,
+                    scale: unsafe { self.scale.assume_init() },
+                    abnrm,
+                })
+            }
+        }
+    };
+}
+
+impl_eig_cond_work_r!(f32, lapack_sys::sgeevx_);
+impl_eig_cond_work_r!(f64, lapack_sys::dgeevx_);
@@ -0,0 +1,486 @@
+//! Generalized eigenvalue problem for general matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32   | f64   | c32   | c64   |
+//! |:------|:------|:------|:------|
+//! | sggev | dggev | cggev | zggev |
+//!
+
+use crate::eig::{reconstruct_eigenvectors, reconstruct_eigs};
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Generalized eigenvalue $\lambda = \alpha / \beta$ of a matrix pencil
+#[cfg_attr(doc, katexit::katexit)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeneralizedEigenvalue<T: Scalar> {
+    /// Finite eigenvalue $\alpha / \beta$ with the pair $(\alpha, \beta)$
+    Finite(T, (T, T)),
+    /// Infinite or indeterminate eigenvalue with the pair $(\alpha, \beta)$
+    /// where $\beta$ vanishes
+    Indeterminate((T, T)),
+}
+
+impl<T: Scalar> GeneralizedEigenvalue<T> {
+    /// Classify the pair $(\alpha, \beta)$ as [Indeterminate](Self::Indeterminate)
+    /// if $|\beta|$ is not larger than `thresh_opt` (zero if `None`)
+    pub fn new(alpha: T, beta: T, thresh_opt: Option<T::Real>) -> Self {
+        let thresh = thresh_opt.unwrap_or_else(T::Real::zero);
+        if beta.abs() > thresh {
+            GeneralizedEigenvalue::Finite(alpha / beta, (alpha, beta))
+        } else {
+            GeneralizedEigenvalue::Indeterminate((alpha, beta))
+        }
+    }
+
+    /// $\alpha$ of the pair
+    pub fn alpha(&self) -> T {
+        match self {
+            GeneralizedEigenvalue::Finite(_, (alpha, _)) => *alpha,
+            GeneralizedEigenvalue::Indeterminate((alpha, _)) => *alpha,
+        }
+    }
+
+    /// $\beta$ of the pair
+    pub fn beta(&self) -> T {
+        match self {
+            GeneralizedEigenvalue::Finite(_, (_, beta)) => *beta,
+            GeneralizedEigenvalue::Indeterminate((_, beta)) => *beta,
+        }
+    }
+
+    /// Eigenvalue $\alpha / \beta$ if it is finite
+    pub fn finite(&self) -> Option<T> {
+        match self {
+            GeneralizedEigenvalue::Finite(e, _) => Some(*e),
+            GeneralizedEigenvalue::Indeterminate(_) => None,
+        }
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized eigenvalue problem $ A v = \lambda B v $ for general matrices
+///
+/// The eigenvalues are returned as pairs $(\alpha, \beta)$ with
+/// $\lambda = \alpha / \beta$ since $\beta$ may vanish for a singular $B$.
+/// As in [EigWork], a row-major matrix is regarded as the transpose of a
+/// column-major matrix, and the right eigenvectors are computed as the
+/// conjugates of the left eigenvectors of the transposed pencil.
+#[non_exhaustive]
+pub struct EigGeneralizedWork<T: Scalar> {
+    /// Problem size
+    pub n: i32,
+    /// Compute right eigenvectors or not
+    pub jobvr: JobEv,
+    /// Compute left eigenvectors or not
+    pub jobvl: JobEv,
+
+    /// Numerators of eigenvalues
+    pub alpha: Vec<MaybeUninit<T::Complex>>,
+    /// Real part of numerators used in real routines
+    pub alpha_re: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Imaginary part of numerators used in real routines
+    pub alpha_im: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Denominators of eigenvalues
+    pub beta: Vec<MaybeUninit<T::Complex>>,
+    /// Denominators used in real routines
+    pub beta_re: Option<Vec<MaybeUninit<T::Real>>>,
+
+    /// Left eigenvectors
+    pub vc_l: Option<Vec<MaybeUninit<T::Complex>>>,
+    /// Left eigenvectors used in real routines
+    pub vr_l: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Right eigenvectors
+    pub vc_r: Option<Vec<MaybeUninit<T::Complex>>>,
+    /// Right eigenvectors used in real routines
+    pub vr_r: Option<Vec<MaybeUninit<T::Real>>>,
+
+    /// Working memory
+    pub work: Vec<MaybeUninit<T>>,
+    /// Working memory with `T::Real`
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+impl<T> EigGeneralizedWork<T>
+where
+    T: Scalar,
+    EigGeneralizedWork<T>: EigGeneralizedWorkImpl<Elem = T>,
+{
+    /// Create new working memory for generalized eigenvalues compution.
+    pub fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+        EigGeneralizedWorkImpl::new(calc_v, l)
+    }
+
+    /// Compute eigenvalues and vectors on this working memory.
+    pub fn calc(&mut self, a: &mut [T], b: &mut [T]) -> Result<EigGeneralizedRef<'_, T>> {
+        EigGeneralizedWorkImpl::calc(self, a, b)
+    }
+
+    /// Compute eigenvalues and vectors by consuming this working memory.
+    pub fn eval(self, a: &mut [T], b: &mut [T]) -> Result<EigGeneralizedOwned<T>> {
+        EigGeneralizedWorkImpl::eval(self, a, b)
+    }
+}
+
+/// Owned result of generalized eigenvalue problem by [EigGeneralizedWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigGeneralizedOwned<T: Scalar> {
+    /// Numerators of eigenvalues
+    pub alpha: Vec<T::Complex>,
+    /// Denominators of eigenvalues
+    pub beta: Vec<T::Complex>,
+    /// Right eigenvectors
+    pub vr: Option<Vec<T::Complex>>,
+    /// Left eigenvectors
+    pub vl: Option<Vec<T::Complex>>,
+}
+
+impl<T: Scalar> EigGeneralizedOwned<T> {
+    /// Eigenvalues classified by [GeneralizedEigenvalue::new]
+    pub fn eigs(&self, thresh_opt: Option<T::Real>) -> Vec<GeneralizedEigenvalue<T::Complex>> {
+        self.alpha
+            .iter()
+            .zip(self.beta.iter())
+            .map(|(&alpha, &beta)| GeneralizedEigenvalue::new(alpha, beta, thresh_opt))
+            .collect()
+    }
+}
+
+/// Classified eigenvalues and eigenvectors returned by [Lapack::eig_generalized]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneralizedEigenpairs<T: Scalar> {
+    /// Eigenvalues classified by [GeneralizedEigenvalue::new]
+    pub eigs: Vec<GeneralizedEigenvalue<T::Complex>>,
+    /// Right eigenvectors in column-major order, empty if not calculated
+    pub vecs: Vec<T::Complex>,
+}
+
+/// Reference result of generalized eigenvalue problem by [EigGeneralizedWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigGeneralizedRef<'work, T: Scalar> {
+    /// Numerators of eigenvalues
+    pub alpha: &'work [T::Complex],
+    /// Denominators of eigenvalues
+    pub beta: &'work [T::Complex],
+    /// Right eigenvectors
+    pub vr: Option<&'work [T::Complex]>,
+    /// Left eigenvectors
+    pub vl: Option<&'work [T::Complex]>,
+}
+
+/// Helper trait for implementing [EigGeneralizedWork] methods
+pub trait EigGeneralizedWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_v: bool, l: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<EigGeneralizedRef<'work, Self::Elem>>;
+    fn eval(
+        self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<EigGeneralizedOwned<Self::Elem>>;
+}
+
+/// Left eigenvectors for row-major, and right eigenvectors for column-major
+fn jobs(calc_v: bool, l: MatrixLayout) -> (JobEv, JobEv) {
+    if calc_v {
+        match l {
+            MatrixLayout::C { .. } => (JobEv::All, JobEv::None),
+            MatrixLayout::F { .. } => (JobEv::None, JobEv::All),
+        }
+    } else {
+        (JobEv::None, JobEv::None)
+    }
+}
+
+macro_rules! impl_eig_generalized_work_c {
+    ($c:ty, $ggev:path) => {
+        impl EigGeneralizedWorkImpl for EigGeneralizedWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                let (n, _) = l.size();
+                let (jobvl, jobvr) = jobs(calc_v, l);
+                let mut alpha = vec_uninit(n as usize);
+                let mut beta = vec_uninit(n as usize);
+                let mut rwork = vec_uninit(8 * n as usize);
+
+                let mut vc_l = jobvl.then(|| vec_uninit((n * n) as usize));
+                let mut vc_r = jobvr.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [<$c>::zero()];
+                unsafe {
+                    $ggev(
+                        jobvl.as_ptr(),
+                        jobvr.as_ptr(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vc_l.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(vc_r.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work: Vec<MaybeUninit<$c>> = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    jobvl,
+                    jobvr,
+                    alpha,
+                    alpha_re: None,
+                    alpha_im: None,
+                    beta,
+                    beta_re: None,
+                    rwork: Some(rwork),
+                    vc_l,
+                    vc_r,
+                    vr_l: None,
+                    vr_r: None,
+                    work,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<EigGeneralizedRef<'work, Self::Elem>> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $ggev(
+                        self.jobvl.as_ptr(),
+                        self.jobvr.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.alpha),
+                        AsPtr::as_mut_ptr(&mut self.beta),
+                        AsPtr::as_mut_ptr(self.vc_l.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.vc_r.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                // Hermite conjugate
+                if let Some(vl) = self.vc_l.as_mut() {
+                    for value in vl {
+                        let value = unsafe { value.assume_init_mut() };
+                        value.im = -value.im;
+                    }
+                }
+                Ok(EigGeneralizedRef {
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    vl: self
+                        .vc_l
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    vr: self
+                        .vc_r
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<EigGeneralizedOwned<Self::Elem>> {
+                let _eig_ref = self.calc(a, b)?;
+                Ok(EigGeneralizedOwned {
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    vl: self.vc_l.map(|v| unsafe { v.assume_init() }),
+                    vr: self.vc_r.map(|v| unsafe { v.assume_init() }),
+                })
+            }
+        }
+    };
+}
+
+impl_eig_generalized_work_c!(c32, lapack_sys::cggev_);
+impl_eig_generalized_work_c!(c64, lapack_sys::zggev_);
+
+macro_rules! impl_eig_generalized_work_r {
+    ($f:ty, $ggev:path) => {
+        impl EigGeneralizedWorkImpl for EigGeneralizedWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                let (n, _) = l.size();
+                let (jobvl, jobvr) = jobs(calc_v, l);
+                let mut alpha_re = vec_uninit(n as usize);
+                let mut alpha_im = vec_uninit(n as usize);
+                let mut beta_re = vec_uninit(n as usize);
+                let mut vr_l = jobvl.then(|| vec_uninit((n * n) as usize));
+                let mut vr_r = jobvr.then(|| vec_uninit((n * n) as usize));
+                let vc_l = jobvl.then(|| vec_uninit((n * n) as usize));
+                let vc_r = jobvr.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size: [$f; 1] = [0.0];
+                unsafe {
+                    $ggev(
+                        jobvl.as_ptr(),
+                        jobvr.as_ptr(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut alpha_re),
+                        AsPtr::as_mut_ptr(&mut alpha_im),
+                        AsPtr::as_mut_ptr(&mut beta_re),
+                        AsPtr::as_mut_ptr(vr_l.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(vr_r.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+
+                Ok(Self {
+                    n,
+                    jobvr,
+                    jobvl,
+                    alpha: vec_uninit(n as usize),
+                    alpha_re: Some(alpha_re),
+                    alpha_im: Some(alpha_im),
+                    beta: vec_uninit(n as usize),
+                    beta_re: Some(beta_re),
+                    rwork: None,
+                    vr_l,
+                    vr_r,
+                    vc_l,
+                    vc_r,
+                    work,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<EigGeneralizedRef<'work, Self::Elem>> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $ggev(
+                        self.jobvl.as_ptr(),
+                        self.jobvr.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.alpha_re.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.alpha_im.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.beta_re.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.vr_l.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.vr_r.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let alpha_re = self
+                    .alpha_re
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                let alpha_im = self
+                    .alpha_im
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                let beta_re = self
+                    .beta_re
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                reconstruct_eigs(alpha_re, alpha_im, &mut self.alpha);
+                for (beta, &re) in self.beta.iter_mut().zip(beta_re) {
+                    beta.write(<$f>::complex(re, 0.0));
+                }
+
+                if let Some(v) = self.vr_l.as_ref() {
+                    let v = unsafe { v.slice_assume_init_ref() };
+                    reconstruct_eigenvectors(true, alpha_im, v, self.vc_l.as_mut().unwrap());
+                }
+                if let Some(v) = self.vr_r.as_ref() {
+                    let v = unsafe { v.slice_assume_init_ref() };
+                    reconstruct_eigenvectors(false, alpha_im, v, self.vc_r.as_mut().unwrap());
+                }
+
+                Ok(EigGeneralizedRef {
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    vl: self
+                        .vc_l
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    vr: self
+                        .vc_r
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<EigGeneralizedOwned<Self::Elem>> {
+                let _eig_ref = self.calc(a, b)?;
+                Ok(EigGeneralizedOwned {
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    vl: self.vc_l.map(|v| unsafe { v.assume_init() }),
+                    vr: self.vc_r.map(|v| unsafe { v.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eig_generalized_work_r!(f32, lapack_sys::sggev_);
+impl_eig_generalized_work_r!(f64, lapack_sys::dggev_);
@@ -0,0 +1,126 @@
+//! Eigenvalue problem for symmetric/Hermitian banded matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32   | f64   | c32   | c64   |
+//! |:------|:------|:------|:------|
+//! | ssbev | dsbev | chbev | zhbev |
+//!
+
+use crate::{error::*, *};
+use cauchy::*;
+
+/// Eigenvalues and eigenvectors of a symmetric/Hermitian band matrix
+///
+/// `ab` is the `(kd + 1) x n` column-major band storage of the `uplo`
+/// triangle, i.e. `ab[kd + i - j, j] = a[i, j]` for `UPLO::Upper` and
+/// `ab[i - j, j] = a[i, j]` for `UPLO::Lower`, which is destroyed.
+/// Returns the eigenvalues in ascending order and the eigenvectors as columns
+/// of a column-major `n x n` matrix (empty if `calc_v` is false).
+pub trait EighBandedImpl: Scalar {
+    fn eigh_banded(
+        calc_v: bool,
+        uplo: UPLO,
+        n: i32,
+        kd: i32,
+        ab: &mut [Self],
+    ) -> Result<(Vec<Self::Real>, Vec<Self>)>;
+}
+
+macro_rules! impl_eigh_banded_c {
+    ($s:ty, $bev:path) => {
+        impl EighBandedImpl for $s {
+            fn eigh_banded(
+                calc_v: bool,
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &mut [Self],
+            ) -> Result<(Vec<Self::Real>, Vec<Self>)> {
+                let ldab = kd + 1;
+                if ab.len() != (ldab * n) as usize {
+                    return Err(Error::InvalidShape);
+                }
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                let mut w: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut z: Vec<MaybeUninit<Self>> = if calc_v {
+                    vec_uninit((n * n) as usize)
+                } else {
+                    Vec::new()
+                };
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(n.max(1) as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> =
+                    vec_uninit((3 * n - 2).max(1) as usize);
+                let mut info = 0;
+                unsafe {
+                    $bev(
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        &kd,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { (w.assume_init(), z.assume_init()) })
+            }
+        }
+    };
+}
+impl_eigh_banded_c!(c64, lapack_sys::zhbev_);
+impl_eigh_banded_c!(c32, lapack_sys::chbev_);
+
+macro_rules! impl_eigh_banded_r {
+    ($s:ty, $bev:path) => {
+        impl EighBandedImpl for $s {
+            fn eigh_banded(
+                calc_v: bool,
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &mut [Self],
+            ) -> Result<(Vec<Self::Real>, Vec<Self>)> {
+                let ldab = kd + 1;
+                if ab.len() != (ldab * n) as usize {
+                    return Err(Error::InvalidShape);
+                }
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                let mut w: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut z: Vec<MaybeUninit<Self>> = if calc_v {
+                    vec_uninit((n * n) as usize)
+                } else {
+                    Vec::new()
+                };
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit((3 * n - 2).max(1) as usize);
+                let mut info = 0;
+                unsafe {
+                    $bev(
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        &kd,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { (w.assume_init(), z.assume_init()) })
+            }
+        }
+    };
+}
+impl_eigh_banded_r!(f64, lapack_sys::dsbev_);
+impl_eigh_banded_r!(f32, lapack_sys::ssbev_);
@@ -0,0 +1,294 @@
+//! Selected eigenvalues of symmetric/Hermitian matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | ssyevr | dsyevr | cheevr | zheevr |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+use std::ops::Range;
+
+/// Range of eigenvalues to be computed
+#[derive(Debug, Clone, PartialEq)]
+pub enum EigRange<R> {
+    /// All eigenvalues
+    All,
+    /// Eigenvalues whose indices in the ascending order are in the range
+    Index(Range<usize>),
+    /// Eigenvalues in the half-open interval `(lower, upper]`
+    Value(R, R),
+}
+
+/// Compute selected eigenvalues and eigenvectors of a symmetric/Hermitian matrix
+///
+/// Returns the eigenvalues in ascending order and, if `calc_v` is true, the
+/// corresponding eigenvectors as columns of a column-major `n x m` matrix
+/// where `m` is the number of the found eigenvalues. An empty range gives
+/// empty results without calling LAPACK. `a` is destroyed.
+///
+/// Returns [Error::InvalidRange] if an index range exceeds the dimension.
+pub trait EighSelectedImpl: Scalar {
+    fn eigh_selected(
+        calc_v: bool,
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        range: &EigRange<Self::Real>,
+    ) -> Result<(Vec<Self::Real>, Vec<Self>)>;
+}
+
+/// Arguments `(RANGE, VL, VU, IL, IU)` of `*evr` and the maximal number of eigenvalues
+type RangeArgs<R> = (u8, R, R, i32, i32, i32);
+
+/// Arguments of `*evr` for the range, or `None` for an empty range
+fn range_args<R: Scalar + PartialOrd>(n: i32, range: &EigRange<R>) -> Result<Option<RangeArgs<R>>> {
+    match range {
+        EigRange::Index(r) if r.end > n as usize => Err(Error::InvalidRange {
+            end: r.end,
+            n: n as usize,
+        }),
+        EigRange::Index(r) if r.is_empty() => Ok(None),
+        EigRange::Value(lower, upper)
+            if lower.partial_cmp(upper) != Some(std::cmp::Ordering::Less) =>
+        {
+            Ok(None)
+        }
+        _ if n == 0 => Ok(None),
+        EigRange::All => Ok(Some((b'A', R::zero(), R::zero(), 0, 0, n))),
+        EigRange::Index(r) => {
+            let (il, iu) = (r.start as i32 + 1, r.end as i32);
+            Ok(Some((b'I', R::zero(), R::zero(), il, iu, iu - il + 1)))
+        }
+        EigRange::Value(lower, upper) => Ok(Some((b'V', *lower, *upper, 0, 0, n))),
+    }
+}
+
+macro_rules! impl_eigh_selected_c {
+    ($s:ty, $evr:path) => {
+        impl EighSelectedImpl for $s {
+            fn eigh_selected(
+                calc_v: bool,
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                range: &EigRange<Self::Real>,
+            ) -> Result<(Vec<Self::Real>, Vec<Self>)> {
+                let n = l.len();
+                assert_eq!(n, l.lda());
+                let (range, vl, vu, il, iu, max_m) = match range_args(n, range)? {
+                    Some(args) => args,
+                    None => return Ok((Vec::new(), Vec::new())),
+                };
+                // Row-major matrix is regarded as the transpose of column-major one,
+                // which is the conjugate for Hermitian matrix
+                let uplo = match l {
+                    MatrixLayout::C { .. } => uplo.t(),
+                    MatrixLayout::F { .. } => uplo,
+                };
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                let abstol = Self::Real::zero(); // use default tolerance
+                let mut m = 0;
+                let mut w = vec![Self::Real::zero(); n as usize];
+                let mut z = if calc_v {
+                    vec![Self::zero(); (n * max_m) as usize]
+                } else {
+                    Vec::new()
+                };
+                let mut isuppz = vec![0; 2 * max_m as usize];
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                let mut rwork_size = [Self::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evr(
+                        jobz.as_ptr(),
+                        &(range as i8),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                let lrwork = rwork_size[0].to_usize().unwrap();
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(lrwork);
+                let liwork = iwork_size[0];
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork as usize);
+                unsafe {
+                    $evr(
+                        jobz.as_ptr(),
+                        &(range as i8),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &(lrwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                w.truncate(m as usize);
+                z.truncate((n * m) as usize);
+                if matches!(l, MatrixLayout::C { .. }) {
+                    // eigenvectors of the conjugate matrix
+                    for v in z.iter_mut() {
+                        *v = v.conj();
+                    }
+                }
+                Ok((w, z))
+            }
+        }
+    };
+}
+impl_eigh_selected_c!(c64, lapack_sys::zheevr_);
+impl_eigh_selected_c!(c32, lapack_sys::cheevr_);
+
+macro_rules! impl_eigh_selected_r {
+    ($s:ty, $evr:path) => {
+        impl EighSelectedImpl for $s {
+            fn eigh_selected(
+                calc_v: bool,
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                range: &EigRange<Self::Real>,
+            ) -> Result<(Vec<Self::Real>, Vec<Self>)> {
+                let n = l.len();
+                assert_eq!(n, l.lda());
+                let (range, vl, vu, il, iu, max_m) = match range_args(n, range)? {
+                    Some(args) => args,
+                    None => return Ok((Vec::new(), Vec::new())),
+                };
+                // Row-major matrix is regarded as the transpose of column-major one,
+                // which is the conjugate for Hermitian matrix
+                let uplo = match l {
+                    MatrixLayout::C { .. } => uplo.t(),
+                    MatrixLayout::F { .. } => uplo,
+                };
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                let abstol = Self::Real::zero(); // use default tolerance
+                let mut m = 0;
+                let mut w = vec![Self::Real::zero(); n as usize];
+                let mut z = if calc_v {
+                    vec![Self::zero(); (n * max_m) as usize]
+                } else {
+                    Vec::new()
+                };
+                let mut isuppz = vec![0; 2 * max_m as usize];
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evr(
+                        jobz.as_ptr(),
+                        &(range as i8),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                let liwork = iwork_size[0];
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork as usize);
+                unsafe {
+                    $evr(
+                        jobz.as_ptr(),
+                        &(range as i8),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                w.truncate(m as usize);
+                z.truncate((n * m) as usize);
+                Ok((w, z))
+            }
+        }
+    };
+}
+impl_eigh_selected_r!(f64, lapack_sys::dsyevr_);
+impl_eigh_selected_r!(f32, lapack_sys::ssyevr_);
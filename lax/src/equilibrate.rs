@@ -0,0 +1,92 @@
+//! Equilibration of general matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgeequ | dgeequ | cgeequ | zgeequ |
+//!
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::Zero;
+
+/// Row and column scale factors of [EquilibrateImpl::equilibrate]
+#[derive(Debug, Clone)]
+pub struct Equilibration<R> {
+    /// Row scale factors `r`
+    pub r: Vec<R>,
+    /// Column scale factors `c`
+    pub c: Vec<R>,
+    /// Ratio of the smallest `r[i]` to the largest `r[i]`
+    pub rowcnd: R,
+    /// Ratio of the smallest `c[j]` to the largest `c[j]`
+    pub colcnd: R,
+    /// Absolute value of the largest element of the matrix
+    pub amax: R,
+}
+
+/// Compute row and column scalings intended to equilibrate a general matrix
+///
+/// The entries of `diag(r) A diag(c)` have the largest absolute value 1
+/// in each row and column.
+/// Returns an error if a row or column of the matrix is exactly zero.
+pub trait EquilibrateImpl: Scalar {
+    fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<Equilibration<Self::Real>>;
+}
+
+macro_rules! impl_equilibrate {
+    ($s:ty, $equ:path) => {
+        impl EquilibrateImpl for $s {
+            fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<Equilibration<Self::Real>> {
+                // C-layout matrix is regarded as the transpose in Fortran layout
+                let (m, n) = match l {
+                    MatrixLayout::F { col, lda } => (lda, col),
+                    MatrixLayout::C { row, lda } => (lda, row),
+                };
+                let mut r = vec![Self::Real::zero(); m as usize];
+                let mut c = vec![Self::Real::zero(); n as usize];
+                let mut rowcnd = Self::Real::zero();
+                let mut colcnd = Self::Real::zero();
+                let mut amax = Self::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $equ(
+                        &m,
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        r.as_mut_ptr(),
+                        c.as_mut_ptr(),
+                        &mut rowcnd,
+                        &mut colcnd,
+                        &mut amax,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(match l {
+                    MatrixLayout::F { .. } => Equilibration {
+                        r,
+                        c,
+                        rowcnd,
+                        colcnd,
+                        amax,
+                    },
+                    MatrixLayout::C { .. } => Equilibration {
+                        r: c,
+                        c: r,
+                        rowcnd: colcnd,
+                        colcnd: rowcnd,
+                        amax,
+                    },
+                })
+            }
+        }
+    };
+}
+impl_equilibrate!(c64, lapack_sys::zgeequ_);
+impl_equilibrate!(c32, lapack_sys::cgeequ_);
+impl_equilibrate!(f64, lapack_sys::dgeequ_);
+impl_equilibrate!(f32, lapack_sys::sgeequ_);
@@ -19,6 +19,14 @@ pub enum Error {
     /// Strides of the array is not supported
     #[error("Invalid shape")]
     InvalidShape,
+
+    /// Index range exceeds the dimension of the matrix
+    #[error("Index range end {} exceeds the dimension {}", end, n)]
+    InvalidRange { end: usize, n: usize },
+
+    /// Character is not a valid LAPACK flag
+    #[error("Invalid flag: {:?}", flag)]
+    InvalidFlag { flag: char },
 }
 
 pub trait AsLapackResult {
@@ -1,4 +1,35 @@
 //! Charactor flags, e.g. `'T'`, used in LAPACK API
+//!
+//! The flags [UPLO], [Transpose], [NormType], [Diag] and [Side] can be parsed from
+//! their LAPACK character with `TryFrom<char>`, and converted back into the
+//! byte passed to LAPACK with [From] for `u8`.
+
+use crate::error::*;
+use std::convert::TryFrom;
+
+macro_rules! impl_char_conversion {
+    ($flag:ident, $($variant:ident),*) => {
+        impl TryFrom<char> for $flag {
+            type Error = Error;
+
+            /// Parse the LAPACK character of the flag, case-insensitively
+            fn try_from(c: char) -> Result<Self> {
+                $(
+                if c.eq_ignore_ascii_case(&($flag::$variant as u8 as char)) {
+                    return Ok($flag::$variant);
+                }
+                )*
+                Err(Error::InvalidFlag { flag: c })
+            }
+        }
+
+        impl From<$flag> for u8 {
+            fn from(flag: $flag) -> u8 {
+                flag as u8
+            }
+        }
+    };
+}
 
 /// Upper/Lower specification for seveal usages
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -8,8 +39,15 @@ pub enum UPLO {
     Lower = b'L',
 }
 
+impl_char_conversion!(UPLO, Upper, Lower);
+
 impl UPLO {
     pub fn t(self) -> Self {
+        self.opposite()
+    }
+
+    /// The other triangular part, i.e. `Lower` for `Upper` and vice versa
+    pub fn opposite(self) -> Self {
         match self {
             UPLO::Upper => UPLO::Lower,
             UPLO::Lower => UPLO::Upper,
@@ -30,7 +68,25 @@ pub enum Transpose {
     Hermite = b'C',
 }
 
+impl_char_conversion!(Transpose, No, Transpose, Hermite);
+
 impl Transpose {
+    /// Combine two operations into one, i.e. `op(A) = self(other(A))`
+    ///
+    /// Returns `None` if the combined operation is not expressible as a
+    /// [Transpose] flag, which is the case for `Transpose` and `Hermite`
+    /// since their composition is the elementwise conjugate without
+    /// transposition.
+    pub fn compose(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (Transpose::No, op) | (op, Transpose::No) => Some(op),
+            (Transpose::Transpose, Transpose::Transpose)
+            | (Transpose::Hermite, Transpose::Hermite) => Some(Transpose::No),
+            (Transpose::Transpose, Transpose::Hermite)
+            | (Transpose::Hermite, Transpose::Transpose) => None,
+        }
+    }
+
     /// To use Fortran LAPACK API in lapack-sys crate
     pub fn as_ptr(&self) -> *const i8 {
         self as *const Transpose as *const i8
@@ -45,6 +101,8 @@ pub enum NormType {
     Frobenius = b'F',
 }
 
+impl_char_conversion!(NormType, One, Infinity, Frobenius);
+
 impl NormType {
     pub fn transpose(self) -> Self {
         match self {
@@ -60,6 +118,63 @@ impl NormType {
     }
 }
 
+/// Side of the matrix to be multiplied, e.g. `Q * C` or `C * Q`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Side {
+    /// Multiply from the left, `op(Q) * C`
+    Left = b'L',
+    /// Multiply from the right, `C * op(Q)`
+    Right = b'R',
+}
+
+impl_char_conversion!(Side, Left, Right);
+
+impl Side {
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const Side as *const i8
+    }
+}
+
+/// Symmetry of the random matrix generated by `*latms`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Symmetry {
+    /// General matrix with the prescribed singular values
+    General = b'N',
+    /// Symmetric/Hermitian matrix with the prescribed (possibly negative) eigenvalues
+    Hermitian = b'H',
+}
+
+impl Symmetry {
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const Symmetry as *const i8
+    }
+}
+
+/// Balancing of a general matrix before computing its eigenvalues
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Balance {
+    /// Neither permute nor scale
+    None = b'N',
+    /// Permute to isolate eigenvalues if possible
+    Permute = b'P',
+    /// Scale to make the rows and columns closer in norm
+    Scale = b'S',
+    /// Both permute and scale
+    Both = b'B',
+}
+
+impl Balance {
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const Balance as *const i8
+    }
+}
+
 /// Flag for calculating eigenvectors or not
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
@@ -132,6 +247,8 @@ pub enum Diag {
     NonUnit = b'N',
 }
 
+impl_char_conversion!(Diag, Unit, NonUnit);
+
 impl Diag {
     pub fn as_ptr(&self) -> *const i8 {
         self as *const Diag as *const i8
@@ -0,0 +1,96 @@
+//! Generate random test matrices with prescribed singular values or eigenvalues
+//!
+//! `*latms` is a part of the matrix generator library of LAPACK (TMGLIB)
+//! used in its own test suite, and may not be shipped by every LAPACK backend.
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | slatms | dlatms | clatms | zlatms |
+//!
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::One;
+
+/// Generate a random `m x n` matrix in column-major order
+/// whose singular values (or eigenvalues for [Symmetry::Hermitian]) are `d`
+///
+/// `iseed` is the seed of the LAPACK internal random number generator.
+/// Its entries must be in `0..4096` and `iseed[3]` must be odd.
+/// It is updated by this routine.
+pub trait LatmsImpl: Scalar {
+    fn latms(
+        l: MatrixLayout,
+        sym: Symmetry,
+        d: &[Self::Real],
+        iseed: &mut [i32; 4],
+    ) -> Result<Vec<Self>>;
+}
+
+macro_rules! impl_latms {
+    ($s:ty, $latms:path) => {
+        impl LatmsImpl for $s {
+            fn latms(
+                l: MatrixLayout,
+                sym: Symmetry,
+                d: &[Self::Real],
+                iseed: &mut [i32; 4],
+            ) -> Result<Vec<Self>> {
+                let (m, n) = match l {
+                    MatrixLayout::F { col, lda } => (lda, col),
+                    MatrixLayout::C { row, lda } => (row, lda),
+                };
+                let k = m.min(n) as usize;
+                if d.len() != k || (sym == Symmetry::Hermitian && m != n) {
+                    return Err(Error::InvalidShape);
+                }
+                if iseed.iter().any(|&s| !(0..4096).contains(&s)) || iseed[3] % 2 == 0 {
+                    return Err(Error::LapackInvalidValue { return_code: -4 });
+                }
+                // Use the given values as is (MODE = 0) in a full band matrix without packing
+                let mut d = d.to_vec();
+                let mode = 0;
+                let cond = Self::Real::one();
+                let dmax = Self::Real::one();
+                let kl = (m - 1).max(0);
+                let ku = (n - 1).max(0);
+                let mut a: Vec<MaybeUninit<Self>> = vec_uninit((m * n) as usize);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(3 * m.max(n) as usize);
+                let mut info = 0;
+                unsafe {
+                    $latms(
+                        &m,
+                        &n,
+                        &(b'U' as i8),
+                        iseed.as_mut_ptr(),
+                        sym.as_ptr(),
+                        d.as_mut_ptr(),
+                        &mode,
+                        &cond,
+                        &dmax,
+                        &kl,
+                        &ku,
+                        &(b'N' as i8),
+                        AsPtr::as_mut_ptr(&mut a),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let a = unsafe { a.assume_init() };
+                Ok(match l {
+                    MatrixLayout::F { .. } => a,
+                    MatrixLayout::C { .. } => transpose(MatrixLayout::F { col: n, lda: m }, &a).1,
+                })
+            }
+        }
+    };
+}
+impl_latms!(c64, lapack_sys::zlatms_);
+impl_latms!(c32, lapack_sys::clatms_);
+impl_latms!(f64, lapack_sys::dlatms_);
+impl_latms!(f32, lapack_sys::slatms_);
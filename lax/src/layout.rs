@@ -278,3 +278,58 @@ pub fn transpose_over<T: Copy>(layout: MatrixLayout, from: &[T], to: &mut [T]) -
     }
     transposed
 }
+
+/// Column-major version of a matrix for passing to LAPACK
+///
+/// Returns `layout` and `None` if it is already F-continuous, i.e. `input`
+/// can be used as is. Otherwise returns the F-continuous layout and the
+/// transposed copy of `input` by [transpose].
+/// The result can be written back by [transpose_over].
+///
+/// Examples
+/// ---------
+///
+/// ```rust
+/// # use lax::layout::*;
+/// let layout = MatrixLayout::F { col: 3, lda: 2 };
+/// let a = vec![1., 2., 3., 4., 5., 6.];
+/// let (l, t) = ensure_fortran(layout, &a);
+/// assert_eq!(l, layout);
+/// assert!(t.is_none());
+/// ```
+///
+/// ```rust
+/// # use lax::layout::*;
+/// let layout = MatrixLayout::C { row: 2, lda: 3 };
+/// let a = vec![1., 2., 3., 4., 5., 6.];
+/// let (l, t) = ensure_fortran(layout, &a);
+/// assert_eq!(l, MatrixLayout::F { col: 3, lda: 2 });
+/// let t = t.unwrap();
+/// assert_eq!(t, &[1., 4., 2., 5., 3., 6.]);
+///
+/// // round-trip
+/// let mut b = vec![0.0; a.len()];
+/// assert_eq!(transpose_over(l, &t, &mut b), layout);
+/// assert_eq!(b, a);
+/// ```
+///
+/// Panics
+/// ------
+/// - If input array size and `layout` size mismatch
+///
+pub fn ensure_fortran<T: Copy>(
+    layout: MatrixLayout,
+    input: &[T],
+) -> (MatrixLayout, Option<Vec<T>>) {
+    match layout {
+        MatrixLayout::C { .. } => {
+            let (layout, t) = transpose(layout, input);
+            (layout, Some(t))
+        }
+        MatrixLayout::F { .. } => {
+            let (m, n) = layout.size();
+            assert_eq!(input.len(), m as usize * n as usize);
+            (layout, None)
+        }
+    }
+}
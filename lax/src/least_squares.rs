@@ -36,11 +36,13 @@ pub trait LeastSquaresWorkImpl: Sized {
         &mut self,
         a: &mut [Self::Elem],
         b: &mut [Self::Elem],
+        rcond: <Self::Elem as Scalar>::Real,
     ) -> Result<LeastSquaresRef<Self::Elem>>;
     fn eval(
         self,
         a: &mut [Self::Elem],
         b: &mut [Self::Elem],
+        rcond: <Self::Elem as Scalar>::Real,
     ) -> Result<LeastSquaresOwned<Self::Elem>>;
 }
 
@@ -107,6 +109,7 @@ macro_rules! impl_least_squares_work_c {
                 &mut self,
                 a: &mut [Self::Elem],
                 b: &mut [Self::Elem],
+                rcond: <Self::Elem as Scalar>::Real,
             ) -> Result<LeastSquaresRef<Self::Elem>> {
                 let (m, n) = self.a_layout.size();
                 let (m_, nrhs) = self.b_layout.size();
@@ -115,28 +118,11 @@ macro_rules! impl_least_squares_work_c {
                 let lwork = self.work.len().to_i32().unwrap();
 
                 // Transpose if a is C-continuous
-                let mut a_t = None;
-                let _ = match self.a_layout {
-                    MatrixLayout::C { .. } => {
-                        let (layout, t) = transpose(self.a_layout, a);
-                        a_t = Some(t);
-                        layout
-                    }
-                    MatrixLayout::F { .. } => self.a_layout,
-                };
+                let (_, mut a_t) = ensure_fortran(self.a_layout, a);
 
                 // Transpose if b is C-continuous
-                let mut b_t = None;
-                let b_layout = match self.b_layout {
-                    MatrixLayout::C { .. } => {
-                        let (layout, t) = transpose(self.b_layout, b);
-                        b_t = Some(t);
-                        layout
-                    }
-                    MatrixLayout::F { .. } => self.b_layout,
-                };
+                let (b_layout, mut b_t) = ensure_fortran(self.b_layout, b);
 
-                let rcond: <Self::Elem as Scalar>::Real = -1.;
                 let mut rank: i32 = 0;
 
                 let mut info = 0;
@@ -179,8 +165,9 @@ macro_rules! impl_least_squares_work_c {
                 mut self,
                 a: &mut [Self::Elem],
                 b: &mut [Self::Elem],
+                rcond: <Self::Elem as Scalar>::Real,
             ) -> Result<LeastSquaresOwned<Self::Elem>> {
-                let LeastSquaresRef { rank, .. } = self.calc(a, b)?;
+                let LeastSquaresRef { rank, .. } = self.calc(a, b, rcond)?;
                 let singular_values = unsafe { self.singular_values.assume_init() };
                 Ok(LeastSquaresOwned {
                     singular_values,
@@ -252,6 +239,7 @@ macro_rules! impl_least_squares_work_r {
                 &mut self,
                 a: &mut [Self::Elem],
                 b: &mut [Self::Elem],
+                rcond: <Self::Elem as Scalar>::Real,
             ) -> Result<LeastSquaresRef<Self::Elem>> {
                 let (m, n) = self.a_layout.size();
                 let (m_, nrhs) = self.b_layout.size();
@@ -260,28 +248,11 @@ macro_rules! impl_least_squares_work_r {
                 let lwork = self.work.len().to_i32().unwrap();
 
                 // Transpose if a is C-continuous
-                let mut a_t = None;
-                let _ = match self.a_layout {
-                    MatrixLayout::C { .. } => {
-                        let (layout, t) = transpose(self.a_layout, a);
-                        a_t = Some(t);
-                        layout
-                    }
-                    MatrixLayout::F { .. } => self.a_layout,
-                };
+                let (_, mut a_t) = ensure_fortran(self.a_layout, a);
 
                 // Transpose if b is C-continuous
-                let mut b_t = None;
-                let b_layout = match self.b_layout {
-                    MatrixLayout::C { .. } => {
-                        let (layout, t) = transpose(self.b_layout, b);
-                        b_t = Some(t);
-                        layout
-                    }
-                    MatrixLayout::F { .. } => self.b_layout,
-                };
+                let (b_layout, mut b_t) = ensure_fortran(self.b_layout, b);
 
-                let rcond: <Self::Elem as Scalar>::Real = -1.;
                 let mut rank: i32 = 0;
 
                 let mut info = 0;
@@ -323,8 +294,9 @@ macro_rules! impl_least_squares_work_r {
                 mut self,
                 a: &mut [Self::Elem],
                 b: &mut [Self::Elem],
+                rcond: <Self::Elem as Scalar>::Real,
             ) -> Result<LeastSquaresOwned<Self::Elem>> {
-                let LeastSquaresRef { rank, .. } = self.calc(a, b)?;
+                let LeastSquaresRef { rank, .. } = self.calc(a, b, rcond)?;
                 let singular_values = unsafe { self.singular_values.assume_init() };
                 Ok(LeastSquaresOwned {
                     singular_values,
@@ -336,3 +308,99 @@ macro_rules! impl_least_squares_work_r {
 }
 impl_least_squares_work_r!(f64, lapack_sys::dgelsd_);
 impl_least_squares_work_r!(f32, lapack_sys::sgelsd_);
+
+/// Helper trait to abstract `*gels` LAPACK routines for implementing [Lapack::least_squares_qr]
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32   | f64   | c32   | c64   |
+/// |:------|:------|:------|:------|
+/// | sgels | dgels | cgels | zgels |
+///
+pub trait LeastSquaresQrImpl: Scalar {
+    fn least_squares_qr(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_least_squares_qr {
+    ($s:ty, $gels:path $(, $extra:expr)*) => {
+        impl LeastSquaresQrImpl for $s {
+            fn least_squares_qr(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (m, n) = a_layout.size();
+                let (m_, nrhs) = b_layout.size();
+                assert!(m_ >= m.max(n));
+
+                // Transpose if a is C-continuous
+                let (_, mut a_t) = ensure_fortran(a_layout, a);
+
+                // Transpose if b is C-continuous
+                let (b_layout, mut b_t) = ensure_fortran(b_layout, b);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gels(
+                        Transpose::No.as_ptr(),
+                        &m,
+                        &n,
+                        &nrhs,
+                        std::ptr::null_mut(),
+                        &m,
+                        std::ptr::null_mut(),
+                        &m_,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                        $($extra),*
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gels(
+                        Transpose::No.as_ptr(),
+                        &m,
+                        &n,
+                        &nrhs,
+                        AsPtr::as_mut_ptr(a_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(a)),
+                        &m,
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &m_,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                        $($extra),*
+                    )
+                };
+                // `info > 0` if a diagonal element of the triangular factor is exactly zero,
+                // i.e. `A` does not have full rank
+                info.as_lapack_result()?;
+
+                // Skip a_t -> a transpose because A has been destroyed
+                // Re-transpose b
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+impl_least_squares_qr!(c64, lapack_sys::zgels_);
+impl_least_squares_qr!(c32, lapack_sys::cgels_);
+// `dgels_` in lapack-sys additionally takes the hidden length of `trans`
+impl_least_squares_qr!(f64, lapack_sys::dgels_, 1);
+impl_least_squares_qr!(f32, lapack_sys::sgels_);
@@ -52,8 +52,12 @@
 //! According to the property input metrix, several types of triangular decomposition are used:
 //!
 //! - [solve] module provides methods for LU-decomposition for general matrix.
+//! - [solve_banded] module provides methods for LU-decomposition for band matrix.
 //! - [solveh] module provides methods for Bunch-Kaufman diagonal pivoting method for symmetric/Hermitian indefinite matrix.
 //! - [cholesky] module provides methods for Cholesky decomposition for symmetric/Hermitian positive dinite matrix.
+//! - [cholesky_banded] module provides methods for Cholesky decomposition for symmetric/Hermitian positive dinite band matrix.
+//! - [sylvester] module provides methods for solving Sylvester equation for (quasi-)triangular matrices.
+//! - [equilibrate] module provides row and column scalings to improve the condition of general matrix.
 //!
 //! Eigenvalue Problem
 //! -------------------
@@ -62,15 +66,34 @@
 //! there are several types of eigenvalue problem API
 //!
 //! - [eig] module for eigenvalue problem for general matrix.
+//! - [eig_cond] module for eigenvalue problem for general matrix with the condition numbers.
+//! - [balance] module for balancing general matrix before eigenvalue problem.
+//! - [eig_generalized] module for generalized eigenvalue problem for general matrix.
 //! - [eigh] module for eigenvalue problem for symmetric/Hermitian matrix.
 //! - [eigh_generalized] module for generalized eigenvalue problem for symmetric/Hermitian matrix.
+//! - [eigh_selected] module for selected eigenvalues of symmetric/Hermitian matrix.
+//! - [eigh_banded] module for eigenvalue problem for symmetric/Hermitian band matrix.
+//! - [schur] module for Schur decomposition of general matrix.
+//! - [qz] module for generalized Schur (QZ) decomposition of matrix pencil.
+//! - [tridiagonalize] module for reduction of symmetric/Hermitian matrix into tridiagonal form.
+//! - [bidiagonalize] module for reduction of general matrix into bidiagonal form.
 //!
 //! Singular Value Decomposition
 //! -----------------------------
 //!
 //! - [svd] module for singular value decomposition (SVD) for general matrix
 //! - [svddc] module for singular value decomposition (SVD) with divided-and-conquer algorithm for general matrix
-//! - [least_squares] module for solving least square problem using SVD
+//! - [least_squares] module for solving least square problem using SVD or QR factorization
+//!
+//! Matrix products
+//! ----------------
+//!
+//! - [rank_k] module for Gram matrices using BLAS symmetric/Hermitian rank-k product
+//!
+//! Test matrix generation
+//! -----------------------
+//!
+//! - [latms] module for random matrices with prescribed singular values or eigenvalues
 //!
 
 #![deny(rustdoc::broken_intra_doc_links, rustdoc::private_intra_doc_links)]
@@ -85,28 +108,51 @@ extern crate openblas_src as _src;
 extern crate netlib_src as _src;
 
 pub mod alloc;
+pub mod balance;
+pub mod bidiagonalize;
 pub mod cholesky;
+pub mod cholesky_banded;
 pub mod eig;
+pub mod eig_cond;
+pub mod eig_generalized;
 pub mod eigh;
+pub mod eigh_banded;
 pub mod eigh_generalized;
+pub mod eigh_selected;
+pub mod equilibrate;
 pub mod error;
 pub mod flags;
+pub mod latms;
 pub mod layout;
 pub mod least_squares;
 pub mod opnorm;
 pub mod qr;
+pub mod qz;
+pub mod rank_k;
 pub mod rcond;
+pub mod schur;
 pub mod solve;
+pub mod solve_banded;
 pub mod solveh;
 pub mod svd;
 pub mod svddc;
+pub mod sylvester;
 pub mod triangular;
 pub mod tridiagonal;
+pub mod tridiagonalize;
 
+pub use self::bidiagonalize::BidiagonalOwned;
+pub use self::eig_cond::EigCondOwned;
+pub use self::eig_generalized::{GeneralizedEigenpairs, GeneralizedEigenvalue};
+pub use self::eigh_selected::EigRange;
+pub use self::equilibrate::Equilibration;
 pub use self::flags::*;
 pub use self::least_squares::LeastSquaresOwned;
+pub use self::solve::ErrorBounds;
+pub use self::solve_banded::BandShape;
 pub use self::svd::{SvdOwned, SvdRef};
 pub use self::tridiagonal::{LUFactorizedTridiagonal, Tridiagonal};
+pub use self::tridiagonalize::TridiagonalizeOwned;
 
 use self::{alloc::*, error::*, layout::*};
 use cauchy::*;
@@ -124,6 +170,32 @@ pub trait Lapack: Scalar {
         a: &mut [Self],
     ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>)>;
 
+    /// Compute right eigenvalue and eigenvectors for general matrices with
+    /// the reciprocal condition numbers of them
+    fn eig_cond(l: MatrixLayout, a: &mut [Self]) -> Result<EigCondOwned<Self>>;
+
+    /// Compute right generalized eigenvalue and eigenvectors $ A v = \lambda B v $ for general matrices
+    ///
+    /// Eigenvalues whose $|\beta|$ is not larger than `thresh_opt` are
+    /// [GeneralizedEigenvalue::Indeterminate].
+    fn eig_generalized(
+        calc_v: bool,
+        l: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+        thresh_opt: Option<Self::Real>,
+    ) -> Result<GeneralizedEigenpairs<Self>>;
+
+    /// Compute Schur decomposition $ A = Z T Z^\dagger $ of a general matrix
+    ///
+    /// `a` is overwritten by $T$, and the eigenvalues and $Z$ are returned.
+    fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)>;
+
+    /// Compute generalized Schur decomposition $ A = Q S Z^\dagger, B = Q T Z^\dagger $ of a matrix pencil
+    ///
+    /// `a` and `b` are overwritten by $S$ and $T$, and $Q$ and $Z$ are returned.
+    fn qz(l: MatrixLayout, a: &mut [Self], b: &mut [Self]) -> Result<(Vec<Self>, Vec<Self>)>;
+
     /// Compute right eigenvalue and eigenvectors for a symmetric or Hermitian matrix
     fn eigh(
         calc_eigenvec: bool,
@@ -141,40 +213,171 @@ pub trait Lapack: Scalar {
         b: &mut [Self],
     ) -> Result<Vec<Self::Real>>;
 
+    /// Compute selected eigenvalues and eigenvectors for a symmetric or Hermitian matrix
+    ///
+    /// Returns the eigenvalues in ascending order and the eigenvectors as
+    /// columns of a column-major matrix (empty if `calc_eigenvec` is false).
+    fn eigh_selected(
+        calc_eigenvec: bool,
+        layout: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        range: &EigRange<Self::Real>,
+    ) -> Result<(Vec<Self::Real>, Vec<Self>)>;
+
+    /// Compute right eigenvalue and eigenvectors for a symmetric or Hermitian band matrix
+    ///
+    /// `ab` is the column-major band storage of the `uplo` triangle with `kd` off-diagonals.
+    fn eigh_banded(
+        calc_eigenvec: bool,
+        uplo: UPLO,
+        n: i32,
+        kd: i32,
+        ab: &mut [Self],
+    ) -> Result<(Vec<Self::Real>, Vec<Self>)>;
+
+    /// Reduce a symmetric or Hermitian matrix into real symmetric tridiagonal form
+    /// $ A = Q T Q^H $
+    ///
+    /// Returns the diagonal $d$ and off-diagonal $e$ of $T$,
+    /// and the scalar factors $\tau$ of the Householder reflectors stored in `a`.
+    fn tridiagonalize(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+    ) -> Result<TridiagonalizeOwned<Self>>;
+
+    /// Reconstruct Q-matrix from Householder-reflectors of [Lapack::tridiagonalize]
+    fn q_tridiagonal(l: MatrixLayout, uplo: UPLO, a: &mut [Self], tau: &[Self]) -> Result<()>;
+
+    /// Reduce a general matrix into real bidiagonal form $ A = Q B P^H $
+    ///
+    /// `a` is overwritten by the Householder reflectors in column-major order
+    /// even if `l` is row-major. See [bidiagonalize::BidiagonalizeImpl] for detail.
+    fn bidiagonalize(l: MatrixLayout, a: &mut [Self]) -> Result<BidiagonalOwned<Self>>;
+
+    /// Reconstruct $Q$ from Householder-reflectors of [Lapack::bidiagonalize]
+    fn q_bidiagonal(l: MatrixLayout, a: &[Self], tauq: &[Self]) -> Result<Vec<Self>>;
+
+    /// Reconstruct $P^H$ from Householder-reflectors of [Lapack::bidiagonalize]
+    fn pt_bidiagonal(l: MatrixLayout, a: &[Self], taup: &[Self]) -> Result<Vec<Self>>;
+
     /// Execute Householder reflection as the first step of QR-decomposition
     ///
     /// For C-continuous array,
     /// this will call LQ-decomposition of the transposed matrix $ A^T = LQ^T $
     fn householder(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
 
+    /// Execute Householder reflection with column pivoting $ AP = QR $
+    ///
+    /// `a` must be in Fortran layout. Returns `tau` and the 1-based column permutation.
+    fn householder_pivot(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Vec<i32>)>;
+
     /// Reconstruct Q-matrix from Householder-reflectors
     fn q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
 
     /// Execute QR-decomposition at once
     fn qr(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
 
+    /// Multiply a matrix by $Q$ of [Lapack::householder] without constructing $Q$
+    ///
+    /// The reflectors `a` and the matrix `c` must be in Fortran layout,
+    /// and `c` is overwritten by $\mathrm{op}(Q) C$ or $C \mathrm{op}(Q)$ according to `side`.
+    fn apply_q(
+        al: MatrixLayout,
+        side: Side,
+        trans: Transpose,
+        a: &[Self],
+        tau: &[Self],
+        cl: MatrixLayout,
+        c: &mut [Self],
+    ) -> Result<()>;
+
+    /// Execute LQ-decomposition $ A = LQ $ at once
+    ///
+    /// Returns the factorized matrix whose lower triangular part is $L$,
+    /// and `a` is overwritten by $Q$ in its first `min(m, n)` rows.
+    fn lq(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+
+    /// Execute QL-decomposition $ A = QL $ at once
+    ///
+    /// Returns the factorized matrix whose last `min(m, n)` rows contain $L$,
+    /// and `a` is overwritten by $Q$ in its last `min(m, n)` columns.
+    fn ql(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+
     /// Compute singular-value decomposition (SVD)
     fn svd(l: MatrixLayout, calc_u: bool, calc_vt: bool, a: &mut [Self]) -> Result<SvdOwned<Self>>;
 
+    /// Compute SVD of each of the matrices stored consecutively in `a`,
+    /// reusing a single workspace
+    ///
+    /// `a` holds `a.len() / (m n)` matrices of the layout `l`, which are
+    /// destroyed. `f` is called with the index of each matrix and its SVD,
+    /// which is only valid in the call, and an error returned by `f` stops
+    /// the iteration.
+    ///
+    /// Returns [Error::InvalidShape] if `a` is not a whole number of
+    /// matrices, or the matrices are empty.
+    fn svd_batch<E, F>(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+        f: F,
+    ) -> std::result::Result<(), E>
+    where
+        E: From<Error>,
+        F: FnMut(usize, SvdRef<'_, Self>) -> std::result::Result<(), E>;
+
     /// Compute singular value decomposition (SVD) with divide-and-conquer algorithm
     fn svddc(layout: MatrixLayout, jobz: JobSvd, a: &mut [Self]) -> Result<SvdOwned<Self>>;
 
+    /// Compute economy SVD with divide-and-conquer algorithm only for the requested singular vectors
+    ///
+    /// `a` is destroyed, and may be overwritten by one of the singular vectors.
+    fn svddc_partial(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+    ) -> Result<SvdOwned<Self>>;
+
     /// Compute a vector $x$ which minimizes Euclidian norm $\| Ax - b\|$
     /// for a given matrix $A$ and a vector $b$.
+    ///
+    /// Singular values $s_i \le \text{rcond} \cdot s_1$ are treated as zero
+    /// to determine the effective rank of $A$.
+    /// A negative `rcond` means the machine precision.
     fn least_squares(
         a_layout: MatrixLayout,
         a: &mut [Self],
         b: &mut [Self],
+        rcond: Self::Real,
     ) -> Result<LeastSquaresOwned<Self>>;
 
     /// Solve least square problems $\argmin_X \| AX - B\|$
+    ///
+    /// `rcond` is interpreted as in [Lapack::least_squares].
     fn least_squares_nrhs(
         a_layout: MatrixLayout,
         a: &mut [Self],
         b_layout: MatrixLayout,
         b: &mut [Self],
+        rcond: Self::Real,
     ) -> Result<LeastSquaresOwned<Self>>;
 
+    /// Solve least square problems $\argmin_X \| AX - B\|$ using QR or LQ factorization
+    ///
+    /// $A$ must have full rank. For $m \ge n$ this is the least squares
+    /// solution, and for $m < n$ the minimum norm solution of $AX = B$.
+    /// `b` must have $\max(m, n)$ rows, and the solution is stored in its first $n$ rows.
+    fn least_squares_qr(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+
     /// Computes the LU decomposition of a general $m \times n$ matrix
     /// with partial pivoting with row interchanges.
     ///
@@ -207,8 +410,36 @@ pub trait Lapack: Scalar {
     fn inv(l: MatrixLayout, a: &mut [Self], p: &Pivot) -> Result<()>;
 
     /// Solve linear equations $Ax = b$ using the output of LU-decomposition
+    ///
+    /// `b` may be a column-major matrix of multiple right hand sides.
     fn solve(l: MatrixLayout, t: Transpose, a: &[Self], p: &Pivot, b: &mut [Self]) -> Result<()>;
 
+    /// Improve a computed solution $x$ of $Ax = b$ by iterative refinement
+    ///
+    /// Returns the estimated forward and backward errors for each right hand side.
+    fn solve_refine(
+        l: MatrixLayout,
+        t: Transpose,
+        a: &[Self],
+        af: &[Self],
+        p: &Pivot,
+        b: &[Self],
+        x: &mut [Self],
+    ) -> Result<ErrorBounds<Self::Real>>;
+
+    /// Computes the LU decomposition of a band matrix in the band storage with fill-in rows
+    fn lu_banded(shape: BandShape, ab: &mut [Self]) -> Result<Pivot>;
+
+    /// Solve linear equations $Ax = b$ for column-major `b` using the output of banded LU-decomposition
+    fn solve_banded(
+        shape: BandShape,
+        t: Transpose,
+        ab: &[Self],
+        p: &Pivot,
+        nrhs: i32,
+        b: &mut [Self],
+    ) -> Result<()>;
+
     /// Factorize symmetric/Hermitian matrix using Bunch-Kaufman diagonal pivoting method
     ///
     /// For a given symmetric matrix $A$,
@@ -228,6 +459,8 @@ pub trait Lapack: Scalar {
     fn invh(l: MatrixLayout, uplo: UPLO, a: &mut [Self], ipiv: &Pivot) -> Result<()>;
 
     /// Solve symmetric/Hermitian linear equation $Ax = b$ using the result of [Lapack::bk]
+    ///
+    /// `b` may be a column-major matrix of multiple right hand sides.
     fn solveh(l: MatrixLayout, uplo: UPLO, a: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()>;
 
     /// Solve symmetric/Hermitian positive-definite linear equations using Cholesky decomposition
@@ -246,12 +479,53 @@ pub trait Lapack: Scalar {
     ///
     fn cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()>;
 
+    /// Cholesky decomposition with complete pivoting $ P^T A P = U^H U $ or $ P^T A P = L L^H $
+    /// of a positive semidefinite matrix
+    ///
+    /// Returns the pivot and the computed rank.
+    /// See [cholesky::CholeskyPivotImpl] for detail.
+    fn cholesky_pivot(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        tol: Self::Real,
+    ) -> Result<(Pivot, i32)>;
+
     /// Compute inverse matrix $A^{-1}$ using $U$ or $L$ calculated by [Lapack::cholesky]
     fn inv_cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()>;
 
     /// Solve linear equation $Ax = b$ using $U$ or $L$ calculated by [Lapack::cholesky]
+    ///
+    /// `b` may be a column-major matrix of multiple right hand sides.
     fn solve_cholesky(l: MatrixLayout, uplo: UPLO, a: &[Self], b: &mut [Self]) -> Result<()>;
 
+    /// Cholesky decomposition of a band matrix in the band storage of the `uplo` triangle
+    fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()>;
+
+    /// Solve linear equations for column-major `b` using the factor by [Lapack::cholesky_banded]
+    fn solve_cholesky_banded(
+        uplo: UPLO,
+        n: i32,
+        kd: i32,
+        ab: &[Self],
+        nrhs: i32,
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Solve Sylvester equation $\mathrm{op}(A) X \pm X \mathrm{op}(B) = s C$ for
+    /// column-major upper (quasi-)triangular $A$ and $B$
+    ///
+    /// `c` is overwritten by $X$, and the scale factor $s \le 1$ is returned.
+    fn sylvester(
+        trans_a: Transpose,
+        trans_b: Transpose,
+        isgn: i32,
+        m: i32,
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    ) -> Result<Self::Real>;
+
     /// Estimates the the reciprocal of the condition number of the matrix in 1-norm.
     ///
     /// `anorm` should be the 1-norm of the matrix `a`.
@@ -303,6 +577,19 @@ pub trait Lapack: Scalar {
         b: &mut [Self],
     ) -> Result<()>;
 
+    /// Solve `op(A) X = B` or `X op(A) = B` for a triangular `A` in place of `b`
+    #[allow(clippy::too_many_arguments)]
+    fn solve_triangular_side(
+        al: MatrixLayout,
+        bl: MatrixLayout,
+        side: Side,
+        uplo: UPLO,
+        d: Diag,
+        t: Transpose,
+        a: &[Self],
+        b: &mut [Self],
+    ) -> Result<()>;
+
     /// Computes the LU factorization of a tridiagonal `m x n` matrix `a` using
     /// partial pivoting with row interchanges.
     fn lu_tridiagonal(a: Tridiagonal<Self>) -> Result<LUFactorizedTridiagonal<Self>>;
@@ -315,6 +602,30 @@ pub trait Lapack: Scalar {
         t: Transpose,
         b: &mut [Self],
     ) -> Result<()>;
+
+    /// Compute row and column scale factors to equilibrate a general matrix
+    fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<Equilibration<Self::Real>>;
+
+    /// Balance a general matrix by scaling, overwrite `a` by $ D^{-1} A D $ and return the diagonal of $ D $
+    fn balance(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self::Real>>;
+
+    /// Compute the Gram matrix $ \mathrm{op}(A) \mathrm{op}(A)^H $ using `syrk`/`herk`
+    ///
+    /// `Transpose::No` gives $ A A^H $, and `Transpose::Hermite` gives $ A^H A $.
+    /// The result is an exactly symmetric/Hermitian column-major matrix.
+    /// `Transpose::Transpose` is rejected for complex matrices.
+    fn gram(l: MatrixLayout, t: Transpose, a: &[Self]) -> Result<Vec<Self>>;
+
+    /// Generate a random matrix with prescribed singular values,
+    /// or eigenvalues for [Symmetry::Hermitian], using `*latms`
+    ///
+    /// `iseed` must have entries in `0..4096` with odd `iseed[3]`, and is updated.
+    fn latms(
+        l: MatrixLayout,
+        sym: Symmetry,
+        d: &[Self::Real],
+        iseed: &mut [i32; 4],
+    ) -> Result<Vec<Self>>;
 }
 
 macro_rules! impl_lapack {
@@ -331,6 +642,42 @@ macro_rules! impl_lapack {
                 Ok((eigs, vr.or(vl).unwrap_or_default()))
             }
 
+            fn eig_cond(l: MatrixLayout, a: &mut [Self]) -> Result<EigCondOwned<Self>> {
+                use eig_cond::*;
+                let work = EigCondWork::<$s>::new(l)?;
+                work.eval(a)
+            }
+
+            fn eig_generalized(
+                calc_v: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+                thresh_opt: Option<Self::Real>,
+            ) -> Result<GeneralizedEigenpairs<Self>> {
+                use eig_generalized::*;
+                let work = EigGeneralizedWork::<$s>::new(calc_v, l)?;
+                let owned = work.eval(a, b)?;
+                Ok(GeneralizedEigenpairs {
+                    eigs: owned.eigs(thresh_opt),
+                    vecs: owned.vr.or(owned.vl).unwrap_or_default(),
+                })
+            }
+
+            fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)> {
+                use schur::*;
+                SchurImpl::schur(l, a)
+            }
+
+            fn qz(
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<(Vec<Self>, Vec<Self>)> {
+                use qz::*;
+                QZImpl::qz(l, a, b)
+            }
+
             fn eigh(
                 calc_eigenvec: bool,
                 layout: MatrixLayout,
@@ -354,12 +701,75 @@ macro_rules! impl_lapack {
                 work.eval(uplo, a, b)
             }
 
+            fn eigh_selected(
+                calc_eigenvec: bool,
+                layout: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                range: &EigRange<Self::Real>,
+            ) -> Result<(Vec<Self::Real>, Vec<Self>)> {
+                use eigh_selected::*;
+                EighSelectedImpl::eigh_selected(calc_eigenvec, layout, uplo, a, range)
+            }
+
+            fn eigh_banded(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &mut [Self],
+            ) -> Result<(Vec<Self::Real>, Vec<Self>)> {
+                use eigh_banded::*;
+                EighBandedImpl::eigh_banded(calc_eigenvec, uplo, n, kd, ab)
+            }
+
+            fn tridiagonalize(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+            ) -> Result<TridiagonalizeOwned<Self>> {
+                use tridiagonalize::*;
+                let work = TridiagonalizeWork::<$s>::new(l)?;
+                work.eval(uplo, a)
+            }
+
+            fn q_tridiagonal(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                tau: &[Self],
+            ) -> Result<()> {
+                use tridiagonalize::*;
+                let mut work = QTridiagonalWork::<$s>::new(l)?;
+                work.calc(uplo, a, tau)
+            }
+
+            fn bidiagonalize(l: MatrixLayout, a: &mut [Self]) -> Result<BidiagonalOwned<Self>> {
+                use bidiagonalize::*;
+                BidiagonalizeImpl::bidiagonalize(l, a)
+            }
+
+            fn q_bidiagonal(l: MatrixLayout, a: &[Self], tauq: &[Self]) -> Result<Vec<Self>> {
+                use bidiagonalize::*;
+                BidiagonalizeImpl::q_bidiagonal(l, a, tauq)
+            }
+
+            fn pt_bidiagonal(l: MatrixLayout, a: &[Self], taup: &[Self]) -> Result<Vec<Self>> {
+                use bidiagonalize::*;
+                BidiagonalizeImpl::pt_bidiagonal(l, a, taup)
+            }
+
             fn householder(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
                 use qr::*;
                 let work = HouseholderWork::<$s>::new(l)?;
                 work.eval(a)
             }
 
+            fn householder_pivot(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Vec<i32>)> {
+                use qr::*;
+                HouseholderPivotImpl::householder_pivot(l, a)
+            }
+
             fn q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()> {
                 use qr::*;
                 let mut work = QWork::<$s>::new(l)?;
@@ -374,6 +784,34 @@ macro_rules! impl_lapack {
                 Ok(r)
             }
 
+            fn apply_q(
+                al: MatrixLayout,
+                side: Side,
+                trans: Transpose,
+                a: &[Self],
+                tau: &[Self],
+                cl: MatrixLayout,
+                c: &mut [Self],
+            ) -> Result<()> {
+                use qr::*;
+                ApplyQImpl::apply_q(al, side, trans, a, tau, cl, c)
+            }
+
+            fn lq(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
+                // LQ-decomposition of A is QR-decomposition of the transposed layout
+                Self::qr(l.t(), a)
+            }
+
+            fn ql(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
+                use qr::*;
+                let work = HouseholderQLWork::<$s>::new(l)?;
+                let tau = work.eval(a)?;
+                let r = Vec::from(&*a);
+                let mut work = QLQWork::<$s>::new(l)?;
+                work.calc(a, &tau)?;
+                Ok(r)
+            }
+
             fn svd(
                 l: MatrixLayout,
                 calc_u: bool,
@@ -385,19 +823,54 @@ macro_rules! impl_lapack {
                 work.eval(a)
             }
 
+            fn svd_batch<E, F>(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+                mut f: F,
+            ) -> std::result::Result<(), E>
+            where
+                E: From<Error>,
+                F: FnMut(usize, SvdRef<'_, Self>) -> std::result::Result<(), E>,
+            {
+                use svd::*;
+                let (m, n) = l.size();
+                let size = (m * n) as usize;
+                if size == 0 || a.len() % size != 0 {
+                    return Err(Error::InvalidShape.into());
+                }
+                let mut work = SvdWork::<$s>::new(l, calc_u, calc_vt)?;
+                for (i, a) in a.chunks_exact_mut(size).enumerate() {
+                    f(i, work.calc(a)?)?;
+                }
+                Ok(())
+            }
+
             fn svddc(layout: MatrixLayout, jobz: JobSvd, a: &mut [Self]) -> Result<SvdOwned<Self>> {
                 use svddc::*;
                 let work = SvdDcWork::<$s>::new(layout, jobz)?;
                 work.eval(a)
             }
 
+            fn svddc_partial(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SvdOwned<Self>> {
+                use svddc::*;
+                SvdDcPartialImpl::svddc_partial(l, calc_u, calc_vt, a)
+            }
+
             fn least_squares(
                 l: MatrixLayout,
                 a: &mut [Self],
                 b: &mut [Self],
+                rcond: Self::Real,
             ) -> Result<LeastSquaresOwned<Self>> {
                 let b_layout = l.resized(b.len() as i32, 1);
-                Self::least_squares_nrhs(l, a, b_layout, b)
+                Self::least_squares_nrhs(l, a, b_layout, b, rcond)
             }
 
             fn least_squares_nrhs(
@@ -405,10 +878,21 @@ macro_rules! impl_lapack {
                 a: &mut [Self],
                 b_layout: MatrixLayout,
                 b: &mut [Self],
+                rcond: Self::Real,
             ) -> Result<LeastSquaresOwned<Self>> {
                 use least_squares::*;
                 let work = LeastSquaresWork::<$s>::new(a_layout, b_layout)?;
-                work.eval(a, b)
+                work.eval(a, b, rcond)
+            }
+
+            fn least_squares_qr(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use least_squares::*;
+                LeastSquaresQrImpl::least_squares_qr(a_layout, a, b_layout, b)
             }
 
             fn lu(l: MatrixLayout, a: &mut [Self]) -> Result<Pivot> {
@@ -434,6 +918,36 @@ macro_rules! impl_lapack {
                 SolveImpl::solve(l, t, a, p, b)
             }
 
+            fn solve_refine(
+                l: MatrixLayout,
+                t: Transpose,
+                a: &[Self],
+                af: &[Self],
+                p: &Pivot,
+                b: &[Self],
+                x: &mut [Self],
+            ) -> Result<ErrorBounds<Self::Real>> {
+                use solve::*;
+                SolveRefineImpl::solve_refine(l, t, a, af, p, b, x)
+            }
+
+            fn lu_banded(shape: BandShape, ab: &mut [Self]) -> Result<Pivot> {
+                use solve_banded::*;
+                LuBandedImpl::lu_banded(shape, ab)
+            }
+
+            fn solve_banded(
+                shape: BandShape,
+                t: Transpose,
+                ab: &[Self],
+                p: &Pivot,
+                nrhs: i32,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use solve_banded::*;
+                SolveBandedImpl::solve_banded(shape, t, ab, p, nrhs, b)
+            }
+
             fn bk(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<Pivot> {
                 use solveh::*;
                 let work = BkWork::<$s>::new(l)?;
@@ -462,6 +976,16 @@ macro_rules! impl_lapack {
                 CholeskyImpl::cholesky(l, uplo, a)
             }
 
+            fn cholesky_pivot(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                tol: Self::Real,
+            ) -> Result<(Pivot, i32)> {
+                use cholesky::*;
+                CholeskyPivotImpl::cholesky_pivot(l, uplo, a, tol)
+            }
+
             fn inv_cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()> {
                 use cholesky::*;
                 InvCholeskyImpl::inv_cholesky(l, uplo, a)
@@ -477,6 +1001,36 @@ macro_rules! impl_lapack {
                 SolveCholeskyImpl::solve_cholesky(l, uplo, a, b)
             }
 
+            fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()> {
+                use cholesky_banded::*;
+                CholeskyBandedImpl::cholesky_banded(uplo, n, kd, ab)
+            }
+
+            fn solve_cholesky_banded(
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &[Self],
+                nrhs: i32,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use cholesky_banded::*;
+                SolveCholeskyBandedImpl::solve_cholesky_banded(uplo, n, kd, ab, nrhs, b)
+            }
+
+            fn sylvester(
+                trans_a: Transpose,
+                trans_b: Transpose,
+                isgn: i32,
+                m: i32,
+                a: &[Self],
+                b: &[Self],
+                c: &mut [Self],
+            ) -> Result<Self::Real> {
+                use sylvester::*;
+                SylvesterImpl::sylvester(trans_a, trans_b, isgn, m, a, b, c)
+            }
+
             fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real> {
                 use rcond::*;
                 let mut work = RcondWork::<$s>::new(l);
@@ -501,6 +1055,20 @@ macro_rules! impl_lapack {
                 SolveTriangularImpl::solve_triangular(al, bl, uplo, d, a, b)
             }
 
+            fn solve_triangular_side(
+                al: MatrixLayout,
+                bl: MatrixLayout,
+                side: Side,
+                uplo: UPLO,
+                d: Diag,
+                t: Transpose,
+                a: &[Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                use triangular::*;
+                SolveTriangularSideImpl::solve_triangular_side(al, bl, side, uplo, d, t, a, b)
+            }
+
             fn lu_tridiagonal(a: Tridiagonal<Self>) -> Result<LUFactorizedTridiagonal<Self>> {
                 use tridiagonal::*;
                 let work = LuTridiagonalWork::<$s>::new(a.l);
@@ -522,6 +1090,31 @@ macro_rules! impl_lapack {
                 use tridiagonal::*;
                 SolveTridiagonalImpl::solve_tridiagonal(lu, bl, t, b)
             }
+
+            fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<Equilibration<Self::Real>> {
+                use equilibrate::*;
+                EquilibrateImpl::equilibrate(l, a)
+            }
+
+            fn balance(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self::Real>> {
+                use balance::*;
+                BalanceImpl::balance(l, a)
+            }
+
+            fn latms(
+                l: MatrixLayout,
+                sym: Symmetry,
+                d: &[Self::Real],
+                iseed: &mut [i32; 4],
+            ) -> Result<Vec<Self>> {
+                use latms::*;
+                LatmsImpl::latms(l, sym, d, iseed)
+            }
+
+            fn gram(l: MatrixLayout, t: Transpose, a: &[Self]) -> Result<Vec<Self>> {
+                use rank_k::*;
+                RankKImpl::gram(l, t, a)
+            }
         }
     };
 }
@@ -1,4 +1,4 @@
-//! QR decomposition
+//! QR, LQ and QL decompositions
 
 use crate::{error::*, layout::MatrixLayout, *};
 use cauchy::*;
@@ -214,3 +214,460 @@ impl_q_work!(c64, lapack_sys::zungqr_, lapack_sys::zunglq_);
 impl_q_work!(c32, lapack_sys::cungqr_, lapack_sys::cunglq_);
 impl_q_work!(f64, lapack_sys::dorgqr_, lapack_sys::dorglq_);
 impl_q_work!(f32, lapack_sys::sorgqr_, lapack_sys::sorglq_);
+
+pub struct HouseholderQLWork<T: Scalar> {
+    pub m: i32,
+    pub n: i32,
+    pub layout: MatrixLayout,
+    pub tau: Vec<MaybeUninit<T>>,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+/// Householder reflection as the first step of QL-decomposition
+///
+/// For C-continuous array, this calls RQ-decomposition of the transposed matrix $ A^T = L^T Q^T $
+pub trait HouseholderQLWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(l: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem]) -> Result<&[Self::Elem]>;
+    fn eval(self, a: &mut [Self::Elem]) -> Result<Vec<Self::Elem>>;
+}
+
+macro_rules! impl_householder_ql_work {
+    ($s:ty, $qlf:path, $rqf: path) => {
+        impl HouseholderQLWorkImpl for HouseholderQLWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let m = layout.lda();
+                let n = layout.len();
+                let k = m.min(n);
+                let mut tau = vec_uninit(k as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                match layout {
+                    MatrixLayout::F { .. } => unsafe {
+                        $qlf(
+                            &m,
+                            &n,
+                            std::ptr::null_mut(),
+                            &m,
+                            AsPtr::as_mut_ptr(&mut tau),
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        )
+                    },
+                    MatrixLayout::C { .. } => unsafe {
+                        $rqf(
+                            &m,
+                            &n,
+                            std::ptr::null_mut(),
+                            &m,
+                            AsPtr::as_mut_ptr(&mut tau),
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        )
+                    },
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(HouseholderQLWork {
+                    n,
+                    m,
+                    layout,
+                    tau,
+                    work,
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem]) -> Result<&[Self::Elem]> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                match self.layout {
+                    MatrixLayout::F { .. } => unsafe {
+                        $qlf(
+                            &self.m,
+                            &self.n,
+                            AsPtr::as_mut_ptr(a),
+                            &self.m,
+                            AsPtr::as_mut_ptr(&mut self.tau),
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            &mut info,
+                        );
+                    },
+                    MatrixLayout::C { .. } => unsafe {
+                        $rqf(
+                            &self.m,
+                            &self.n,
+                            AsPtr::as_mut_ptr(a),
+                            &self.m,
+                            AsPtr::as_mut_ptr(&mut self.tau),
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            &mut info,
+                        );
+                    },
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { self.tau.slice_assume_init_ref() })
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<Vec<Self::Elem>> {
+                let _eig = self.calc(a)?;
+                Ok(unsafe { self.tau.assume_init() })
+            }
+        }
+    };
+}
+impl_householder_ql_work!(c64, lapack_sys::zgeqlf_, lapack_sys::zgerqf_);
+impl_householder_ql_work!(c32, lapack_sys::cgeqlf_, lapack_sys::cgerqf_);
+impl_householder_ql_work!(f64, lapack_sys::dgeqlf_, lapack_sys::dgerqf_);
+impl_householder_ql_work!(f32, lapack_sys::sgeqlf_, lapack_sys::sgerqf_);
+
+pub struct QLQWork<T: Scalar> {
+    pub layout: MatrixLayout,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+/// Reconstruct Q-matrix of QL-decomposition from Householder-reflectors
+///
+/// The `m x k` matrix Q, where `k = min(m, n)`, is stored in the last `k`
+/// columns of the `m x n` matrix.
+pub trait QLQWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(layout: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()>;
+}
+
+macro_rules! impl_ql_q_work {
+    ($s:ty, $gql:path, $grq:path) => {
+        impl QLQWorkImpl for QLQWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let m = layout.lda();
+                let n = layout.len();
+                let k = m.min(n);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                match layout {
+                    MatrixLayout::F { .. } => unsafe {
+                        $gql(
+                            &m,
+                            &k,
+                            &k,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        )
+                    },
+                    MatrixLayout::C { .. } => unsafe {
+                        $grq(
+                            &k,
+                            &n,
+                            &k,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        )
+                    },
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(QLQWork { layout, work })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()> {
+                let m = self.layout.lda();
+                let n = self.layout.len();
+                let k = m.min(n);
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                match self.layout {
+                    // Reflectors are stored in the last `k` columns
+                    MatrixLayout::F { .. } => unsafe {
+                        $gql(
+                            &m,
+                            &k,
+                            &k,
+                            AsPtr::as_mut_ptr(&mut a[((n - k) * m) as usize..]),
+                            &m,
+                            AsPtr::as_ptr(&tau),
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            &mut info,
+                        )
+                    },
+                    // Reflectors are stored in the last `k` rows
+                    MatrixLayout::C { .. } => unsafe {
+                        $grq(
+                            &k,
+                            &n,
+                            &k,
+                            AsPtr::as_mut_ptr(&mut a[(m - k) as usize..]),
+                            &m,
+                            AsPtr::as_ptr(&tau),
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            &mut info,
+                        )
+                    },
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_ql_q_work!(c64, lapack_sys::zungql_, lapack_sys::zungrq_);
+impl_ql_q_work!(c32, lapack_sys::cungql_, lapack_sys::cungrq_);
+impl_ql_q_work!(f64, lapack_sys::dorgql_, lapack_sys::dorgrq_);
+impl_ql_q_work!(f32, lapack_sys::sorgql_, lapack_sys::sorgrq_);
+
+/// Multiply a matrix by Q of QR-decomposition without constructing Q
+///
+/// `a` is the `m x k` (Side::Left) or `n x k` (Side::Right) matrix of
+/// Householder reflectors computed by [HouseholderWork] in Fortran layout,
+/// and `c` of `m x n` is overwritten by `op(Q) * C` or `C * op(Q)`.
+/// Both `a` and `c` must be in Fortran layout.
+/// [Transpose::Hermite] is regarded as [Transpose::Transpose] for real matrices.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | sormqr | dormqr | cunmqr | zunmqr |
+///
+pub trait ApplyQImpl: Scalar {
+    fn apply_q(
+        al: MatrixLayout,
+        side: Side,
+        trans: Transpose,
+        a: &[Self],
+        tau: &[Self],
+        cl: MatrixLayout,
+        c: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_apply_q {
+    ($s:ty, $mqr:path, $hermite:expr) => {
+        impl ApplyQImpl for $s {
+            fn apply_q(
+                al: MatrixLayout,
+                side: Side,
+                trans: Transpose,
+                a: &[Self],
+                tau: &[Self],
+                cl: MatrixLayout,
+                c: &mut [Self],
+            ) -> Result<()> {
+                let (lda, ldc) = match (al, cl) {
+                    (MatrixLayout::F { lda, .. }, MatrixLayout::F { .. }) => (lda, cl.lda()),
+                    _ => return Err(Error::InvalidShape),
+                };
+                let (m, n) = cl.size();
+                let k = tau.len() as i32;
+                let trans = match trans {
+                    Transpose::Hermite => $hermite,
+                    t => t,
+                };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $mqr(
+                        side.as_ptr(),
+                        trans.as_ptr(),
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_ptr(a),
+                        &lda,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(c),
+                        &ldc,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // apply Q
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $mqr(
+                        side.as_ptr(),
+                        trans.as_ptr(),
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_ptr(a),
+                        &lda,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(c),
+                        &ldc,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_apply_q!(c64, lapack_sys::zunmqr_, Transpose::Hermite);
+impl_apply_q!(c32, lapack_sys::cunmqr_, Transpose::Hermite);
+impl_apply_q!(f64, lapack_sys::dormqr_, Transpose::Transpose);
+impl_apply_q!(f32, lapack_sys::sormqr_, Transpose::Transpose);
+
+/// QR-decomposition with column pivoting $ A P = Q R $
+///
+/// `a` must be in Fortran layout, and is overwritten by the Householder
+/// reflectors and `R` in the same form as [HouseholderWork]. Returns the scalar
+/// factors `tau` and the permutation, where the `j`-th column of $AP$ is
+/// the `jpvt[j]`-th column of $A$ (1-based as LAPACK).
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | sgeqp3 | dgeqp3 | cgeqp3 | zgeqp3 |
+///
+pub trait HouseholderPivotImpl: Scalar {
+    fn householder_pivot(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Vec<i32>)>;
+}
+
+macro_rules! impl_householder_pivot_c {
+    ($s:ty, $qp3:path) => {
+        impl HouseholderPivotImpl for $s {
+            fn householder_pivot(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Vec<i32>)> {
+                let (m, n) = match l {
+                    MatrixLayout::F { col, lda } => (lda, col),
+                    MatrixLayout::C { .. } => return Err(Error::InvalidShape),
+                };
+                let k = m.min(n);
+                // All columns are free
+                let mut jpvt = vec![0; n as usize];
+                let mut tau: Vec<MaybeUninit<Self>> = vec_uninit(k as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(2 * n as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // factorize
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok((unsafe { tau.assume_init() }, jpvt))
+            }
+        }
+    };
+}
+impl_householder_pivot_c!(c64, lapack_sys::zgeqp3_);
+impl_householder_pivot_c!(c32, lapack_sys::cgeqp3_);
+
+macro_rules! impl_householder_pivot_r {
+    ($s:ty, $qp3:path) => {
+        impl HouseholderPivotImpl for $s {
+            fn householder_pivot(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Vec<i32>)> {
+                let (m, n) = match l {
+                    MatrixLayout::F { col, lda } => (lda, col),
+                    MatrixLayout::C { .. } => return Err(Error::InvalidShape),
+                };
+                let k = m.min(n);
+                // All columns are free
+                let mut jpvt = vec![0; n as usize];
+                let mut tau: Vec<MaybeUninit<Self>> = vec_uninit(k as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // factorize
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok((unsafe { tau.assume_init() }, jpvt))
+            }
+        }
+    };
+}
+impl_householder_pivot_r!(f64, lapack_sys::dgeqp3_);
+impl_householder_pivot_r!(f32, lapack_sys::sgeqp3_);
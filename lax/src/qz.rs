@@ -0,0 +1,218 @@
+//! Generalized Schur (QZ) decomposition of matrix pencils
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32   | f64   | c32   | c64   |
+//! |:------|:------|:------|:------|
+//! | sgges | dgges | cgges | zgges |
+//!
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized Schur decomposition $ A = Q S Z^\dagger, B = Q T Z^\dagger $
+/// of a square matrix pencil $(A, B)$
+///
+/// `a` and `b` are overwritten by $S$ and $T$. $T$ is upper triangular, and
+/// $S$ is upper triangular for complex matrices and upper quasi-triangular
+/// with 1x1 and 2x2 diagonal blocks for real matrices. Returns the unitary
+/// (orthogonal) matrices $Q$ and $Z$ of the left and right Schur vectors in
+/// the same layout as `a` and `b`.
+pub trait QZImpl: Scalar {
+    fn qz(l: MatrixLayout, a: &mut [Self], b: &mut [Self]) -> Result<(Vec<Self>, Vec<Self>)>;
+}
+
+macro_rules! impl_qz_c {
+    ($s:ty, $gges:path) => {
+        impl QZImpl for $s {
+            fn qz(
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<(Vec<Self>, Vec<Self>)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                }
+                let mut sdim = 0;
+                let mut alpha: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut beta: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut vsl: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+                let mut vsr: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(8 * n as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gges(
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &(b'N' as i8), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(&mut vsl),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut vsr),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gges(
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &(b'N' as i8), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(&mut vsl),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut vsr),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let mut vsl = unsafe { vsl.assume_init() };
+                let mut vsr = unsafe { vsr.assume_init() };
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                    square_transpose(l, &mut vsl);
+                    square_transpose(l, &mut vsr);
+                }
+                Ok((vsl, vsr))
+            }
+        }
+    };
+}
+impl_qz_c!(c64, lapack_sys::zgges_);
+impl_qz_c!(c32, lapack_sys::cgges_);
+
+macro_rules! impl_qz_r {
+    ($s:ty, $gges:path) => {
+        impl QZImpl for $s {
+            fn qz(
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<(Vec<Self>, Vec<Self>)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                }
+                let mut sdim = 0;
+                let mut alpha_re: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut alpha_im: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut beta: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut vsl: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+                let mut vsr: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gges(
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &(b'N' as i8), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha_re),
+                        AsPtr::as_mut_ptr(&mut alpha_im),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(&mut vsl),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut vsr),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gges(
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &(b'N' as i8), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha_re),
+                        AsPtr::as_mut_ptr(&mut alpha_im),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(&mut vsl),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut vsr),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let mut vsl = unsafe { vsl.assume_init() };
+                let mut vsr = unsafe { vsr.assume_init() };
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                    square_transpose(l, &mut vsl);
+                    square_transpose(l, &mut vsr);
+                }
+                Ok((vsl, vsr))
+            }
+        }
+    };
+}
+impl_qz_r!(f64, lapack_sys::dgges_);
+impl_qz_r!(f32, lapack_sys::sgges_);
@@ -0,0 +1,95 @@
+//! Symmetric/Hermitian rank-k product
+//!
+//! BLAS correspondance
+//! --------------------
+//!
+//! | f32   | f64   | c32   | c64   |
+//! |:------|:------|:------|:------|
+//! | ssyrk | dsyrk | cherk | zherk |
+//!
+//! These are called through CBLAS, which every BLAS backend of this crate
+//! already builds (the `cblas` feature of `netlib-src` and `openblas-src`,
+//! and Intel MKL natively), and which `ndarray` links through `cblas-sys`
+//! for its `blas` feature anyway.
+//!
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{One, Zero};
+
+/// Compute the Gram matrix `op(A) op(A)^H` using only one triangle
+///
+/// The other triangle is filled by the (conjugate) transpose,
+/// so that the result is exactly symmetric/Hermitian.
+/// `Transpose::Transpose` is accepted only for real matrices,
+/// where it is identical to `Transpose::Hermite`.
+pub trait RankKImpl: Scalar {
+    fn gram(l: MatrixLayout, t: Transpose, a: &[Self]) -> Result<Vec<Self>>;
+}
+
+macro_rules! impl_rank_k {
+    ($s:ty, $rk:path, $conj:expr, $complex:expr) => {
+        impl RankKImpl for $s {
+            fn gram(l: MatrixLayout, t: Transpose, a: &[Self]) -> Result<Vec<Self>> {
+                if t == Transpose::Transpose && $complex {
+                    return Err(Error::InvalidFlag { flag: 'T' });
+                }
+                // A row-major `A` is a column-major `A^T`, and
+                // `A^T (A^T)^H = conj(A^H A)`, `(A^T)^H A^T = conj(A A^H)`.
+                // Compute them with the opposite `trans` and conjugate back.
+                let (rows, cols) = match l {
+                    MatrixLayout::F { col, lda } => (lda, col),
+                    MatrixLayout::C { row, lda } => (lda, row),
+                };
+                let no_trans = match l {
+                    MatrixLayout::F { .. } => t == Transpose::No,
+                    MatrixLayout::C { .. } => t != Transpose::No,
+                };
+                let (trans, dim, k) = if no_trans {
+                    (cblas_sys::CblasNoTrans, rows, cols)
+                } else {
+                    (cblas_sys::CblasConjTrans, cols, rows)
+                };
+                let mut c = vec![Self::zero(); (dim as usize) * (dim as usize)];
+                if dim == 0 {
+                    return Ok(c);
+                }
+                unsafe {
+                    $rk(
+                        cblas_sys::CblasColMajor,
+                        cblas_sys::CblasUpper,
+                        trans,
+                        dim,
+                        k,
+                        <Self as Scalar>::Real::one(),
+                        a.as_ptr() as *const _,
+                        l.lda(),
+                        <Self as Scalar>::Real::zero(),
+                        c.as_mut_ptr() as *mut _,
+                        dim,
+                    );
+                }
+                // Fill the lower triangle, and drop the rounding errors in
+                // the imaginary part of the diagonal
+                let dim = dim as usize;
+                for j in 0..dim {
+                    for i in (j + 1)..dim {
+                        c[i + j * dim] = $conj(c[j + i * dim]);
+                    }
+                    c[j + j * dim] = Self::from_real(c[j + j * dim].re());
+                }
+                if matches!(l, MatrixLayout::C { .. }) {
+                    for x in c.iter_mut() {
+                        *x = $conj(*x);
+                    }
+                }
+                Ok(c)
+            }
+        }
+    };
+}
+
+impl_rank_k!(f64, cblas_sys::cblas_dsyrk, |x: f64| x, false);
+impl_rank_k!(f32, cblas_sys::cblas_ssyrk, |x: f32| x, false);
+impl_rank_k!(c64, cblas_sys::cblas_zherk, |x: c64| x.conj(), true);
+impl_rank_k!(c32, cblas_sys::cblas_cherk, |x: c32| x.conj(), true);
@@ -0,0 +1,180 @@
+//! Schur decomposition of general matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32   | f64   | c32   | c64   |
+//! |:------|:------|:------|:------|
+//! | sgees | dgees | cgees | zgees |
+//!
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Schur decomposition $ A = Z T Z^\dagger $ of a square matrix
+///
+/// `a` is overwritten by the Schur form $T$, which is upper triangular for
+/// complex matrices, and upper quasi-triangular with 1x1 and 2x2 diagonal
+/// blocks for real matrices. Returns the eigenvalues and the unitary
+/// (orthogonal) matrix $Z$ of Schur vectors in the same layout as `a`.
+pub trait SchurImpl: Scalar {
+    fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)>;
+}
+
+macro_rules! impl_schur_c {
+    ($s:ty, $gees:path) => {
+        impl SchurImpl for $s {
+            fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut sdim = 0;
+                let mut eigs: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut vs: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gees(
+                        JobEv::All.as_ptr(),
+                        &(b'N' as i8), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut vs),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gees(
+                        JobEv::All.as_ptr(),
+                        &(b'N' as i8), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut vs),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let eigs = unsafe { eigs.assume_init() };
+                let mut vs = unsafe { vs.assume_init() };
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, &mut vs);
+                }
+                Ok((eigs, vs))
+            }
+        }
+    };
+}
+impl_schur_c!(c64, lapack_sys::zgees_);
+impl_schur_c!(c32, lapack_sys::cgees_);
+
+macro_rules! impl_schur_r {
+    ($s:ty, $gees:path) => {
+        impl SchurImpl for $s {
+            fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut sdim = 0;
+                let mut eigs_re: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut eigs_im: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut vs: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gees(
+                        JobEv::All.as_ptr(),
+                        &(b'N' as i8), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut eigs_re),
+                        AsPtr::as_mut_ptr(&mut eigs_im),
+                        AsPtr::as_mut_ptr(&mut vs),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gees(
+                        JobEv::All.as_ptr(),
+                        &(b'N' as i8), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut eigs_re),
+                        AsPtr::as_mut_ptr(&mut eigs_im),
+                        AsPtr::as_mut_ptr(&mut vs),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let eigs_re = unsafe { eigs_re.assume_init() };
+                let eigs_im = unsafe { eigs_im.assume_init() };
+                let eigs = eigs_re
+                    .iter()
+                    .zip(eigs_im.iter())
+                    .map(|(&re, &im)| Self::complex(re, im))
+                    .collect();
+                let mut vs = unsafe { vs.assume_init() };
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, &mut vs);
+                }
+                Ok((eigs, vs))
+            }
+        }
+    };
+}
+impl_schur_r!(f64, lapack_sys::dgees_);
+impl_schur_r!(f32, lapack_sys::sgees_);
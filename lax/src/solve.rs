@@ -79,6 +79,10 @@ impl_lu!(f32, lapack_sys::sgetrf_);
 /// be reinterpreted as Fortran layout) and applying the
 /// elementwise conjugate to `x` and `b`.
 ///
+/// `b` is a column-major `n x nrhs` matrix, i.e. `nrhs` right hand sides
+/// are solved at once, where `nrhs = b.len() / n`. This panics if `b.len()`
+/// is not a multiple of `n`.
+///
 pub trait SolveImpl: Scalar {
     /// LAPACK correspondance
     /// ----------------------
@@ -109,7 +113,15 @@ macro_rules! impl_solve {
                     MatrixLayout::F { .. } => (t, false),
                 };
                 let (n, _) = l.size();
-                let nrhs = 1;
+                if n == 0 {
+                    return Ok(());
+                }
+                assert_eq!(
+                    b.len() % n as usize,
+                    0,
+                    "The length of `b` must be a multiple of the size of `a`"
+                );
+                let nrhs = b.len() as i32 / n;
                 let ldb = l.lda();
                 let mut info = 0;
                 if conj {
@@ -147,6 +159,125 @@ impl_solve!(f32, lapack_sys::sgetrs_);
 impl_solve!(c64, lapack_sys::zgetrs_);
 impl_solve!(c32, lapack_sys::cgetrs_);
 
+/// Error bounds of the refined solutions of [SolveRefineImpl::solve_refine]
+#[derive(Debug, Clone)]
+pub struct ErrorBounds<R> {
+    /// Estimated forward error bound for each right hand side
+    pub ferr: Vec<R>,
+    /// Componentwise relative backward error for each right hand side
+    pub berr: Vec<R>,
+}
+
+/// Helper trait to abstract `*gerfs` LAPACK routines for implementing [Lapack::solve_refine]
+///
+/// `a` is the original matrix, and `af` and `ipiv` are its LU-decomposition
+/// computed by [Lapack::lu] with the same layout. `x` is a computed solution
+/// of `op(A) X = B` for the column-major right hand sides `b`, and it is
+/// improved in place. The C layout case is handled in the same way as [SolveImpl].
+///
+pub trait SolveRefineImpl: Scalar {
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sgerfs | dgerfs | cgerfs | zgerfs |
+    ///
+    fn solve_refine(
+        l: MatrixLayout,
+        t: Transpose,
+        a: &[Self],
+        af: &[Self],
+        p: &Pivot,
+        b: &[Self],
+        x: &mut [Self],
+    ) -> Result<ErrorBounds<Self::Real>>;
+}
+
+macro_rules! impl_solve_refine {
+    ($scalar:ty, $gerfs:path, $work_size:expr, $work2:ty) => {
+        impl SolveRefineImpl for $scalar {
+            fn solve_refine(
+                l: MatrixLayout,
+                t: Transpose,
+                a: &[Self],
+                af: &[Self],
+                ipiv: &Pivot,
+                b: &[Self],
+                x: &mut [Self],
+            ) -> Result<ErrorBounds<Self::Real>> {
+                let (t, conj) = match l {
+                    MatrixLayout::C { .. } => match t {
+                        Transpose::No => (Transpose::Transpose, false),
+                        Transpose::Transpose => (Transpose::No, false),
+                        Transpose::Hermite => (Transpose::No, true),
+                    },
+                    MatrixLayout::F { .. } => (t, false),
+                };
+                let (n, _) = l.size();
+                assert_eq!(b.len(), x.len());
+                if n == 0 {
+                    return Ok(ErrorBounds {
+                        ferr: Vec::new(),
+                        berr: Vec::new(),
+                    });
+                }
+                let nrhs = b.len() as i32 / n;
+                let b: Vec<Self> = if conj {
+                    b.iter().map(|b_elem| b_elem.conj()).collect()
+                } else {
+                    b.to_vec()
+                };
+                if conj {
+                    for x_elem in &mut *x {
+                        *x_elem = x_elem.conj();
+                    }
+                }
+                let mut ferr = vec_uninit(nrhs as usize);
+                let mut berr = vec_uninit(nrhs as usize);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit($work_size * n as usize);
+                let mut work2: Vec<MaybeUninit<$work2>> = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $gerfs(
+                        t.as_ptr(),
+                        &n,
+                        &nrhs,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_ptr(af),
+                        &l.lda(),
+                        ipiv.as_ptr(),
+                        AsPtr::as_ptr(&b),
+                        &n,
+                        AsPtr::as_mut_ptr(x),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut ferr),
+                        AsPtr::as_mut_ptr(&mut berr),
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut work2),
+                        &mut info,
+                    )
+                };
+                if conj {
+                    for x_elem in &mut *x {
+                        *x_elem = x_elem.conj();
+                    }
+                }
+                info.as_lapack_result()?;
+                let ferr = unsafe { ferr.assume_init() };
+                let berr = unsafe { berr.assume_init() };
+                Ok(ErrorBounds { ferr, berr })
+            }
+        }
+    };
+} // impl_solve_refine!
+
+impl_solve_refine!(f64, lapack_sys::dgerfs_, 3, i32);
+impl_solve_refine!(f32, lapack_sys::sgerfs_, 3, i32);
+impl_solve_refine!(c64, lapack_sys::zgerfs_, 2, f64);
+impl_solve_refine!(c32, lapack_sys::cgerfs_, 2, f32);
+
 /// Working memory for computing inverse matrix
 pub struct InvWork<T: Scalar> {
     pub layout: MatrixLayout,
@@ -0,0 +1,140 @@
+//! Solve linear equations with band matrices using LU-decomposition
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgbtrf | dgbtrf | cgbtrf | zgbtrf |
+//! | sgbtrs | dgbtrs | cgbtrs | zgbtrs |
+//!
+
+use crate::{error::*, *};
+use cauchy::*;
+
+/// Shape of a square band matrix with `kl` sub-diagonals and `ku` super-diagonals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BandShape {
+    pub n: i32,
+    pub kl: i32,
+    pub ku: i32,
+}
+
+impl BandShape {
+    /// Leading dimension of the band storage with `kl` rows for the fill-in
+    pub fn ldab(&self) -> i32 {
+        2 * self.kl + self.ku + 1
+    }
+}
+
+/// Helper trait to abstract `*gbtrf` LAPACK routines for implementing [Lapack::lu_banded]
+///
+/// `ab` is the `(2 kl + ku + 1) x n` column-major band storage, where the
+/// matrix is stored in the rows `kl..` as `ab[kl + ku + i - j, j] = a[i, j]`
+/// and the first `kl` rows are workspace for the fill-in. `ab` is overwritten
+/// by the LU factors.
+pub trait LuBandedImpl: Scalar {
+    fn lu_banded(shape: BandShape, ab: &mut [Self]) -> Result<Pivot>;
+}
+
+macro_rules! impl_lu_banded {
+    ($scalar:ty, $gbtrf:path) => {
+        impl LuBandedImpl for $scalar {
+            fn lu_banded(shape: BandShape, ab: &mut [Self]) -> Result<Pivot> {
+                let BandShape { n, kl, ku } = shape;
+                let ldab = shape.ldab();
+                if ab.len() != (ldab * n) as usize {
+                    return Err(Error::InvalidShape);
+                }
+                if n == 0 {
+                    // Do nothing for empty matrix
+                    return Ok(Vec::new());
+                }
+                let mut ipiv = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $gbtrf(
+                        &n,
+                        &n,
+                        &kl,
+                        &ku,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let ipiv = unsafe { ipiv.assume_init() };
+                Ok(ipiv)
+            }
+        }
+    };
+}
+
+impl_lu_banded!(c64, lapack_sys::zgbtrf_);
+impl_lu_banded!(c32, lapack_sys::cgbtrf_);
+impl_lu_banded!(f64, lapack_sys::dgbtrf_);
+impl_lu_banded!(f32, lapack_sys::sgbtrf_);
+
+/// Helper trait to abstract `*gbtrs` LAPACK routines for implementing [Lapack::solve_banded]
+///
+/// `ab` and `ipiv` are the LU factors by [LuBandedImpl::lu_banded], and `b`
+/// is the column-major `n x nrhs` right hand sides overwritten by the solution.
+pub trait SolveBandedImpl: Scalar {
+    fn solve_banded(
+        shape: BandShape,
+        t: Transpose,
+        ab: &[Self],
+        ipiv: &Pivot,
+        nrhs: i32,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_solve_banded {
+    ($scalar:ty, $gbtrs:path) => {
+        impl SolveBandedImpl for $scalar {
+            fn solve_banded(
+                shape: BandShape,
+                t: Transpose,
+                ab: &[Self],
+                ipiv: &Pivot,
+                nrhs: i32,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let BandShape { n, kl, ku } = shape;
+                let ldab = shape.ldab();
+                if ab.len() != (ldab * n) as usize || b.len() != (n * nrhs) as usize {
+                    return Err(Error::InvalidShape);
+                }
+                if n == 0 || nrhs == 0 {
+                    return Ok(());
+                }
+                let mut info = 0;
+                unsafe {
+                    $gbtrs(
+                        t.as_ptr(),
+                        &n,
+                        &kl,
+                        &ku,
+                        &nrhs,
+                        AsPtr::as_ptr(ab),
+                        &ldab,
+                        ipiv.as_ptr(),
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_solve_banded!(c64, lapack_sys::zgbtrs_);
+impl_solve_banded!(c32, lapack_sys::cgbtrs_);
+impl_solve_banded!(f64, lapack_sys::dgbtrs_);
+impl_solve_banded!(f32, lapack_sys::sgbtrs_);
@@ -157,6 +157,10 @@ impl_invh_work!(f32, lapack_sys::ssytri_);
 /// |:-------|:-------|:-------|:-------|
 /// | ssytrs | dsytrs | chetrs | zhetrs |
 ///
+/// `b` is a column-major `n x nrhs` matrix, i.e. `nrhs` right hand sides
+/// are solved at once, where `nrhs = b.len() / n`. This panics if `b.len()`
+/// is not a multiple of `n`.
+///
 pub trait SolvehImpl: Scalar {
     fn solveh(l: MatrixLayout, uplo: UPLO, a: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()>;
 }
@@ -172,12 +176,21 @@ macro_rules! impl_solveh_ {
                 b: &mut [Self],
             ) -> Result<()> {
                 let (n, _) = l.size();
+                if n == 0 {
+                    return Ok(());
+                }
+                assert_eq!(
+                    b.len() % n as usize,
+                    0,
+                    "The length of `b` must be a multiple of the size of `a`"
+                );
+                let nrhs = b.len() as i32 / n;
                 let mut info = 0;
                 unsafe {
                     $trs(
                         uplo.as_ptr(),
                         &n,
-                        &1,
+                        &nrhs,
                         AsPtr::as_ptr(a),
                         &l.lda(),
                         ipiv.as_ptr(),
@@ -305,3 +305,122 @@ macro_rules! impl_svd_dc_work_r {
 }
 impl_svd_dc_work_r!(f64, lapack_sys::dgesdd_);
 impl_svd_dc_work_r!(f32, lapack_sys::sgesdd_);
+
+/// Economy SVD by divide-and-conquer computing only the requested singular vectors
+///
+/// `U` is `(m, k)` and `V^T` is `(k, n)` for `k = min(m, n)` as [JobSvd::Some].
+/// If only one of them is requested, `*gesdd` runs with `JOBZ = 'O'`, which
+/// overwrites `a` by the `(m, k)` or `(k, n)` factor and allocates the other
+/// one only if it is the small `(k, k)` factor.
+pub trait SvdDcPartialImpl: Scalar {
+    fn svddc_partial(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+    ) -> Result<SvdOwned<Self>>;
+}
+
+/// `JOBZ = 'O'` of `*gesdd`, which is only used in [SvdDcPartialImpl]
+const JOBZ_OVERWRITE: u8 = b'O';
+
+macro_rules! impl_svd_dc_partial {
+    ($s:ty, $sdd:path, $($rwork:ident)?) => {
+        impl SvdDcPartialImpl for $s {
+            fn svddc_partial(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SvdOwned<Self>> {
+                if calc_u == calc_vt {
+                    let jobz = if calc_u { JobSvd::Some } else { JobSvd::None };
+                    return SvdDcWork::<$s>::new(l, jobz)?.eval(a);
+                }
+                let m = l.lda();
+                let n = l.len();
+                let k = m.min(n);
+                // Only the `(k, k)` factor is allocated, and the other overwrites `a`
+                let (ldu, ldvt) = if m >= n { (1, n) } else { (m, 1) };
+                let mut s = vec_uninit(k as usize);
+                let mut u: Vec<MaybeUninit<Self>> = vec_uninit(if m >= n { 0 } else { (m * m) as usize });
+                let mut vt: Vec<MaybeUninit<Self>> = vec_uninit(if m >= n { (n * n) as usize } else { 0 });
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(8 * k as usize);
+                $(
+                let mx = n.max(m) as usize;
+                let mn = n.min(m) as usize;
+                let mut $rwork: Vec<MaybeUninit<<Self as Scalar>::Real>> = vec_uninit(std::cmp::max(
+                    5 * mn * mn + 5 * mn,
+                    2 * mx * mn + 2 * mn * mn + mn,
+                ));
+                )?
+
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $sdd(
+                        &(JOBZ_OVERWRITE as i8),
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(&mut u),
+                        &ldu,
+                        AsPtr::as_mut_ptr(&mut vt),
+                        &ldvt,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        $(AsPtr::as_mut_ptr(&mut $rwork),)?
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $sdd(
+                        &(JOBZ_OVERWRITE as i8),
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(&mut u),
+                        &ldu,
+                        AsPtr::as_mut_ptr(&mut vt),
+                        &ldvt,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork.to_i32().unwrap(),
+                        $(AsPtr::as_mut_ptr(&mut $rwork),)?
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let s = unsafe { s.assume_init() };
+                // `a` is overwritten by the whole `(m, k)` or `(k, n)` factor
+                let (u, vt) = if m >= n {
+                    (a.to_vec(), unsafe { vt.assume_init() })
+                } else {
+                    (unsafe { u.assume_init() }, a.to_vec())
+                };
+                let (u, vt) = match l {
+                    MatrixLayout::F { .. } => (u, vt),
+                    MatrixLayout::C { .. } => (vt, u),
+                };
+                Ok(SvdOwned {
+                    s,
+                    u: if calc_u { Some(u) } else { None },
+                    vt: if calc_vt { Some(vt) } else { None },
+                })
+            }
+        }
+    };
+}
+impl_svd_dc_partial!(c64, lapack_sys::zgesdd_, rwork);
+impl_svd_dc_partial!(c32, lapack_sys::cgesdd_, rwork);
+impl_svd_dc_partial!(f64, lapack_sys::dgesdd_,);
+impl_svd_dc_partial!(f32, lapack_sys::sgesdd_,);
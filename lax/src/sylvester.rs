@@ -0,0 +1,85 @@
+//! Sylvester equation for (quasi-)triangular matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | strsyl | dtrsyl | ctrsyl | ztrsyl |
+//!
+
+use crate::{error::*, *};
+use cauchy::*;
+use num_traits::Zero;
+
+/// Solve the Sylvester equation `op(A) X + isgn X op(B) = scale C`
+///
+/// `a` is a `m x m` upper triangular matrix and `b` is a `n x n` one, both
+/// in column-major order, which are upper quasi-triangular in the real Schur
+/// canonical form for real scalars. `c` is a column-major `m x n` matrix,
+/// overwritten by `X`. `isgn` must be `1` or `-1`, and `op` must be
+/// `Transpose::No` or `Transpose::Hermite`.
+///
+/// Returns the scale factor `scale <= 1` chosen to avoid overflow in `X`.
+/// [Error::LapackComputationalFailure] is returned if `op(A)` and `-isgn op(B)`
+/// have common or very close eigenvalues, i.e. the equation is (nearly) singular.
+pub trait SylvesterImpl: Scalar {
+    fn sylvester(
+        trans_a: Transpose,
+        trans_b: Transpose,
+        isgn: i32,
+        m: i32,
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    ) -> Result<Self::Real>;
+}
+
+macro_rules! impl_sylvester {
+    ($s:ty, $trsyl:path) => {
+        impl SylvesterImpl for $s {
+            fn sylvester(
+                trans_a: Transpose,
+                trans_b: Transpose,
+                isgn: i32,
+                m: i32,
+                a: &[Self],
+                b: &[Self],
+                c: &mut [Self],
+            ) -> Result<Self::Real> {
+                let n = if m > 0 { c.len() as i32 / m } else { 0 };
+                if a.len() != (m * m) as usize
+                    || b.len() != (n * n) as usize
+                    || c.len() != (m * n) as usize
+                {
+                    return Err(Error::InvalidShape);
+                }
+                let mut scale = Self::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $trsyl(
+                        trans_a.as_ptr(),
+                        trans_b.as_ptr(),
+                        &isgn,
+                        &m,
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_ptr(b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(c),
+                        &m.max(1),
+                        &mut scale,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(scale)
+            }
+        }
+    };
+}
+impl_sylvester!(c64, lapack_sys::ztrsyl_);
+impl_sylvester!(c32, lapack_sys::ctrsyl_);
+impl_sylvester!(f64, lapack_sys::dtrsyl_);
+impl_sylvester!(f32, lapack_sys::strsyl_);
@@ -35,26 +35,10 @@ macro_rules! impl_triangular {
                 b: &mut [Self],
             ) -> Result<()> {
                 // Transpose if a is C-continuous
-                let mut a_t = None;
-                let a_layout = match a_layout {
-                    MatrixLayout::C { .. } => {
-                        let (layout, t) = transpose(a_layout, a);
-                        a_t = Some(t);
-                        layout
-                    }
-                    MatrixLayout::F { .. } => a_layout,
-                };
+                let (a_layout, a_t) = ensure_fortran(a_layout, a);
 
                 // Transpose if b is C-continuous
-                let mut b_t = None;
-                let b_layout = match b_layout {
-                    MatrixLayout::C { .. } => {
-                        let (layout, t) = transpose(b_layout, b);
-                        b_t = Some(t);
-                        layout
-                    }
-                    MatrixLayout::F { .. } => b_layout,
-                };
+                let (b_layout, mut b_t) = ensure_fortran(b_layout, b);
 
                 let (m, n) = a_layout.size();
                 let (n_, nrhs) = b_layout.size();
@@ -91,3 +75,113 @@ impl_triangular!(f64, lapack_sys::dtrtrs_);
 impl_triangular!(f32, lapack_sys::strtrs_);
 impl_triangular!(c64, lapack_sys::ztrtrs_);
 impl_triangular!(c32, lapack_sys::ctrtrs_);
+
+/// Solve a triangular system with a matrix right hand side from either side
+///
+/// This solves `op(A) X = B` for [Side::Left] and `X op(A) = B` for
+/// [Side::Right] in place of `b`, where `op` is specified by [Transpose].
+/// `a` and `b` must have the same order, and `a` must be square with the
+/// size matching `b`, otherwise [Error::InvalidShape] is returned.
+///
+/// BLAS correspondance
+/// --------------------
+///
+/// | f32   | f64   | c32   | c64   |
+/// |:------|:------|:------|:------|
+/// | strsm | dtrsm | ctrsm | ztrsm |
+///
+/// These are called through CBLAS as in [crate::rank_k].
+pub trait SolveTriangularSideImpl: Scalar {
+    #[allow(clippy::too_many_arguments)]
+    fn solve_triangular_side(
+        al: MatrixLayout,
+        bl: MatrixLayout,
+        side: Side,
+        uplo: UPLO,
+        d: Diag,
+        t: Transpose,
+        a: &[Self],
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_triangular_side {
+    ($scalar:ty, $trsm:path, $alpha:expr) => {
+        impl SolveTriangularSideImpl for $scalar {
+            fn solve_triangular_side(
+                al: MatrixLayout,
+                bl: MatrixLayout,
+                side: Side,
+                uplo: UPLO,
+                diag: Diag,
+                trans: Transpose,
+                a: &[Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, n_) = al.size();
+                let (m, nrhs) = bl.size();
+                let k = match side {
+                    Side::Left => m,
+                    Side::Right => nrhs,
+                };
+                if !al.same_order(&bl) || n != n_ || n != k {
+                    return Err(Error::InvalidShape);
+                }
+                if m == 0 || nrhs == 0 {
+                    return Ok(());
+                }
+                let order = match al {
+                    MatrixLayout::F { .. } => cblas_sys::CblasColMajor,
+                    MatrixLayout::C { .. } => cblas_sys::CblasRowMajor,
+                };
+                let side = match side {
+                    Side::Left => cblas_sys::CblasLeft,
+                    Side::Right => cblas_sys::CblasRight,
+                };
+                let uplo = match uplo {
+                    UPLO::Upper => cblas_sys::CblasUpper,
+                    UPLO::Lower => cblas_sys::CblasLower,
+                };
+                let trans = match trans {
+                    Transpose::No => cblas_sys::CblasNoTrans,
+                    Transpose::Transpose => cblas_sys::CblasTrans,
+                    Transpose::Hermite => cblas_sys::CblasConjTrans,
+                };
+                let diag = match diag {
+                    Diag::Unit => cblas_sys::CblasUnit,
+                    Diag::NonUnit => cblas_sys::CblasNonUnit,
+                };
+                unsafe {
+                    $trsm(
+                        order,
+                        side,
+                        uplo,
+                        trans,
+                        diag,
+                        m,
+                        nrhs,
+                        $alpha,
+                        a.as_ptr() as *const _,
+                        al.lda(),
+                        b.as_mut_ptr() as *mut _,
+                        bl.lda(),
+                    );
+                }
+                Ok(())
+            }
+        }
+    };
+} // impl_triangular_side!
+
+impl_triangular_side!(f64, cblas_sys::cblas_dtrsm, 1.0);
+impl_triangular_side!(f32, cblas_sys::cblas_strsm, 1.0);
+impl_triangular_side!(
+    c64,
+    cblas_sys::cblas_ztrsm,
+    &c64::new(1.0, 0.0) as *const c64 as *const _
+);
+impl_triangular_side!(
+    c32,
+    cblas_sys::cblas_ctrsm,
+    &c32::new(1.0, 0.0) as *const c32 as *const _
+);
@@ -22,15 +22,7 @@ macro_rules! impl_solve_tridiagonal {
                 let (n, _) = lu.a.l.size();
                 let ipiv = &lu.ipiv;
                 // Transpose if b is C-continuous
-                let mut b_t = None;
-                let b_layout = match b_layout {
-                    MatrixLayout::C { .. } => {
-                        let (layout, t) = transpose(b_layout, b);
-                        b_t = Some(t);
-                        layout
-                    }
-                    MatrixLayout::F { .. } => b_layout,
-                };
+                let (b_layout, mut b_t) = ensure_fortran(b_layout, b);
                 let (ldb, nrhs) = b_layout.size();
                 let mut info = 0;
                 unsafe {
@@ -0,0 +1,227 @@
+//! Reduce symmetric/Hermitian matrix to real symmetric tridiagonal form
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | ssytrd | dsytrd | chetrd | zhetrd |
+//! | sorgtr | dorgtr | cungtr | zungtr |
+//!
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+pub struct TridiagonalizeWork<T: Scalar> {
+    pub n: i32,
+    pub layout: MatrixLayout,
+    pub d: Vec<MaybeUninit<T::Real>>,
+    pub e: Vec<MaybeUninit<T::Real>>,
+    pub tau: Vec<MaybeUninit<T>>,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TridiagonalizeRef<'work, T: Scalar> {
+    /// Diagonal elements of the tridiagonal matrix
+    pub d: &'work [T::Real],
+    /// Off-diagonal elements of the tridiagonal matrix
+    pub e: &'work [T::Real],
+    /// Scalar factors of the elementary reflectors
+    pub tau: &'work [T],
+}
+
+/// Result of [TridiagonalizeWorkImpl::eval]
+#[derive(Debug, Clone)]
+pub struct TridiagonalizeOwned<T: Scalar> {
+    /// Diagonal elements of the tridiagonal matrix
+    pub d: Vec<T::Real>,
+    /// Off-diagonal elements of the tridiagonal matrix
+    pub e: Vec<T::Real>,
+    /// Scalar factors of the elementary reflectors
+    pub tau: Vec<T>,
+}
+
+pub trait TridiagonalizeWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(layout: MatrixLayout) -> Result<Self>;
+    fn calc(
+        &mut self,
+        uplo: UPLO,
+        a: &mut [Self::Elem],
+    ) -> Result<TridiagonalizeRef<'_, Self::Elem>>;
+    fn eval(self, uplo: UPLO, a: &mut [Self::Elem]) -> Result<TridiagonalizeOwned<Self::Elem>>;
+}
+
+macro_rules! impl_tridiagonalize_work {
+    ($s:ty, $trd:path) => {
+        impl TridiagonalizeWorkImpl for TridiagonalizeWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let k = (n as usize).saturating_sub(1);
+                let mut d = vec_uninit(n as usize);
+                let mut e = vec_uninit(k);
+                let mut tau = vec_uninit(k);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $trd(
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(TridiagonalizeWork {
+                    n,
+                    layout,
+                    d,
+                    e,
+                    tau,
+                    work,
+                })
+            }
+
+            fn calc(
+                &mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<TridiagonalizeRef<'_, Self::Elem>> {
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $trd(
+                        uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.d),
+                        AsPtr::as_mut_ptr(&mut self.e),
+                        AsPtr::as_mut_ptr(&mut self.tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                }
+                Ok(unsafe {
+                    TridiagonalizeRef {
+                        d: self.d.slice_assume_init_ref(),
+                        e: self.e.slice_assume_init_ref(),
+                        tau: self.tau.slice_assume_init_ref(),
+                    }
+                })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<TridiagonalizeOwned<Self::Elem>> {
+                let _ref = self.calc(uplo, a)?;
+                Ok(unsafe {
+                    TridiagonalizeOwned {
+                        d: self.d.assume_init(),
+                        e: self.e.assume_init(),
+                        tau: self.tau.assume_init(),
+                    }
+                })
+            }
+        }
+    };
+}
+impl_tridiagonalize_work!(c64, lapack_sys::zhetrd_);
+impl_tridiagonalize_work!(c32, lapack_sys::chetrd_);
+impl_tridiagonalize_work!(f64, lapack_sys::dsytrd_);
+impl_tridiagonalize_work!(f32, lapack_sys::ssytrd_);
+
+pub struct QTridiagonalWork<T: Scalar> {
+    pub n: i32,
+    pub layout: MatrixLayout,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+pub trait QTridiagonalWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(layout: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, uplo: UPLO, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()>;
+}
+
+macro_rules! impl_q_tridiagonal_work {
+    ($s:ty, $gtr:path) => {
+        impl QTridiagonalWorkImpl for QTridiagonalWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $gtr(
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null(),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(QTridiagonalWork { n, layout, work })
+            }
+
+            fn calc(&mut self, uplo: UPLO, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()> {
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $gtr(
+                        uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+impl_q_tridiagonal_work!(c64, lapack_sys::zungtr_);
+impl_q_tridiagonal_work!(c32, lapack_sys::cungtr_);
+impl_q_tridiagonal_work!(f64, lapack_sys::dorgtr_);
+impl_q_tridiagonal_work!(f32, lapack_sys::sorgtr_);
@@ -0,0 +1,66 @@
+//! Balancing of general matrices
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use crate::validate::check_input;
+
+/// Balancing of a general matrix by a diagonal similarity transformation
+pub trait Balance<A: Scalar> {
+    /// Compute the balanced matrix `D^{-1} A D` and the diagonal of `D`
+    ///
+    /// The rows and columns of the balanced matrix are close in norm, which
+    /// improves the accuracy of the eigenvalues computed from it. Its
+    /// eigenvalues are those of `A`, and its eigenvectors are mapped back to
+    /// those of `A` by [unbalance]. The scale factors are powers of the radix
+    /// so that the transformation is exact.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[1.0, 1e6], [1e-6, 1.0]];
+    /// let (b, d) = a.balance().unwrap();
+    /// assert!(b[(0, 1)].abs() < 10.0 && b[(1, 0)].abs() > 0.1);
+    /// // D^{-1} A D = B
+    /// let a2 = Array2::from_shape_fn((2, 2), |(i, j)| b[(i, j)] * d[i] / d[j]);
+    /// assert_close_l2!(&a2, &a, 1e-15);
+    /// ```
+    fn balance(&self) -> Result<(Array2<A>, Array1<A::Real>)>;
+}
+
+impl<A, S> Balance<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn balance(&self) -> Result<(Array2<A>, Array1<A::Real>)> {
+        check_input(self)?;
+        let mut a = self.to_owned();
+        let scale = A::balance(a.square_layout()?, a.as_allocated_mut()?)?;
+        Ok((a, ArrayBase::from(scale)))
+    }
+}
+
+/// Map the eigenvectors `v` of the balanced matrix `D^{-1} A D` back to those
+/// of `A`, i.e. compute `D v` for the diagonal `scale` of `D` by [Balance::balance]
+///
+/// The eigenvectors are not normalized again.
+///
+/// # Panics
+///
+/// Panics if the number of rows of `v` differs from the length of `scale`.
+pub fn unbalance<T, S>(scale: &Array1<T::Real>, v: &ArrayBase<S, Ix2>) -> Array2<T>
+where
+    T: Scalar,
+    S: Data<Elem = T>,
+{
+    assert_eq!(scale.len(), v.nrows(), "Size of scale factors mismatch");
+    let mut v = v.to_owned();
+    for (mut row, &s) in v.outer_iter_mut().zip(scale) {
+        row.mapv_inplace(|x| x.mul_real(s));
+    }
+    v
+}
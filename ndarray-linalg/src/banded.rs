@@ -0,0 +1,473 @@
+//! Band matrices
+//! &
+//! Methods for band matrices
+
+use super::error::*;
+use super::layout::*;
+use cauchy::Scalar;
+use lax::*;
+use ndarray::*;
+
+/// Square band matrix with `kl` sub-diagonals and `ku` super-diagonals
+///
+/// The diagonals are stored as the rows of the `(kl + ku + 1) x n` matrix `ab`
+/// in the LAPACK band storage `ab[ku + i - j, j] = a[i, j]`, e.g. for `kl = 1`
+/// and `ku = 2`
+///
+/// ```text
+/// [  *,   *, u02, u13, ..., ]  <- 2nd super-diagonal
+/// [  *, u01, u12, u23, ..., ]  <- 1st super-diagonal
+/// [ d00, d11, d22, d33, ..., ]  <- diagonal
+/// [ l10, l21, l32, ...,   *, ]  <- 1st sub-diagonal
+/// ```
+///
+/// where the elements `*` outside of the matrix are not referred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedMatrix<A: Scalar> {
+    /// Number of sub-diagonals
+    pub kl: usize,
+    /// Number of super-diagonals
+    pub ku: usize,
+    /// Diagonals stored as rows in column-major order
+    pub ab: Array2<A>,
+}
+
+impl<A: Scalar> BandedMatrix<A> {
+    /// Create a band matrix from the band storage `ab` of `kl + ku + 1` rows
+    pub fn from_band<S: Data<Elem = A>>(
+        kl: usize,
+        ku: usize,
+        ab: &ArrayBase<S, Ix2>,
+    ) -> Result<Self> {
+        if ab.nrows() != kl + ku + 1 {
+            return Err(LinalgError::NotStandardShape {
+                obj: "BandedMatrix",
+                rows: ab.nrows() as i32,
+                cols: ab.ncols() as i32,
+            });
+        }
+        let mut ab_ = Array2::zeros(ab.dim().f());
+        ab_.assign(ab);
+        Ok(BandedMatrix { kl, ku, ab: ab_ })
+    }
+
+    /// Size of the square matrix
+    pub fn len(&self) -> usize {
+        self.ab.ncols()
+    }
+
+    /// Whether the matrix is `0 x 0`
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Element `a[i, j]`, which is zero outside of the band
+    pub fn get(&self, i: usize, j: usize) -> A {
+        if i + self.ku >= j && j + self.kl >= i {
+            self.ab[(self.ku + i - j, j)]
+        } else {
+            A::zero()
+        }
+    }
+
+    /// Dense representation of the matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.len();
+        Array2::from_shape_fn((n, n), |(i, j)| self.get(i, j))
+    }
+
+    /// Column-major band storage of the `uplo` triangle with its number of off-diagonals,
+    /// which is used in LAPACK routines for symmetric/Hermitian band matrices
+    pub(crate) fn triangle(&self, uplo: UPLO) -> (usize, Array2<A>) {
+        let (kd, rows) = match uplo {
+            UPLO::Upper => (self.ku, 0..=self.ku),
+            UPLO::Lower => (self.kl, self.ku..=self.ku + self.kl),
+        };
+        let mut ab = Array2::zeros((kd + 1, self.len()).f());
+        ab.assign(&self.ab.slice(s![rows, ..]));
+        (kd, ab)
+    }
+}
+
+/// An interface for making a BandedMatrix struct.
+pub trait ExtractBanded<A: Scalar> {
+    /// Extract the band of `bandwidth` sub- and super-diagonals of the raw
+    /// matrix. Elements outside of the band are ignored.
+    fn extract_banded(&self, bandwidth: usize) -> Result<BandedMatrix<A>> {
+        self.extract_banded_with(bandwidth, bandwidth)
+    }
+
+    /// Extract the band of `kl` sub-diagonals and `ku` super-diagonals of the
+    /// raw matrix. Elements outside of the band are ignored.
+    fn extract_banded_with(&self, kl: usize, ku: usize) -> Result<BandedMatrix<A>>;
+}
+
+impl<A, S> ExtractBanded<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    fn extract_banded_with(&self, kl: usize, ku: usize) -> Result<BandedMatrix<A>> {
+        self.ensure_square()?;
+        let n = self.nrows();
+        let mut ab = Array2::zeros((kl + ku + 1, n).f());
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..n.min(j + kl + 1) {
+                ab[(ku + i - j, j)] = self[(i, j)];
+            }
+        }
+        Ok(BandedMatrix { kl, ku, ab })
+    }
+}
+
+/// Eigenvalue decomposition of symmetric/Hermitian band matrix
+pub trait EighBanded {
+    type EigVal;
+    type EigVec;
+    /// Eigenvalues in ascending order and the corresponding eigenvectors as
+    /// columns of a dense matrix. Only the `uplo` triangle of the band is referred.
+    fn eigh_banded(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)>;
+    /// Eigenvalues in ascending order without eigenvectors
+    fn eigvalsh_banded(&self, uplo: UPLO) -> Result<Self::EigVal>;
+}
+
+impl<A> EighBanded for BandedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_banded(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+        let n = self.len();
+        let (kd, mut ab) = self.triangle(uplo);
+        let (e, v) = A::eigh_banded(
+            true,
+            uplo,
+            n as i32,
+            kd as i32,
+            ab.as_slice_memory_order_mut().unwrap(),
+        )?;
+        Ok((ArrayBase::from(e), Array2::from_shape_vec((n, n).f(), v)?))
+    }
+
+    fn eigvalsh_banded(&self, uplo: UPLO) -> Result<Self::EigVal> {
+        let n = self.len();
+        let (kd, mut ab) = self.triangle(uplo);
+        let (e, _) = A::eigh_banded(
+            false,
+            uplo,
+            n as i32,
+            kd as i32,
+            ab.as_slice_memory_order_mut().unwrap(),
+        )?;
+        Ok(ArrayBase::from(e))
+    }
+}
+
+/// LU decomposition of a band matrix by [FactorizeBanded]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LUFactorizedBanded<A: Scalar> {
+    /// Shape of the band matrix
+    pub shape: BandShape,
+    /// LU factors in the band storage with `kl` additional rows for the fill-in
+    pub afb: Array2<A>,
+    /// Pivot indices
+    pub ipiv: Pivot,
+}
+
+/// An interface for computing LU decompositions of band matrices
+pub trait FactorizeBanded<A: Scalar> {
+    /// Computes the LU decomposition `A = P*L*U` with partial pivoting by `*gbtrf`.
+    fn factorize_banded(&self) -> Result<LUFactorizedBanded<A>>;
+}
+
+impl<A> FactorizeBanded<A> for BandedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorize_banded(&self) -> Result<LUFactorizedBanded<A>> {
+        let shape = BandShape {
+            n: self.len() as i32,
+            kl: self.kl as i32,
+            ku: self.ku as i32,
+        };
+        let mut afb = Array2::zeros((shape.ldab() as usize, self.len()).f());
+        afb.slice_mut(s![self.kl.., ..]).assign(&self.ab);
+        let ipiv = A::lu_banded(shape, afb.as_slice_memory_order_mut().unwrap())?;
+        Ok(LUFactorizedBanded { shape, afb, ipiv })
+    }
+}
+
+/// Call a LAPACK solver `solve(nrhs, b)` for the columns of `b`, which are
+/// copied into column-major order if needed
+fn solve_columns<A, F>(n: usize, mut b: ArrayViewMut2<A>, solve: F) -> Result<()>
+where
+    A: Scalar,
+    F: FnOnce(i32, &mut [A]) -> lax::error::Result<()>,
+{
+    if b.nrows() != n {
+        return Err(LinalgError::NotStandardShape {
+            obj: "Right hand side",
+            rows: b.nrows() as i32,
+            cols: b.ncols() as i32,
+        });
+    }
+    let nrhs = b.ncols() as i32;
+    if b.t().is_standard_layout() {
+        solve(nrhs, b.as_slice_memory_order_mut().unwrap())?;
+    } else {
+        let mut x = Array2::zeros(b.dim().f());
+        x.assign(&b);
+        solve(nrhs, x.as_slice_memory_order_mut().unwrap())?;
+        b.assign(&x);
+    }
+    Ok(())
+}
+
+impl<A> LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Solve `op(A) X = B` for the columns of `b` in place
+    fn solve_columns(&self, t: Transpose, b: ArrayViewMut2<A>) -> Result<()> {
+        let ab = self.afb.as_slice_memory_order().unwrap();
+        solve_columns(self.shape.n as usize, b, |nrhs, b| {
+            A::solve_banded(self.shape, t, ab, &self.ipiv, nrhs, b)
+        })
+    }
+}
+
+/// Solve linear equations with band matrices in place
+pub trait SolveBandedInplace<A: Scalar, D: Dimension> {
+    /// Solves `op(A) x = b` where `op` is specified by `t`, and `b` is
+    /// overwritten by `x`. `b` is a vector or a matrix of multiple right hand
+    /// sides as its columns.
+    fn solve_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        t: Transpose,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+}
+
+/// Solve linear equations with band matrices
+pub trait SolveBanded<A: Scalar, D: Dimension> {
+    /// Solves a system of linear equations `A * x = b` with band matrix `A`,
+    /// where `A` is `self`, `b` is the argument, and `x` is the successful result.
+    fn solve_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+    /// Solves a system of linear equations `A * x = b` with band matrix `A`,
+    /// where `A` is `self`, `b` is the argument, and `x` is the successful result.
+    fn solve_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>>;
+    /// Solves a system of linear equations `A^T * x = b` with band matrix `A`,
+    /// where `A` is `self`, `b` is the argument, and `x` is the successful result.
+    fn solve_t_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+    /// Solves a system of linear equations `A^H * x = b` with band matrix `A`,
+    /// where `A` is `self`, `b` is the argument, and `x` is the successful result.
+    fn solve_h_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+}
+
+impl<A, D, T> SolveBanded<A, D> for T
+where
+    A: Scalar,
+    D: Dimension,
+    T: SolveBandedInplace<A, D>,
+{
+    fn solve_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
+        let mut b = b.to_owned();
+        self.solve_banded_inplace(Transpose::No, &mut b)?;
+        Ok(b)
+    }
+    fn solve_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>> {
+        self.solve_banded_inplace(Transpose::No, &mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
+        let mut b = b.to_owned();
+        self.solve_banded_inplace(Transpose::Transpose, &mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
+        let mut b = b.to_owned();
+        self.solve_banded_inplace(Transpose::Hermite, &mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveBandedInplace<A, Ix1> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        t: Transpose,
+        b: &'a mut ArrayBase<S, Ix1>,
+    ) -> Result<&'a mut ArrayBase<S, Ix1>> {
+        self.solve_columns(t, b.view_mut().insert_axis(Axis(1)))?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveBandedInplace<A, Ix2> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        t: Transpose,
+        b: &'a mut ArrayBase<S, Ix2>,
+    ) -> Result<&'a mut ArrayBase<S, Ix2>> {
+        self.solve_columns(t, b.view_mut())?;
+        Ok(b)
+    }
+}
+
+impl<A, D> SolveBandedInplace<A, D> for BandedMatrix<A>
+where
+    A: Scalar + Lapack,
+    D: Dimension,
+    LUFactorizedBanded<A>: SolveBandedInplace<A, D>,
+{
+    fn solve_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        t: Transpose,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>> {
+        self.factorize_banded()?.solve_banded_inplace(t, b)
+    }
+}
+
+/// Cholesky decomposition of a positive definite band matrix by [FactorizeCBanded]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedCholeskyFactorized<A: Scalar> {
+    /// Triangular factor `U` (`A = U^H U`) for `UPLO::Upper`, or `L`
+    /// (`A = L L^H`) for `UPLO::Lower`
+    pub factor: BandedMatrix<A>,
+    /// Which triangle is stored in `factor`
+    pub uplo: UPLO,
+}
+
+impl<A: Scalar> BandedCholeskyFactorized<A> {
+    /// Number of off-diagonals of the triangular factor
+    fn kd(&self) -> usize {
+        match self.uplo {
+            UPLO::Upper => self.factor.ku,
+            UPLO::Lower => self.factor.kl,
+        }
+    }
+}
+
+/// Cholesky decomposition of Hermitian (or real symmetric) positive definite band matrix
+pub trait CholeskyBanded<A: Scalar> {
+    /// Computes the triangular factor `U` (`A = U^H U`) if `uplo == UPLO::Upper`
+    /// or `L` (`A = L L^H`) if `uplo == UPLO::Lower` by `*pbtrf`, referring
+    /// only the `uplo` triangle of the band. The factor has the same bandwidth.
+    fn cholesky_banded(&self, uplo: UPLO) -> Result<BandedMatrix<A>>;
+}
+
+/// Cholesky decomposition of Hermitian (or real symmetric) positive definite
+/// band matrix for solving linear equations repeatedly
+pub trait FactorizeCBanded<A: Scalar> {
+    /// Computes the Cholesky decomposition of the band matrix, referring only
+    /// the `uplo` triangle of the band.
+    fn factorizec_banded(&self, uplo: UPLO) -> Result<BandedCholeskyFactorized<A>>;
+}
+
+impl<A> FactorizeCBanded<A> for BandedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizec_banded(&self, uplo: UPLO) -> Result<BandedCholeskyFactorized<A>> {
+        let n = self.len();
+        let (kd, mut ab) = self.triangle(uplo);
+        A::cholesky_banded(
+            uplo,
+            n as i32,
+            kd as i32,
+            ab.as_slice_memory_order_mut().unwrap(),
+        )?;
+        let (kl, ku) = match uplo {
+            UPLO::Upper => (0, kd),
+            UPLO::Lower => (kd, 0),
+        };
+        Ok(BandedCholeskyFactorized {
+            factor: BandedMatrix { kl, ku, ab },
+            uplo,
+        })
+    }
+}
+
+impl<A> CholeskyBanded<A> for BandedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn cholesky_banded(&self, uplo: UPLO) -> Result<BandedMatrix<A>> {
+        Ok(self.factorizec_banded(uplo)?.factor)
+    }
+}
+
+/// Solve systems of linear equations with Hermitian (or real symmetric)
+/// positive definite band matrices
+pub trait SolveCBanded<A: Scalar, D: Dimension> {
+    /// Solves a system of linear equations `A * x = b` with Hermitian (or real
+    /// symmetric) positive definite band matrix `A`, where `A` is `self`, `b`
+    /// is the argument, and `x` is the successful result.
+    fn solvec_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
+        let mut b = b.to_owned();
+        self.solvec_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    /// Solves a system of linear equations `A * x = b` with Hermitian (or real
+    /// symmetric) positive definite band matrix `A`, where `A` is `self`, `b`
+    /// is the argument, and `x` is the successful result. The value of `x` is
+    /// also assigned to the argument.
+    fn solvec_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+}
+
+impl<A> BandedCholeskyFactorized<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Solve `A X = B` for the columns of `b` in place
+    fn solve_columns(&self, b: ArrayViewMut2<A>) -> Result<()> {
+        let n = self.factor.len();
+        let ab = self.factor.ab.as_slice_memory_order().unwrap();
+        solve_columns(n, b, |nrhs, b| {
+            A::solve_cholesky_banded(self.uplo, n as i32, self.kd() as i32, ab, nrhs, b)
+        })
+    }
+}
+
+impl<A> SolveCBanded<A, Ix1> for BandedCholeskyFactorized<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, Ix1>,
+    ) -> Result<&'a mut ArrayBase<S, Ix1>> {
+        self.solve_columns(b.view_mut().insert_axis(Axis(1)))?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveCBanded<A, Ix2> for BandedCholeskyFactorized<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, Ix2>,
+    ) -> Result<&'a mut ArrayBase<S, Ix2>> {
+        self.solve_columns(b.view_mut())?;
+        Ok(b)
+    }
+}
@@ -0,0 +1,107 @@
+//! Reduction of general matrices into real bidiagonal form
+//!
+//! For a `m x n` matrix `A`, this computes unitary matrices `Q`, `P` and a
+//! real bidiagonal matrix `B` such that `A = Q B P^H`. This is the first step
+//! of the singular value decomposition, and the singular values of `B` are
+//! those of `A`.
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use crate::validate::check_input;
+use crate::UPLO;
+
+/// Bidiagonal form `A = Q B P^H` by [Bidiagonal::bidiagonal]
+///
+/// With `k = min(m, n)`, `B` is a `k x k` real bidiagonal matrix, which is
+/// upper bidiagonal if `m >= n` and lower bidiagonal otherwise.
+#[derive(Debug, Clone)]
+pub struct BidiagonalForm<A: Scalar> {
+    /// `m x k` matrix `Q` with orthonormal columns
+    pub q: Array2<A>,
+    /// `k x n` matrix `P^H` with orthonormal rows
+    pub pt: Array2<A>,
+    /// Diagonal elements of `B`
+    pub d: Array1<A::Real>,
+    /// Off-diagonal elements of `B`, i.e. the super-diagonal for
+    /// [UPLO::Upper] and the sub-diagonal for [UPLO::Lower]
+    pub e: Array1<A::Real>,
+    /// Whether `B` is upper or lower bidiagonal
+    pub uplo: UPLO,
+    /// Householder reflectors of `Q` and `P` as returned by `?GEBRD` of LAPACK
+    pub reflectors: Array2<A>,
+    /// Scalar factors of the elementary reflectors representing `Q`
+    pub tauq: Array1<A>,
+    /// Scalar factors of the elementary reflectors representing `P`
+    pub taup: Array1<A>,
+}
+
+impl<A: Scalar> BidiagonalForm<A> {
+    /// Bidiagonal matrix `B` as a dense `k x k` matrix
+    pub fn b(&self) -> Array2<A::Real> {
+        let k = self.d.len();
+        let mut b = Array2::zeros((k, k));
+        b.diag_mut().assign(&self.d);
+        for (i, &e) in self.e.iter().enumerate() {
+            match self.uplo {
+                UPLO::Upper => b[(i, i + 1)] = e,
+                UPLO::Lower => b[(i + 1, i)] = e,
+            }
+        }
+        b
+    }
+}
+
+/// Bidiagonalize a general matrix reference
+pub trait Bidiagonal<A: Scalar> {
+    /// Returns `Q`, `B` and `P^H` where `A = Q B P^H`
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = random((4, 3));
+    /// let f = a.bidiagonal().unwrap();
+    /// assert_eq!(f.uplo, UPLO::Upper);
+    /// assert_close_l2!(&f.q.dot(&f.b()).dot(&f.pt), &a, 1e-9);
+    /// ```
+    fn bidiagonal(&self) -> Result<BidiagonalForm<A>>;
+}
+
+impl<A, S> Bidiagonal<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn bidiagonal(&self) -> Result<BidiagonalForm<A>> {
+        check_input(self)?;
+        let (m, n) = self.dim();
+        let k = m.min(n);
+        let mut a = self.to_owned();
+        let layout = a.layout()?;
+        let lax::BidiagonalOwned { d, e, tauq, taup } =
+            A::bidiagonalize(layout, a.as_allocated_mut()?)?;
+        // `a` is overwritten in column-major order
+        let f_layout = MatrixLayout::F {
+            col: n as i32,
+            lda: m as i32,
+        };
+        let reflectors = Array2::from_shape_vec((m, n).f(), a.into_raw_vec()).unwrap();
+        let reflectors_slice = reflectors.as_slice_memory_order().unwrap();
+        let q = A::q_bidiagonal(f_layout, reflectors_slice, &tauq)?;
+        let pt = A::pt_bidiagonal(f_layout, reflectors_slice, &taup)?;
+        let uplo = if m >= n { UPLO::Upper } else { UPLO::Lower };
+        Ok(BidiagonalForm {
+            q: Array2::from_shape_vec((m, k).f(), q).unwrap(),
+            pt: Array2::from_shape_vec((k, n).f(), pt).unwrap(),
+            d: ArrayBase::from(d),
+            e: ArrayBase::from(e),
+            uplo,
+            reflectors,
+            tauq: ArrayBase::from(tauq),
+            taup: ArrayBase::from(taup),
+        })
+    }
+}
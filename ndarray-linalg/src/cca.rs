@@ -0,0 +1,79 @@
+//! Canonical correlation analysis (CCA)
+//!
+//! [Wikipedia article on CCA](https://en.wikipedia.org/wiki/Canonical_correlation)
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::qr::*;
+use crate::svd::*;
+use crate::triangular::*;
+use crate::types::*;
+
+/// Result of [cca]
+#[derive(Debug, Clone)]
+pub struct CcaResult<A: Scalar> {
+    /// Canonical correlations in descending order
+    pub correlations: Array1<A::Real>,
+    /// Canonical weights of `x` as columns of a `(p, k)` matrix
+    pub x_weights: Array2<A>,
+    /// Canonical weights of `y` as columns of a `(q, k)` matrix
+    pub y_weights: Array2<A>,
+}
+
+/// Canonical correlation analysis of two sets of variables
+///
+/// The rows of the `(n, p)` matrix `x` and the `(n, q)` matrix `y` are the
+/// `n` paired observations. This finds the weights `a_i` and `b_i` such that
+/// the correlation between `X a_i` and `Y b_i` is maximized, subject to
+/// being uncorrelated with the preceding pairs.
+///
+/// The columns are centered, and factorized by the QR decomposition
+/// `X = Q_x R_x`, `Y = Q_y R_y`. The canonical correlations are the singular
+/// values of `Q_x^H Q_y = U Σ V^H`, and the weights are given by
+/// `R_x^{-1} U` and `R_y^{-1} V`, so that the canonical variates `X a_i`
+/// have unit norm. This avoids forming the covariance matrices, whose
+/// condition numbers are the squares of those of the data.
+///
+/// Only the leading `k` pairs are returned, and `k` is truncated to
+/// `min(p, q)`. Both centered matrices must have full column rank.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different numbers of rows.
+pub fn cca<A>(x: &ArrayView2<A>, y: &ArrayView2<A>, k: usize) -> Result<CcaResult<A>>
+where
+    A: Scalar + Lapack,
+{
+    assert_eq!(
+        x.nrows(),
+        y.nrows(),
+        "The numbers of observations must be identical."
+    );
+    let (qx, rx) = center(x).qr_into()?;
+    let (qy, ry) = center(y).qr_into()?;
+    let qxh = qx.t().mapv(|x| x.conj());
+    let (u, s, vt) = qxh.dot(&qy).svd(true, true)?;
+    let (u, vt) = (u.unwrap(), vt.unwrap());
+    let k = k.min(s.len());
+
+    let x_weights =
+        rx.solve_triangular(UPLO::Upper, Diag::NonUnit, &u.slice(s![.., ..k]).to_owned())?;
+    let y_weights = ry.solve_triangular(
+        UPLO::Upper,
+        Diag::NonUnit,
+        &vt.slice(s![..k, ..]).t().mapv(|x| x.conj()),
+    )?;
+    Ok(CcaResult {
+        correlations: s.slice_move(s![..k]),
+        x_weights,
+        y_weights,
+    })
+}
+
+/// Subtract the mean of each column
+fn center<A: Scalar>(x: &ArrayView2<A>) -> Array2<A> {
+    let n = A::real(x.nrows());
+    let mean = x.sum_axis(Axis(0)).mapv(|m| m.div_real(n));
+    x - &mean
+}
@@ -44,13 +44,15 @@
 //! ```
 
 use ndarray::*;
-use num_traits::Float;
+use num_traits::{Float, One, Zero};
 
 use crate::convert::*;
 use crate::error::*;
+use crate::inner::InnerProduct;
 use crate::layout::*;
 use crate::triangular::IntoTriangular;
 use crate::types::*;
+use crate::validate::check_input;
 
 pub use lax::UPLO;
 
@@ -183,6 +185,30 @@ where
     }
 }
 
+impl<A, S> SolveC<A, Ix2> for CholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            b.nrows(),
+            self.factor.nrows(),
+            "The number of rows of `b` must be compatible with the shape of the factored matrix.",
+        );
+        let l = self.factor.square_layout()?;
+        let a = self.factor.as_allocated()?;
+        with_fortran(b, |b| Ok(A::solve_cholesky(l, self.uplo, a, b)?))?;
+        Ok(b)
+    }
+}
+
 /// Cholesky decomposition of Hermitian (or real symmetric) positive definite matrix reference
 pub trait Cholesky {
     type Output;
@@ -195,6 +221,10 @@ pub trait Cholesky {
     /// Otherwise, if the argument is `UPLO::Lower`, computes the decomposition
     /// `A = L * L^H` using the lower triangular portion of `A` and returns
     /// `L`.
+    ///
+    /// If `A` is not positive definite, this returns
+    /// [LinalgError::NotPositiveDefinite] with the order of the first leading
+    /// minor which is not positive definite.
     fn cholesky(&self, uplo: UPLO) -> Result<Self::Output>;
 }
 
@@ -257,11 +287,25 @@ where
     S: DataMut<Elem = A>,
 {
     fn cholesky_inplace(&mut self, uplo: UPLO) -> Result<&mut Self> {
-        A::cholesky(self.square_layout()?, uplo, self.as_allocated_mut()?)?;
+        check_input(self)?;
+        A::cholesky(self.square_layout()?, uplo, self.as_allocated_mut()?)
+            .map_err(not_positive_definite)?;
         Ok(self.into_triangular(uplo))
     }
 }
 
+/// Map the failure of `*potrf` at the leading minor of order `return_code`
+fn not_positive_definite(e: lax::error::Error) -> LinalgError {
+    match e {
+        lax::error::Error::LapackComputationalFailure { return_code } => {
+            LinalgError::NotPositiveDefinite {
+                minor: return_code as usize,
+            }
+        }
+        e => e.into(),
+    }
+}
+
 /// Cholesky decomposition of Hermitian (or real symmetric) positive definite matrix reference
 pub trait FactorizeC<S: Data> {
     /// Computes the Cholesky decomposition of the Hermitian (or real
@@ -316,11 +360,14 @@ where
 
 /// Solve systems of linear equations with Hermitian (or real symmetric)
 /// positive definite coefficient matrices
-pub trait SolveC<A: Scalar> {
+///
+/// `b` is a vector for `D = Ix1`, or a matrix whose columns are the right hand
+/// sides for `D = Ix2`, which are solved by a single `*potrs` call.
+pub trait SolveC<A: Scalar, D: Dimension = Ix1> {
     /// Solves a system of linear equations `A * x = b` with Hermitian (or real
     /// symmetric) positive definite matrix `A`, where `A` is `self`, `b` is
     /// the argument, and `x` is the successful result.
-    fn solvec<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    fn solvec<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solvec_inplace(&mut b)?;
         Ok(b)
@@ -328,10 +375,7 @@ pub trait SolveC<A: Scalar> {
     /// Solves a system of linear equations `A * x = b` with Hermitian (or real
     /// symmetric) positive definite matrix `A`, where `A` is `self`, `b` is
     /// the argument, and `x` is the successful result.
-    fn solvec_into<S: DataMut<Elem = A>>(
-        &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+    fn solvec_into<S: DataMut<Elem = A>>(&self, mut b: ArrayBase<S, D>) -> Result<ArrayBase<S, D>> {
         self.solvec_inplace(&mut b)?;
         Ok(b)
     }
@@ -341,8 +385,8 @@ pub trait SolveC<A: Scalar> {
     /// also assigned to the argument.
     fn solvec_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
 }
 
 impl<A, S> SolveC<A> for ArrayBase<S, Ix2>
@@ -361,6 +405,22 @@ where
     }
 }
 
+impl<A, S> SolveC<A, Ix2> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorizec(UPLO::Upper)?.solvec_inplace(b)
+    }
+}
+
 /// Inverse of Hermitian (or real symmetric) positive definite matrix ref
 pub trait InverseC {
     type Output;
@@ -466,3 +526,197 @@ where
         Ok(self.factorizec_into(UPLO::Upper)?.ln_detc_into())
     }
 }
+
+/// Cholesky decomposition with complete pivoting by [CholeskyPivot::cholesky_pivot]
+#[derive(Debug, Clone)]
+pub struct PivotedCholesky<A> {
+    /// Triangular factor `U` or `L` of `P^T A P` according to `uplo`
+    ///
+    /// The rows of `U` (or the columns of `L`) after `rank` are zero.
+    pub factor: Array2<A>,
+    /// Whether `factor` is `U` or `L`
+    pub uplo: UPLO,
+    /// Permutation `perm` of `P` such that `(P^T A P)[i, j] = A[perm[i], perm[j]]`
+    pub perm: Vec<usize>,
+    /// Numerical rank of `A`
+    pub rank: usize,
+}
+
+/// Cholesky decomposition with complete pivoting of Hermitian (or real
+/// symmetric) positive semidefinite matrix reference
+pub trait CholeskyPivot<A: Scalar> {
+    /// Computes `P^T A P = U^H U` if the argument is `UPLO::Upper`, or
+    /// `P^T A P = L L^H` if `UPLO::Lower`, using the corresponding triangular
+    /// portion of `A`
+    ///
+    /// Unlike [Cholesky::cholesky], a singular `A`, e.g. a covariance matrix
+    /// of collinear samples, is factorized up to its numerical rank. The
+    /// diagonal elements not larger than `tol` are regarded as zero during
+    /// the factorization, and `n * eps * max_k A[k, k]` is used if `tol` is
+    /// `None`.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let x: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0], [0.0, 0.0]];
+    /// let a = x.dot(&x.t()); // rank 1
+    /// let c = a.cholesky_pivot(UPLO::Lower, None).unwrap();
+    /// assert_eq!(c.rank, 1);
+    /// let l = &c.factor;
+    /// let pap = Array2::from_shape_fn((3, 3), |(i, j)| a[(c.perm[i], c.perm[j])]);
+    /// assert_close_l2!(&l.dot(&l.t()), &pap, 1e-12);
+    /// ```
+    fn cholesky_pivot(&self, uplo: UPLO, tol: Option<A::Real>) -> Result<PivotedCholesky<A>>;
+}
+
+impl<A, S> CholeskyPivot<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn cholesky_pivot(&self, uplo: UPLO, tol: Option<A::Real>) -> Result<PivotedCholesky<A>> {
+        check_input(self)?;
+        let mut a = replicate(self);
+        let (piv, rank) = A::cholesky_pivot(
+            a.square_layout()?,
+            uplo,
+            a.as_allocated_mut()?,
+            tol.unwrap_or_else(|| A::real(-1.0)),
+        )?;
+        let rank = rank as usize;
+        // The trailing block is left as the remnant of the factorization
+        a.slice_mut(s![rank.., rank..]).fill(A::zero());
+        Ok(PivotedCholesky {
+            factor: a.into_triangular(uplo),
+            uplo,
+            perm: piv.iter().map(|&p| (p - 1) as usize).collect(),
+            rank,
+        })
+    }
+}
+
+/// Log marginal likelihood of a Gaussian process regression model
+///
+/// For the kernel matrix `K` of the training inputs, the observed targets
+/// `y` and the noise variance `σ^2 = noise`, this computes
+///
+/// ```text
+/// log p(y) = -1/2 y^H (K + σ^2 I)^{-1} y - 1/2 log|K + σ^2 I| - n/2 log(2π)
+/// ```
+///
+/// using a single Cholesky factorization of `K + σ^2 I` both for the solve
+/// and the log-determinant.
+///
+/// # Panics
+///
+/// Panics if the length of `y` differs from the size of `k`.
+pub fn gp_log_marginal_likelihood<A>(
+    k: &ArrayView2<A>,
+    y: &ArrayView1<A>,
+    noise: A::Real,
+) -> Result<A::Real>
+where
+    A: Scalar + Lapack,
+{
+    k.ensure_square()?;
+    let n = k.nrows();
+    assert_eq!(
+        y.len(),
+        n,
+        "The length of y must be equal to the size of the kernel matrix."
+    );
+    let mut kn = k.to_owned();
+    kn.diag_mut().mapv_inplace(|x| x + A::from_real(noise));
+    let f = kn.factorizec_into(UPLO::Lower)?;
+    let alpha = f.solvec(y)?;
+    let half = A::real(0.5);
+    let two_pi = A::real(2.0 * std::f64::consts::PI);
+    Ok(-half * y.inner(&alpha).re() - half * f.ln_detc() - half * A::real(n) * Float::ln(two_pi))
+}
+
+/// Rank-1 update of the Cholesky factor, `L L^H <- L L^H + x x^H`
+///
+/// The lower triangular factor `L` of `A = L L^H`, e.g. given by
+/// [Cholesky::cholesky] with [UPLO::Lower], is overwritten by the factor of
+/// `A + x x^H` using a sequence of Givens rotations in `O(n^2)`, which is
+/// cheaper than refactorizing `A + x x^H` in `O(n^3)`. The upper triangular
+/// part of `l` is not referenced.
+///
+/// # Panics
+///
+/// Panics if the length of `x` differs from the size of `l`.
+pub fn cholesky_rank1_update<A, S, Sx>(
+    l: &mut ArrayBase<S, Ix2>,
+    x: &ArrayBase<Sx, Ix1>,
+) -> Result<()>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    rank1_modify(l, x, false)
+}
+
+/// Rank-1 downdate of the Cholesky factor, `L L^H <- L L^H - x x^H`
+///
+/// This is the counterpart of [cholesky_rank1_update] using hyperbolic
+/// rotations.
+///
+/// Errors
+/// -------
+/// - [LinalgError::NotPositiveDefinite] if `A - x x^H` is not positive
+///   definite. `l` is partially modified in this case.
+///
+/// # Panics
+///
+/// Panics if the length of `x` differs from the size of `l`.
+pub fn cholesky_rank1_downdate<A, S, Sx>(
+    l: &mut ArrayBase<S, Ix2>,
+    x: &ArrayBase<Sx, Ix1>,
+) -> Result<()>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    rank1_modify(l, x, true)
+}
+
+fn rank1_modify<A, S, Sx>(
+    l: &mut ArrayBase<S, Ix2>,
+    x: &ArrayBase<Sx, Ix1>,
+    downdate: bool,
+) -> Result<()>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    l.ensure_square()?;
+    let n = l.nrows();
+    assert_eq!(x.len(), n, "The length of x must be the size of L.");
+    let sign = if downdate {
+        -A::Real::one()
+    } else {
+        A::Real::one()
+    };
+    let mut x = x.to_owned();
+    for k in 0..n {
+        let lkk = l[(k, k)].re();
+        let r2 = lkk * lkk + sign * x[k].square();
+        if r2 <= A::Real::zero() {
+            return Err(LinalgError::NotPositiveDefinite { minor: k + 1 });
+        }
+        let r = Float::sqrt(r2);
+        let c = r / lkk;
+        let s = x[k].div_real(lkk);
+        l[(k, k)] = A::from_real(r);
+        for i in (k + 1)..n {
+            let lik = (l[(i, k)] + s.conj() * x[i].mul_real(sign)).div_real(c);
+            l[(i, k)] = lik;
+            x[i] = x[i].mul_real(c) - s * lik;
+        }
+    }
+    Ok(())
+}
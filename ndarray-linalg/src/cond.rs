@@ -0,0 +1,76 @@
+//! Condition number of general matrices
+//!
+//! [Wikipedia article on condition number](https://en.wikipedia.org/wiki/Condition_number)
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// Condition number `κ(A) = ‖A‖ ‖A^{-1}‖` of matrix
+pub trait Cond<A: Scalar> {
+    /// Compute the condition number in the norm specified by `norm` as
+    /// `numpy.linalg.cond`
+    ///
+    /// - `None` gives the 2-norm condition number `σ_max / σ_min` computed
+    ///   from the singular values, which is also defined for rectangular matrices.
+    /// - `Some(NormType::Frobenius)` is computed from the singular values as
+    ///   `sqrt(Σ σ_i^2) sqrt(Σ σ_i^{-2})`.
+    /// - `Some(NormType::One)` and `Some(NormType::Infinity)` are the reciprocal
+    ///   of [ReciprocalConditionNum::rcond] of `A` and `A^T` respectively, which
+    ///   are *estimated* by `*gecon` after the LU decomposition.
+    ///
+    /// Infinity is returned for singular matrices.
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NotSquare] if `A` is not square except for the 2-norm
+    fn cond(&self, norm: Option<NormType>) -> Result<A::Real>;
+}
+
+impl<A, S> Cond<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn cond(&self, norm: Option<NormType>) -> Result<A::Real> {
+        if norm.is_some() {
+            self.ensure_square()?;
+        }
+        let rcond = match norm {
+            None => {
+                let (_, s, _) = self.svd(false, false)?;
+                return Ok(match (s.first(), s.last()) {
+                    (Some(&s_max), Some(&s_min)) if !s_min.is_zero() => s_max / s_min,
+                    (Some(_), Some(_)) => A::Real::infinity(),
+                    _ => A::Real::zero(),
+                });
+            }
+            Some(NormType::Frobenius) => {
+                let (_, s, _) = self.svd(false, false)?;
+                if s.iter().any(|s| s.is_zero()) {
+                    return Ok(A::Real::infinity());
+                }
+                let norm = s.iter().map(|&s| s * s).sum::<A::Real>();
+                let inv_norm = s.iter().map(|&s| (s * s).recip()).sum::<A::Real>();
+                return Ok(Float::sqrt(norm * inv_norm));
+            }
+            Some(NormType::One) => self.rcond(),
+            // `‖A‖_∞ = ‖A^T‖_1`
+            Some(NormType::Infinity) => self.t().rcond(),
+        };
+        match rcond {
+            Ok(rcond) => Ok(rcond.recip()),
+            Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. })) => {
+                // The matrix is exactly singular in the LU decomposition.
+                Ok(A::Real::infinity())
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
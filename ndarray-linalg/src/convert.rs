@@ -111,6 +111,152 @@ where
     new
 }
 
+/// Stack vectors into a matrix horizontally, i.e. as columns
+///
+/// The result has a LAPACK-compatible layout, i.e. [AllocatedArray::layout] succeeds,
+/// and can be passed to solvers directly.
+pub fn hstack<A, S>(xs: &[ArrayBase<S, Ix1>]) -> Result<Array<A, Ix2>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let views: Vec<_> = xs.iter().map(|x| x.view()).collect();
+    Ok(into_allocated(stack(Axis(1), &views)?))
+}
+
+/// Stack vectors into a matrix vertically, i.e. as rows
+///
+/// The result has a LAPACK-compatible layout as [hstack].
+pub fn vstack<A, S>(xs: &[ArrayBase<S, Ix1>]) -> Result<Array<A, Ix2>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let views: Vec<_> = xs.iter().map(|x| x.view()).collect();
+    Ok(into_allocated(stack(Axis(0), &views)?))
+}
+
+/// Copy into the standard layout only if the layout is not LAPACK-compatible
+fn into_allocated<A: Copy>(a: Array2<A>) -> Array2<A> {
+    if a.layout().is_ok() {
+        a
+    } else {
+        a.as_standard_layout().into_owned()
+    }
+}
+
+/// Construct a block-diagonal matrix from square or rectangular blocks
+///
+/// The blocks are placed along the diagonal in the given order,
+/// and the remaining elements are zero.
+/// An empty slice gives a `0 x 0` matrix.
+pub fn block_diag<A>(blocks: &[&Array2<A>]) -> Array2<A>
+where
+    A: Scalar,
+{
+    let rows = blocks.iter().map(|b| b.nrows()).sum();
+    let cols = blocks.iter().map(|b| b.ncols()).sum();
+    let mut a = Array2::zeros((rows, cols));
+    let (mut i, mut j) = (0, 0);
+    for b in blocks {
+        let (m, n) = b.dim();
+        a.slice_mut(s![i..i + m, j..j + n]).assign(b);
+        i += m;
+        j += n;
+    }
+    a
+}
+
+/// Conversion between real and complex arrays
+pub trait ComplexParts {
+    type Elem: Scalar;
+    type Dim: Dimension;
+
+    /// Convert into a complex array, with zero imaginary part for a real array
+    fn to_complex(&self) -> Array<<Self::Elem as Scalar>::Complex, Self::Dim>;
+
+    /// Real part of each element
+    fn real_part(&self) -> Array<<Self::Elem as Scalar>::Real, Self::Dim>;
+
+    /// Imaginary part of each element, which is zero for a real array
+    fn imag_part(&self) -> Array<<Self::Elem as Scalar>::Real, Self::Dim>;
+}
+
+impl<A, S, D> ComplexParts for ArrayBase<S, D>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    type Elem = A;
+    type Dim = D;
+
+    fn to_complex(&self) -> Array<A::Complex, D> {
+        self.mapv(|x| x.as_c())
+    }
+
+    fn real_part(&self) -> Array<A::Real, D> {
+        self.mapv(|x| x.re())
+    }
+
+    fn imag_part(&self) -> Array<A::Real, D> {
+        self.mapv(|x| x.im())
+    }
+}
+
+/// Remove the asymmetry of a square matrix accumulated by rounding errors
+pub trait Symmetrize {
+    /// Replace the matrix by its symmetric part `(A + A^T) / 2`
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NotSquare] if the matrix is not square
+    fn symmetrize(&mut self) -> Result<()>;
+
+    /// Replace the matrix by its Hermitian part `(A + A^H) / 2`
+    ///
+    /// The result is exactly Hermitian, i.e. the diagonal is real and
+    /// `a[(i, j)] == a[(j, i)].conj()`. This is same as [Symmetrize::symmetrize] for real matrices.
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NotSquare] if the matrix is not square
+    fn hermitianize(&mut self) -> Result<()>;
+}
+
+impl<A, S> Symmetrize for ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    fn symmetrize(&mut self) -> Result<()> {
+        self.ensure_square()?;
+        let half = A::Real::real(0.5);
+        for i in 0..self.nrows() {
+            for j in (i + 1)..self.ncols() {
+                let m = (self[(i, j)] + self[(j, i)]).mul_real(half);
+                self[(i, j)] = m;
+                self[(j, i)] = m;
+            }
+        }
+        Ok(())
+    }
+
+    fn hermitianize(&mut self) -> Result<()> {
+        self.ensure_square()?;
+        let half = A::Real::real(0.5);
+        for i in 0..self.nrows() {
+            self[(i, i)] = A::from_real(self[(i, i)].re());
+            for j in (i + 1)..self.ncols() {
+                let m = (self[(i, j)] + self[(j, i)].conj()).mul_real(half);
+                self[(i, j)] = m;
+                self[(j, i)] = m.conj();
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Fills in the remainder of a Hermitian matrix that's represented by only one
 /// triangle.
 ///
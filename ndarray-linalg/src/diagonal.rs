@@ -2,6 +2,7 @@
 
 use ndarray::*;
 
+use super::error::*;
 use super::operator::*;
 use super::types::*;
 
@@ -46,3 +47,73 @@ where
         }
     }
 }
+
+/// Extract an off-diagonal of a matrix
+pub trait DiagonalOffset<A> {
+    /// Returns the `k`-th diagonal, which is above the main diagonal for `k > 0`
+    /// and below it for `k < 0`
+    ///
+    /// An empty array is returned if the diagonal lies outside of the matrix.
+    fn diagonal_offset(&self, k: isize) -> Array1<A>;
+}
+
+impl<A, S> DiagonalOffset<A> for ArrayBase<S, Ix2>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+{
+    fn diagonal_offset(&self, k: isize) -> Array1<A> {
+        let (m, n) = self.dim();
+        let offset = k.unsigned_abs();
+        if k >= 0 {
+            self.slice(s![.., offset.min(n)..]).diag().to_owned()
+        } else {
+            self.slice(s![offset.min(m).., ..]).diag().to_owned()
+        }
+    }
+}
+
+/// Construct a square banded matrix from a set of diagonals
+///
+/// Each entry `(k, d)` places `d` on the `k`-th diagonal as in [DiagonalOffset::diagonal_offset],
+/// and diagonals given more than once are summed.
+/// The size `n` of the matrix is determined by `d.len() + |k|`, which must agree for all entries.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Second order finite difference
+/// let a = from_diagonals(&[
+///     (-1, Array1::ones(3)),
+///     (0, Array1::from_elem(4, -2.0)),
+///     (1, Array1::ones(3)),
+/// ])
+/// .unwrap();
+/// assert_eq!(a.row(1), aview1(&[1.0, -2.0, 1.0, 0.0]));
+/// ```
+///
+/// Errors
+/// -------
+/// - `IncompatibleShape` error if the lengths of the diagonals are inconsistent
+pub fn from_diagonals<A, S>(diags: &[(isize, ArrayBase<S, Ix1>)]) -> Result<Array2<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let n = diags.first().map_or(0, |(k, d)| d.len() + k.unsigned_abs());
+    let mut a = Array2::zeros((n, n));
+    for (k, d) in diags {
+        let offset = k.unsigned_abs();
+        if d.len() + offset != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut diag = if *k >= 0 {
+            a.slice_mut(s![.., offset..])
+        } else {
+            a.slice_mut(s![offset.., ..])
+        };
+        diag.diag_mut().zip_mut_with(d, |x, &y| *x += y);
+    }
+    Ok(a)
+}
@@ -0,0 +1,97 @@
+//! Dynamic mode decomposition (DMD)
+//!
+//! For snapshot matrices `X = [x_0, ..., x_{m-1}]` and
+//! `X' = [x_1, ..., x_m]` of a dynamical system, DMD approximates the
+//! linear operator `A` with `X' ≈ A X` by its leading eigenvalues and
+//! eigenvectors (the DMD modes).
+//!
+//! [Wikipedia article on DMD](https://en.wikipedia.org/wiki/Dynamic_mode_decomposition)
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::eig::*;
+use crate::error::*;
+use crate::solve::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// Result of [dmd]
+#[derive(Debug, Clone)]
+pub struct DmdResult<A: Scalar> {
+    /// DMD modes as columns of a `(n, r)` matrix
+    pub modes: Array2<A::Complex>,
+    /// Discrete-time eigenvalues corresponding to the modes
+    ///
+    /// The continuous-time growth rates and frequencies are given by
+    /// `ln(λ) / Δt` for the sampling interval `Δt`.
+    pub eigenvalues: Array1<A::Complex>,
+    /// Amplitudes of the modes in the first snapshot
+    pub amplitudes: Array1<A::Complex>,
+}
+
+/// Exact dynamic mode decomposition of snapshot pairs
+///
+/// The columns of `x` and `x_prime` are the snapshots before and after one
+/// time step. This computes the SVD `X = U Σ V^H` truncated to `rank`
+/// singular values, the reduced operator `Ã = U^H X' V Σ^{-1}` and its
+/// eigenvalue decomposition `Ã W = W Λ`. The DMD modes are
+/// `Φ = X' V Σ^{-1} W`, and the amplitudes `b` are determined from the
+/// projection of the first snapshot `U^H x_0 = W Λ b`.
+///
+/// If `rank` is `None`, or larger than the number of singular values,
+/// the numerical rank of `X` is used.
+///
+/// # Panics
+///
+/// Panics if the shapes of `x` and `x_prime` are different.
+pub fn dmd<A>(
+    x: &ArrayView2<A>,
+    x_prime: &ArrayView2<A>,
+    rank: Option<usize>,
+) -> Result<DmdResult<A>>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+{
+    assert_eq!(
+        x.shape(),
+        x_prime.shape(),
+        "The shapes of the snapshot matrices must be identical.",
+    );
+    let (n, m) = x.dim();
+    let (u, s, vt) = x.svd(true, true)?;
+    let (u, vt) = (u.unwrap(), vt.unwrap());
+
+    let tol = s.first().map_or(A::Real::zero(), |&s0| {
+        s0 * A::real(n.max(m)) * A::Real::epsilon()
+    });
+    let numerical_rank = s.iter().take_while(|&&s| s > tol).count();
+    let r = rank.map_or(numerical_rank, |r| r.min(numerical_rank));
+
+    let ur = u.slice(s![.., ..r]);
+    let uh = ur.t().mapv(|x| x.conj().as_c());
+    // X' V Σ^{-1}
+    let mut xvs = x_prime.dot(&vt.slice(s![..r, ..]).t().mapv(|x| x.conj()));
+    for (mut col, &s) in xvs.axis_iter_mut(Axis(1)).zip(s.iter()) {
+        col.mapv_inplace(|x| x.div_real(s));
+    }
+    let xvs = xvs.mapv(|x| x.as_c());
+
+    let a_tilde = uh.dot(&xvs);
+    let (eigenvalues, w) = a_tilde.eig()?;
+    let modes = xvs.dot(&w);
+
+    let mut wl = w;
+    for (mut col, &l) in wl.axis_iter_mut(Axis(1)).zip(eigenvalues.iter()) {
+        col.mapv_inplace(|x| x * l);
+    }
+    let x0 = uh.dot(&x.column(0).mapv(|x| x.as_c()));
+    let amplitudes = wl.solve_into(x0)?;
+
+    Ok(DmdResult {
+        modes,
+        eigenvalues,
+        amplitudes,
+    })
+}
@@ -3,7 +3,11 @@
 use crate::error::*;
 use crate::layout::*;
 use crate::types::*;
+use crate::validate::check_input;
 use ndarray::*;
+use num_traits::Zero;
+
+pub use lax::GeneralizedEigenvalue;
 
 #[cfg_attr(doc, katexit::katexit)]
 /// Eigenvalue decomposition of general matrix reference
@@ -36,6 +40,55 @@ pub trait Eig {
     /// }
     /// ```
     fn eig(&self) -> Result<(Self::EigVal, Self::EigVec)>;
+
+    /// Calculate eigenvalues with the right eigenvectors in a fixed phase
+    ///
+    /// The eigenvectors computed by [Eig::eig] have unit 2-norm but an
+    /// arbitrary phase. This rotates each of them further so that its
+    /// largest-magnitude component (the first one in case of ties) is real
+    /// and positive, which makes the result reproducible.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[0.0, -1.0], [1.0, 0.0]];
+    /// let (_, vecs) = a.eig_normalized().unwrap();
+    /// for vec in vecs.axis_iter(Axis(1)) {
+    ///     // the first one of the largest-magnitude components
+    ///     let mut imax = 0;
+    ///     for (i, x) in vec.iter().enumerate() {
+    ///         if x.abs() > vec[imax].abs() {
+    ///             imax = i;
+    ///         }
+    ///     }
+    ///     assert_eq!(vec[imax].im, 0.0);
+    ///     assert!(vec[imax].re > 0.0);
+    /// }
+    /// ```
+    fn eig_normalized<C>(&self) -> Result<(Self::EigVal, Self::EigVec)>
+    where
+        C: Scalar,
+        Self: Eig<EigVec = Array2<C>>,
+    {
+        let (s, mut t) = self.eig()?;
+        for mut v in t.axis_iter_mut(Axis(1)) {
+            let mut imax = 0;
+            for (i, x) in v.iter().enumerate() {
+                if x.abs() > v[imax].abs() {
+                    imax = i;
+                }
+            }
+            let r = v[imax].abs();
+            if r.is_zero() {
+                continue;
+            }
+            let phase = v[imax].conj().div_real(r);
+            v.mapv_inplace(|x| x * phase);
+            v[imax] = C::from_real(r);
+        }
+        Ok((s, t))
+    }
 }
 
 impl<A, S> Eig for ArrayBase<S, Ix2>
@@ -47,6 +100,7 @@ where
     type EigVec = Array2<A::Complex>;
 
     fn eig(&self) -> Result<(Self::EigVal, Self::EigVec)> {
+        check_input(self)?;
         let mut a = self.to_owned();
         let layout = a.square_layout()?;
         let (s, t) = A::eig(true, layout, a.as_allocated_mut()?)?;
@@ -59,6 +113,9 @@ where
 }
 
 /// Calculate eigenvalues without eigenvectors
+///
+/// This is cheaper than [Eig] since the eigenvectors are neither computed
+/// nor allocated.
 pub trait EigVals {
     type EigVal;
     fn eigvals(&self) -> Result<Self::EigVal>;
@@ -72,8 +129,218 @@ where
     type EigVal = Array1<A::Complex>;
 
     fn eigvals(&self) -> Result<Self::EigVal> {
+        check_input(self)?;
         let mut a = self.to_owned();
         let (s, _) = A::eig(false, a.square_layout()?, a.as_allocated_mut()?)?;
         Ok(ArrayBase::from(s))
     }
 }
+
+/// Result of [EigCond::eig_cond]
+#[derive(Debug, Clone)]
+pub struct EigCondResult<A: Scalar> {
+    /// Eigenvalues
+    pub eigs: Array1<A::Complex>,
+    /// Right eigenvectors as columns, normalized to unit 2-norm
+    pub vecs: Array2<A::Complex>,
+    /// Reciprocal condition numbers of the eigenvalues
+    ///
+    /// This is `|y^H x|` for the normalized left and right eigenvectors `y`
+    /// and `x`, which is `1` for normal matrices. The error of the computed
+    /// eigenvalue is approximately bounded by `eps ||A|| / rconde`.
+    pub rconde: Array1<A::Real>,
+    /// Reciprocal condition numbers of the right eigenvectors
+    pub rcondv: Array1<A::Real>,
+    /// Start of the rows and columns not isolated by the permutation of the
+    /// balancing, `ilo..ihi` in 0-based indices
+    pub ilo: usize,
+    /// End of the rows and columns not isolated by the permutation of the
+    /// balancing, `ilo..ihi` in 0-based indices
+    pub ihi: usize,
+    /// Permutation of the balancing, see `SCALE` of `?GEEVX` in LAPACK
+    pub scale: Array1<A::Real>,
+    /// One-norm of the matrix
+    pub abnrm: A::Real,
+}
+
+/// Eigenvalue decomposition of general matrix with the condition numbers
+pub trait EigCond<A: Scalar> {
+    /// Calculate eigenvalues and right eigenvectors with their reciprocal
+    /// condition numbers
+    ///
+    /// The matrix is balanced only by permutation, which does not change the
+    /// condition numbers. Small condition numbers indicate sensitive eigenvalues or eigenvectors,
+    /// e.g. of a non-normal matrix:
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[1.0, 1e4], [0.0, 1.0 + 1e-4]];
+    /// let r = a.eig_cond().unwrap();
+    /// assert!(r.rconde.iter().all(|&c| c < 1e-6));
+    /// ```
+    fn eig_cond(&self) -> Result<EigCondResult<A>>;
+}
+
+impl<A, S> EigCond<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn eig_cond(&self) -> Result<EigCondResult<A>> {
+        check_input(self)?;
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let lax::EigCondOwned {
+            eigs,
+            vr,
+            rconde,
+            rcondv,
+            ilo,
+            ihi,
+            scale,
+            abnrm,
+        } = A::eig_cond(layout, a.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        Ok(EigCondResult {
+            eigs: ArrayBase::from(eigs),
+            vecs: Array2::from_shape_vec((n, n).f(), vr).unwrap(),
+            rconde: ArrayBase::from(rconde),
+            rcondv: ArrayBase::from(rcondv),
+            ilo: (ilo - 1) as usize,
+            ihi: ihi as usize,
+            scale: ArrayBase::from(scale),
+            abnrm,
+        })
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Eigenvalue decomposition of general matrix pencil
+pub trait EigGeneralized {
+    type EigVal;
+    type EigVec;
+    type Real;
+    /// Calculate generalized eigenvalues with the right eigenvectors
+    ///
+    /// $$ A u_i = \lambda_i B u_i $$
+    ///
+    /// The eigenvalues are returned as [GeneralizedEigenvalue] holding the
+    /// pair $(\alpha_i, \beta_i)$ with $\beta_i A u_i = \alpha_i B u_i$.
+    /// The eigenvalue is [GeneralizedEigenvalue::Indeterminate] if $|\beta_i|$
+    /// is not larger than `thresh_opt` (zero if `None`), e.g. for the
+    /// infinite eigenvalues of a singular $B$.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[1.0, 2.0], [0.0, 3.0]];
+    /// let b: Array2<f64> = array![[1.0, 0.0], [0.0, 0.0]];
+    /// let (eigs, vecs) = (a.view(), b.view()).eig_generalized(Some(1e-12)).unwrap();
+    ///
+    /// let (a, b) = (a.map(|v| v.as_c()), b.map(|v| v.as_c()));
+    /// for (e, vec) in eigs.iter().zip(vecs.axis_iter(Axis(1))) {
+    ///     let av = a.dot(&vec).map(|v| v * e.beta());
+    ///     let bv = b.dot(&vec).map(|v| v * e.alpha());
+    ///     assert_close_l2!(&av, &bv, 1e-9);
+    /// }
+    /// ```
+    fn eig_generalized(
+        &self,
+        thresh_opt: Option<Self::Real>,
+    ) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+/// Calculate generalized eigenvalues without eigenvectors
+pub trait EigValsGeneralized {
+    type EigVal;
+    type Real;
+    fn eigvals_generalized(&self, thresh_opt: Option<Self::Real>) -> Result<Self::EigVal>;
+}
+
+/// Copy a pencil into owned arrays in the same memory order as `a`
+fn pencil_to_owned<A, S, S2>(
+    a: &ArrayBase<S, Ix2>,
+    b: &ArrayBase<S2, Ix2>,
+) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    if a.dim() != b.dim() {
+        return Err(LinalgError::NotStandardShape {
+            obj: "Matrix pencil",
+            rows: b.nrows() as i32,
+            cols: b.ncols() as i32,
+        });
+    }
+    let a = a.to_owned();
+    let mut b_ = if a.is_standard_layout() {
+        Array2::zeros(a.dim())
+    } else {
+        Array2::zeros(a.dim().f())
+    };
+    b_.assign(b);
+    Ok((a, b_))
+}
+
+impl<A, S, S2> EigGeneralized for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type EigVal = Array1<GeneralizedEigenvalue<A::Complex>>;
+    type EigVec = Array2<A::Complex>;
+    type Real = A::Real;
+
+    fn eig_generalized(
+        &self,
+        thresh_opt: Option<Self::Real>,
+    ) -> Result<(Self::EigVal, Self::EigVec)> {
+        check_input(&self.0)?;
+        check_input(&self.1)?;
+        let (mut a, mut b) = pencil_to_owned(&self.0, &self.1)?;
+        let layout = a.square_layout()?;
+        let pairs = A::eig_generalized(
+            true,
+            layout,
+            a.as_allocated_mut()?,
+            b.as_allocated_mut()?,
+            thresh_opt,
+        )?;
+        let n = layout.len() as usize;
+        Ok((
+            ArrayBase::from(pairs.eigs),
+            Array2::from_shape_vec((n, n).f(), pairs.vecs).unwrap(),
+        ))
+    }
+}
+
+impl<A, S, S2> EigValsGeneralized for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type EigVal = Array1<GeneralizedEigenvalue<A::Complex>>;
+    type Real = A::Real;
+
+    fn eigvals_generalized(&self, thresh_opt: Option<Self::Real>) -> Result<Self::EigVal> {
+        check_input(&self.0)?;
+        check_input(&self.1)?;
+        let (mut a, mut b) = pencil_to_owned(&self.0, &self.1)?;
+        let pairs = A::eig_generalized(
+            false,
+            a.square_layout()?,
+            a.as_allocated_mut()?,
+            b.as_allocated_mut()?,
+            thresh_opt,
+        )?;
+        Ok(ArrayBase::from(pairs.eigs))
+    }
+}
@@ -11,6 +11,10 @@
 //! order and `V` is the matrix of corresponding generalized eigenvectors. The
 //! matrix `V` is normalized such that `V^H B V = I`.
 //!
+//! Only the triangular part specified by [UPLO] is read. It refers to the
+//! matrix as displayed, i.e. [UPLO::Upper] means the elements `a[(i, j)]`
+//! with `i <= j`, regardless of the memory layout of the array.
+//!
 //! # Example
 //!
 //! Find the eigendecomposition of a Hermitian (or real symmetric) matrix.
@@ -34,14 +38,23 @@
 //! ```
 
 use ndarray::*;
+use num_traits::{One, ToPrimitive};
 
+use crate::convert::Symmetrize;
 use crate::diagonal::*;
 use crate::error::*;
+use crate::generate::conjugate;
+use crate::inner::InnerProduct;
 use crate::layout::*;
+use crate::norm::Norm;
 use crate::operator::LinearOperator;
+use crate::solve::Solve;
 use crate::types::*;
+use crate::validate::check_input;
 use crate::UPLO;
 
+pub use lax::EigRange;
+
 /// Eigenvalue decomposition of Hermite matrix reference
 pub trait Eigh {
     type EigVal;
@@ -49,6 +62,49 @@ pub trait Eigh {
     fn eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)>;
 }
 
+/// Eigenvalue decomposition of Hermite matrix with a check of the input
+pub trait EighChecked {
+    type EigVal;
+    type EigVec;
+    type Real;
+
+    /// Same as [Eigh::eigh], but verify that `‖A - A^H‖_F <= tol` first
+    ///
+    /// [Eigh::eigh] reads only the `uplo` triangle, and silently gives a wrong result
+    /// for a non-Hermitian input. The matrix passed the check is made exactly Hermitian
+    /// by [Symmetrize::hermitianize] before the decomposition.
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NotHermitian] if the matrix is not Hermitian within `tol`
+    /// - [LinalgError::NotSquare] if the matrix is not square
+    fn eigh_checked(&self, uplo: UPLO, tol: Self::Real) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A, S> EighChecked for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+    type Real = A::Real;
+
+    fn eigh_checked(&self, uplo: UPLO, tol: A::Real) -> Result<(Self::EigVal, Self::EigVec)> {
+        self.ensure_square()?;
+        let ah: Array2<A> = conjugate(self);
+        let deviation = (self - &ah).norm_l2();
+        if deviation > tol {
+            return Err(LinalgError::NotHermitian {
+                deviation: deviation.to_f64().unwrap(),
+            });
+        }
+        let mut a = self.to_owned();
+        a.hermitianize()?;
+        a.eigh_into(uplo)
+    }
+}
+
 /// Eigenvalue decomposition of mutable reference of Hermite matrix
 pub trait EighInplace {
     type EigVal;
@@ -125,13 +181,21 @@ where
     type EigVal = Array1<A::Real>;
 
     fn eigh_inplace(&mut self, uplo: UPLO) -> Result<(Self::EigVal, &mut Self)> {
+        check_input(self)?;
         let layout = self.square_layout()?;
         // XXX Force layout to be Fortran (see #146)
-        match layout {
-            MatrixLayout::C { .. } => self.swap_axes(0, 1),
-            MatrixLayout::F { .. } => {}
-        }
+        let uplo = match layout {
+            MatrixLayout::C { .. } => {
+                self.swap_axes(0, 1);
+                uplo.t()
+            }
+            MatrixLayout::F { .. } => uplo,
+        };
         let s = A::eigh(true, self.square_layout()?, uplo, self.as_allocated_mut()?)?;
+        // The transposed matrix is `A^*`, whose eigenvectors are conjugated
+        if matches!(layout, MatrixLayout::C { .. }) {
+            self.mapv_inplace(|x| x.conj());
+        }
         Ok((ArrayBase::from(s), self))
     }
 }
@@ -148,25 +212,30 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if the shapes of the matrices are different.
+    /// Panics if the shapes or the memory layouts (C or Fortran) of the matrices are different.
     fn eigh_inplace(&mut self, uplo: UPLO) -> Result<(Self::EigVal, &mut Self)> {
         assert_eq!(
             self.0.shape(),
             self.1.shape(),
             "The shapes of the matrices must be identical.",
         );
+        check_input(&self.0)?;
+        check_input(&self.1)?;
         let layout = self.0.square_layout()?;
+        assert_eq!(
+            matches!(layout, MatrixLayout::C { .. }),
+            matches!(self.1.square_layout()?, MatrixLayout::C { .. }),
+            "The matrices must have the same memory layout.",
+        );
         // XXX Force layout to be Fortran (see #146)
-        match layout {
-            MatrixLayout::C { .. } => self.0.swap_axes(0, 1),
-            MatrixLayout::F { .. } => {}
-        }
-
-        let layout = self.1.square_layout()?;
-        match layout {
-            MatrixLayout::C { .. } => self.1.swap_axes(0, 1),
-            MatrixLayout::F { .. } => {}
-        }
+        let (swapped, uplo) = match layout {
+            MatrixLayout::C { .. } => {
+                self.0.swap_axes(0, 1);
+                self.1.swap_axes(0, 1);
+                (true, uplo.t())
+            }
+            MatrixLayout::F { .. } => (false, uplo),
+        };
 
         let s = A::eigh_generalized(
             true,
@@ -175,12 +244,108 @@ where
             self.0.as_allocated_mut()?,
             self.1.as_allocated_mut()?,
         )?;
+        // The transposed matrices are `A^*` and `B^*`, whose eigenvectors are conjugated
+        if swapped {
+            self.0.mapv_inplace(|x| x.conj());
+        }
 
         Ok((ArrayBase::from(s), self))
     }
 }
 
+/// Generalized eigenvalue problem `A x = λ B x` for Hermitian (or real
+/// symmetric) `A` and Hermitian (or real symmetric) positive definite `B`
+///
+/// The eigenvalues are real and returned in ascending order. The eigenvectors
+/// are the columns of `V`, which is normalized such that `V^H B V = I`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2.0, 0.0], [0.0, 12.0]];
+/// let b: Array2<f64> = array![[2.0, 0.0], [0.0, 4.0]];
+/// let (e, v) = (&a, &b).eigh_generalized(UPLO::Upper).unwrap();
+/// assert_close_l2!(&e, &array![1.0, 3.0], 1e-12);
+/// assert_close_l2!(&v.t().dot(&b).dot(&v), &Array2::eye(2), 1e-12);
+/// ```
+pub trait EighGeneralized {
+    type EigVal;
+    type EigVec;
+
+    /// Eigenvalues and `B`-orthonormal eigenvectors by `*sygv`/`*hegv`
+    ///
+    /// Only the `uplo` triangles of `A` and `B` are referred.
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NotSquare] if `A` is not square
+    /// - [LinalgError::NotStandardShape] if `B` has a different shape from `A`
+    /// - [LinalgError::Lapack] if `B` is not positive definite
+    fn eigh_generalized(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)>;
+
+    /// Eigenvalues without eigenvectors
+    fn eigvalsh_generalized(&self, uplo: UPLO) -> Result<Self::EigVal>;
+}
+
+impl<A, S, S2> EighGeneralized for (&ArrayBase<S, Ix2>, &ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_generalized(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (e, (v, _)) = pencil_to_fortran(self.0, self.1)?.eigh_into(uplo)?;
+        Ok((e, v))
+    }
+
+    fn eigvalsh_generalized(&self, uplo: UPLO) -> Result<Self::EigVal> {
+        let (mut a, mut b) = pencil_to_fortran(self.0, self.1)?;
+        let e = A::eigh_generalized(
+            false,
+            a.square_layout()?,
+            uplo,
+            a.as_allocated_mut()?,
+            b.as_allocated_mut()?,
+        )?;
+        Ok(ArrayBase::from(e))
+    }
+}
+
+/// Copy a pencil into column-major arrays
+fn pencil_to_fortran<A, S, S2>(
+    a: &ArrayBase<S, Ix2>,
+    b: &ArrayBase<S2, Ix2>,
+) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    if a.dim() != b.dim() {
+        return Err(LinalgError::NotStandardShape {
+            obj: "Matrix pencil",
+            rows: b.nrows() as i32,
+            cols: b.ncols() as i32,
+        });
+    }
+    check_input(a)?;
+    check_input(b)?;
+    let mut a_ = Array2::zeros(a.dim().f());
+    a_.assign(a);
+    let mut b_ = Array2::zeros(b.dim().f());
+    b_.assign(b);
+    Ok((a_, b_))
+}
+
 /// Calculate eigenvalues without eigenvectors
+///
+/// This is cheaper than [Eigh] since LAPACK skips the accumulation of the
+/// eigenvectors.
 pub trait EigValsh {
     type EigVal;
     fn eigvalsh(&self, uplo: UPLO) -> Result<Self::EigVal>;
@@ -231,11 +396,78 @@ where
     type EigVal = Array1<A::Real>;
 
     fn eigvalsh_inplace(&mut self, uplo: UPLO) -> Result<Self::EigVal> {
-        let s = A::eigh(true, self.square_layout()?, uplo, self.as_allocated_mut()?)?;
+        check_input(self)?;
+        let layout = self.square_layout()?;
+        // `uplo` refers to the row-major storage of a C-layout matrix
+        let uplo = match layout {
+            MatrixLayout::C { .. } => uplo.t(),
+            MatrixLayout::F { .. } => uplo,
+        };
+        let s = A::eigh(false, layout, uplo, self.as_allocated_mut()?)?;
         Ok(ArrayBase::from(s))
     }
 }
 
+/// Selected eigenvalues and eigenvectors of Hermite matrix reference
+///
+/// Only the eigenpairs in the [EigRange] are computed by `*syevr`/`*heevr`,
+/// which is much cheaper than [Eigh] for a small number of eigenpairs.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2.0, 1.0, 0.0], [1.0, 2.0, 1.0], [0.0, 1.0, 2.0]];
+/// let (e, v) = a.eigh_range(UPLO::Lower, EigRange::Index(0..1)).unwrap();
+/// assert_eq!(v.dim(), (3, 1));
+/// assert_rclose!(e[0], 2.0 - 2.0_f64.sqrt(), 1e-12);
+/// ```
+pub trait EighRange {
+    type EigVal;
+    type EigVec;
+    type Real;
+    /// Eigenvalues in ascending order and the corresponding eigenvectors as
+    /// columns. Both are empty for an empty range.
+    fn eigh_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<Self::Real>,
+    ) -> Result<(Self::EigVal, Self::EigVec)>;
+    /// Eigenvalues in ascending order without eigenvectors
+    fn eigvalsh_range(&self, uplo: UPLO, range: EigRange<Self::Real>) -> Result<Self::EigVal>;
+}
+
+impl<A, S> EighRange for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+    type Real = A::Real;
+
+    fn eigh_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<Self::Real>,
+    ) -> Result<(Self::EigVal, Self::EigVec)> {
+        check_input(self)?;
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let (e, v) = A::eigh_selected(true, layout, uplo, a.as_allocated_mut()?, &range)?;
+        let v = Array2::from_shape_vec((self.nrows(), e.len()).f(), v)?;
+        Ok((ArrayBase::from(e), v))
+    }
+
+    fn eigvalsh_range(&self, uplo: UPLO, range: EigRange<Self::Real>) -> Result<Self::EigVal> {
+        check_input(self)?;
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let (e, _) = A::eigh_selected(false, layout, uplo, a.as_allocated_mut()?, &range)?;
+        Ok(ArrayBase::from(e))
+    }
+}
+
 /// Calculate symmetric square-root matrix using `eigh`
 pub trait SymmetricSqrt {
     type Output;
@@ -275,3 +507,164 @@ where
         Ok(v.apply2(&ev))
     }
 }
+
+/// Calculate the symmetric whitening matrix and its inverse using a single `eigh`
+pub trait WhiteningPair {
+    type Real;
+    type Output;
+
+    /// Returns `(W, W^{-1})` where `W = (A + εI)^{-1/2}` and `W^{-1} = (A + εI)^{1/2}`
+    /// for the Hermitian (or real symmetric) positive definite matrix `A`.
+    ///
+    /// Both matrices are Hermitian and share the eigenvectors of `A`, so that
+    /// `W A W ≈ I` for small `epsilon`. The regularization `epsilon` is added
+    /// to every eigenvalue to keep the transform finite for nearly singular
+    /// covariance matrices.
+    fn whitening_pair(
+        &self,
+        uplo: UPLO,
+        epsilon: Self::Real,
+    ) -> Result<(Self::Output, Self::Output)>;
+}
+
+impl<A, S> WhiteningPair for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Real = A::Real;
+    type Output = Array2<A>;
+
+    fn whitening_pair(&self, uplo: UPLO, epsilon: A::Real) -> Result<(Array2<A>, Array2<A>)> {
+        let (e, v) = self.eigh(uplo)?;
+        let vh: Array2<A> = conjugate(&v);
+        let e_sqrt = e.mapv(|e| (e + epsilon).sqrt());
+        let w = e_sqrt
+            .mapv(|s| A::from_real(A::Real::one() / s))
+            .into_diagonal()
+            .apply2(&vh);
+        let w_inv = e_sqrt.mapv(A::from_real).into_diagonal().apply2(&vh);
+        Ok((v.dot(&w), v.dot(&w_inv)))
+    }
+}
+
+/// Eigenvalue decomposition of a block-diagonal Hermitian matrix
+///
+/// Each diagonal block is solved independently by [Eigh], which is much
+/// cheaper than decomposing the whole matrix when its block structure is
+/// known, e.g. after a symmetry-adapted change of basis.
+///
+/// Returns the eigenvalues of all blocks in a single array sorted in
+/// ascending order, and the eigenvectors of each block as computed by
+/// [Eigh], i.e. the `i`-th element of the returned `Vec` is the matrix of
+/// eigenvectors of `blocks[i]`, whose columns are sorted by the
+/// eigenvalues of that block.
+pub fn eigh_blocks<A>(
+    blocks: &[ArrayView2<A>],
+    uplo: UPLO,
+) -> Result<(Array1<A::Real>, Vec<Array2<A>>)>
+where
+    A: Scalar + Lapack,
+{
+    let mut eigvals = Vec::new();
+    let mut eigvecs = Vec::with_capacity(blocks.len());
+    for block in blocks {
+        let (e, v) = block.eigh(uplo)?;
+        eigvals.extend(e);
+        eigvecs.push(v);
+    }
+    eigvals.sort_by(|a, b| a.to_f64().unwrap().total_cmp(&b.to_f64().unwrap()));
+    Ok((Array1::from(eigvals), eigvecs))
+}
+
+/// Maximum number of Rayleigh quotient iterations for each eigenpair in [EighRefine]
+const MAX_REFINE_ITER: usize = 5;
+
+/// Eigenvalue decomposition of Hermite matrix with iterative refinement
+pub trait EighRefine {
+    type EigVal;
+    type EigVec;
+
+    /// Refine the eigenpairs of [Eigh] to `target_digits` digits
+    ///
+    /// Each eigenpair `(λ, v)` is refined by the Rayleigh quotient
+    /// iteration, which solves the shifted system `(A - λI) w = v` and
+    /// updates `v = w / |w|` and `λ = v^H A v`, until the residual
+    /// `|A v - λ v|` becomes smaller than `10^{-target_digits} |A|_F`.
+    /// The refinement of a pair is stopped after a few iterations if the
+    /// target is not reached, e.g. when it is beyond the working precision,
+    /// and the pair with the smallest residual is returned.
+    ///
+    /// Since each pair is refined independently, eigenvectors of
+    /// (nearly) degenerate eigenvalues are not re-orthogonalized.
+    fn eigh_refine(&self, uplo: UPLO, target_digits: u32) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A, S> EighRefine for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_refine(&self, uplo: UPLO, target_digits: u32) -> Result<(Self::EigVal, Self::EigVec)> {
+        let n = self.square_layout()?.len() as usize;
+        // Restore the full Hermitian matrix from the triangle referred by `uplo`.
+        let mut h = Array2::zeros((n, n));
+        for ((i, j), val) in h.indexed_iter_mut() {
+            let stored = match uplo {
+                UPLO::Upper => i <= j,
+                UPLO::Lower => i >= j,
+            };
+            *val = if i == j {
+                A::from_real(self[(i, i)].re())
+            } else if stored {
+                self[(i, j)]
+            } else {
+                self[(j, i)].conj()
+            };
+        }
+        let (mut e, mut v) = h.eigh(uplo)?;
+        let tol = A::real(10_f64.powi(-(target_digits as i32))) * h.norm_l2();
+        let residual = |x: &Array1<A>, lambda: A::Real| {
+            let mut r = h.dot(x);
+            r.scaled_add(-A::from_real(lambda), x);
+            r.norm_l2()
+        };
+
+        for (lambda, mut col) in e.iter_mut().zip(v.axis_iter_mut(Axis(1))) {
+            let mut x = col.to_owned();
+            let mut res = residual(&x, *lambda);
+            for _ in 0..MAX_REFINE_ITER {
+                if res <= tol {
+                    break;
+                }
+                let mut shifted = h.clone();
+                for i in 0..n {
+                    shifted[(i, i)] -= A::from_real(*lambda);
+                }
+                // The shift is an exact eigenvalue in working precision
+                // if the shifted matrix is singular.
+                let w = match shifted.solve(&x) {
+                    Ok(w) => w,
+                    Err(_) => break,
+                };
+                // Keep the phase of the eigenvector, i.e. `x^H x_new > 0`
+                let p = x.inner(&w);
+                let scale = A::from_real(p.abs() / w.norm_l2()) / p;
+                let x_new = w.mapv(|w| w * scale);
+                let lambda_new = x_new.inner(&h.dot(&x_new)).re();
+                let res_new = residual(&x_new, lambda_new);
+                if res_new.partial_cmp(&res) != Some(std::cmp::Ordering::Less) {
+                    break;
+                }
+                x = x_new;
+                *lambda = lambda_new;
+                res = res_new;
+            }
+            col.assign(&x);
+        }
+        Ok((e, v))
+    }
+}
@@ -35,4 +35,51 @@ pub enum LinalgError {
     /// Strides of the array is not supported
     #[error(transparent)]
     Shape(#[from] ShapeError),
+
+    /// Matrix is not orthogonal/unitary within the tolerance
+    #[error("Matrix is not orthogonal/unitary")]
+    NotOrthogonal,
+
+    /// Matrix is not Hermitian within the tolerance, where `deviation` is `‖A - A^H‖_F`
+    #[error("Matrix is not Hermitian: |A - A^H| = {}", deviation)]
+    NotHermitian { deviation: f64 },
+
+    /// Matrix square root does not exist, e.g. for a singular defective matrix
+    #[error("Matrix square root does not exist")]
+    NoSquareRoot,
+
+    /// Matrix logarithm does not exist since the matrix is singular
+    #[error("Matrix logarithm does not exist for a singular matrix")]
+    NoLogarithm,
+
+    /// Matrix is not positive definite since the leading minor of order `minor` is not positive
+    #[error(
+        "Not positive definite: leading minor of order {} is not positive",
+        minor
+    )]
+    NotPositiveDefinite { minor: usize },
+
+    /// Input contains a NaN or infinite element
+    #[error("Non-finite element at index {:?}", index)]
+    InvalidInput { index: Vec<usize> },
+
+    /// The order `p` of the norm is not supported
+    #[error("Norm of order p = {} is not supported", p)]
+    UnsupportedNormOrder { p: f64 },
+
+    /// Statistics need at least `required` samples, but only `count` are given
+    #[error("Not enough samples: {} < {}", count, required)]
+    NotEnoughSamples { count: usize, required: usize },
+
+    /// The diagonal element at `index` is zero where it is inverted
+    #[error("Zero diagonal element at index {}", index)]
+    ZeroDiagonal { index: usize },
+
+    /// Iterative method breaks down at `iteration` by a vanishing denominator
+    #[error("Iterative method breaks down at iteration {}", iteration)]
+    Breakdown { iteration: usize },
+
+    /// 1-norm of the matrix is not finite
+    #[error("1-norm of the matrix is not finite")]
+    NonFiniteNorm,
 }
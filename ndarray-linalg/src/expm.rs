@@ -0,0 +1,453 @@
+//! Matrix exponential
+//!
+//! - N. J. Higham, "The Scaling and Squaring Method for the Matrix
+//!   Exponential Revisited", SIAM J. Matrix Anal. Appl. 26 (2005)
+//! - A. H. Al-Mohy and N. J. Higham, "Computing the Fréchet Derivative of
+//!   the Matrix Exponential, with an Application to Condition Number
+//!   Estimation", SIAM J. Matrix Anal. Appl. 30 (2009)
+//! - A. H. Al-Mohy and N. J. Higham, "Computing the Action of the Matrix
+//!   Exponential, with an Application to Exponential Integrators",
+//!   SIAM J. Sci. Comput. 33 (2011)
+
+use ndarray::*;
+use num_traits::{Float, ToPrimitive, Zero};
+use std::cell::RefCell;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::normest1::normest1;
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::types::*;
+use crate::validate::*;
+
+/// Coefficients of the `[m/m]` Padé approximants of `exp(x)`
+const PADE_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const PADE_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const PADE_7: [f64; 8] = [
+    17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+];
+const PADE_9: [f64; 10] = [
+    17643225600.0,
+    8821612800.0,
+    2075673600.0,
+    302702400.0,
+    30270240.0,
+    2162160.0,
+    110880.0,
+    3960.0,
+    90.0,
+    1.0,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+/// Largest 1-norm for which the `[m/m]` Padé approximant of degree
+/// `m = 3, 5, 7, 9, 13` attains double precision
+const THETA: [f64; 5] = [
+    1.495585217958292e-2,
+    2.53939833006323e-1,
+    9.504178996162932e-1,
+    2.097847961257068e0,
+    5.371920351148152e0,
+];
+
+/// Largest 1-norm for which the `[m/m]` Padé approximant of degree
+/// `m = 3, 5, 7` attains single precision
+const THETA_SINGLE: [f64; 3] = [
+    4.258730016922831e-1,
+    1.880152677804762e0,
+    3.92572478313866e0,
+];
+
+/// Pairs of the degree `m` of the truncated Taylor series of `exp(x)` and
+/// the largest 1-norm `θ_m` for which it attains double precision
+const THETA_TAYLOR: [(usize, f64); 35] = [
+    (1, 2.29e-16),
+    (2, 2.58e-8),
+    (3, 1.39e-5),
+    (4, 3.40e-4),
+    (5, 2.40e-3),
+    (6, 9.07e-3),
+    (7, 2.38e-2),
+    (8, 5.00e-2),
+    (9, 8.96e-2),
+    (10, 1.44e-1),
+    (11, 2.14e-1),
+    (12, 3.00e-1),
+    (13, 4.00e-1),
+    (14, 5.14e-1),
+    (15, 6.41e-1),
+    (16, 7.81e-1),
+    (17, 9.31e-1),
+    (18, 1.09),
+    (19, 1.26),
+    (20, 1.44),
+    (21, 1.62),
+    (22, 1.82),
+    (23, 2.01),
+    (24, 2.22),
+    (25, 2.43),
+    (26, 2.64),
+    (27, 2.86),
+    (28, 3.08),
+    (29, 3.31),
+    (30, 3.54),
+    (35, 4.7),
+    (40, 6.0),
+    (45, 7.2),
+    (50, 8.5),
+    (55, 9.9),
+];
+
+/// Largest power `p` for which `|A^p|_1^{1/p}` is estimated in [expm_multiply]
+const P_MAX: usize = 8;
+
+/// Matrix exponential `exp(A)` of a square matrix
+///
+/// This uses the scaling and squaring method with Padé approximants, where
+/// the degree and the number of squarings are chosen from the 1-norm of `A`
+/// to attain the precision of the element type. The degree is up to 13 for
+/// double precision (`f64` and `c64`), and up to 7 for single precision
+/// (`f32` and `c32`).
+///
+/// Returns `LinalgError::InvalidInput` if `A` has a NaN or infinite element,
+/// and `LinalgError::NonFiniteNorm` if the 1-norm of `A` overflows, since
+/// the number of squarings cannot be chosen then.
+pub fn expm<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    let n = a.nrows();
+    a.validate_finite()?;
+    let a = a.to_owned();
+    let norm = a.opnorm_one()?.to_f64().unwrap();
+    if !norm.is_finite() {
+        return Err(LinalgError::NonFiniteNorm);
+    }
+
+    let a2 = a.dot(&a);
+    let pade = |b: &[f64], a: &Array2<A>, a2: &Array2<A>| -> Result<Array2<A>> {
+        // U = A Σ b_{2j+1} A^{2j}, V = Σ b_{2j} A^{2j}
+        let mut u = Array2::zeros((n, n));
+        let mut v = Array2::zeros((n, n));
+        let mut power = Array2::eye(n);
+        for (j, c) in b.chunks(2).enumerate() {
+            if j > 0 {
+                power = power.dot(a2);
+            }
+            v.scaled_add(coef::<A>(c[0]), &power);
+            u.scaled_add(coef::<A>(c[1]), &power);
+        }
+        solve_pade(&a.dot(&u), &v)
+    };
+    if A::Real::epsilon().to_f64().unwrap() >= f64::from(f32::EPSILON) {
+        for (b, &theta) in [&PADE_3[..], &PADE_5, &PADE_7]
+            .iter()
+            .zip(THETA_SINGLE.iter())
+        {
+            if norm <= theta {
+                return pade(b, &a, &a2);
+            }
+        }
+        let s = (norm / THETA_SINGLE[2]).log2().ceil() as i32;
+        let scale = A::from_real(A::real(2.0f64.powi(-s)));
+        let a = a.mapv(|x| x * scale);
+        let a2 = a.dot(&a);
+        let mut r = pade(&PADE_7, &a, &a2)?;
+        for _ in 0..s {
+            r = r.dot(&r);
+        }
+        return Ok(r);
+    }
+
+    for (b, &theta) in [&PADE_3[..], &PADE_5, &PADE_7, &PADE_9]
+        .iter()
+        .zip(THETA.iter())
+    {
+        if norm <= theta {
+            return pade(b, &a, &a2);
+        }
+    }
+
+    let s = if norm > THETA[4] {
+        (norm / THETA[4]).log2().ceil() as i32
+    } else {
+        0
+    };
+    let scale = A::from_real(A::real(2.0f64.powi(-s)));
+    let a = a.mapv(|x| x * scale);
+    let a2 = a.dot(&a);
+    let a4 = a2.dot(&a2);
+    let a6 = a4.dot(&a2);
+    let eye = Array2::<A>::eye(n);
+    // Linear combination Σ b_i M_i of the powers M_i
+    let lincomb = |init: Array2<A>, terms: &[(usize, &Array2<A>)]| {
+        terms.iter().fold(init, |mut acc, &(i, m)| {
+            acc.scaled_add(coef::<A>(PADE_13[i]), m);
+            acc
+        })
+    };
+
+    let w = lincomb(Array2::zeros((n, n)), &[(13, &a6), (11, &a4), (9, &a2)]);
+    let w = lincomb(a6.dot(&w), &[(7, &a6), (5, &a4), (3, &a2), (1, &eye)]);
+    let u = a.dot(&w);
+    let z = lincomb(Array2::zeros((n, n)), &[(12, &a6), (10, &a4), (8, &a2)]);
+    let z = lincomb(a6.dot(&z), &[(6, &a6), (4, &a4), (2, &a2), (0, &eye)]);
+
+    let mut r = solve_pade(&u, &z)?;
+    for _ in 0..s {
+        r = r.dot(&r);
+    }
+    Ok(r)
+}
+
+/// Matrix exponential with its condition number
+///
+/// Returns `exp(A)` and the relative condition number
+///
+/// ```text
+/// κ(A) = |L(A)|_1 |A|_1 / |exp(A)|_1
+/// ```
+///
+/// where `L(A)` is the Fréchet derivative of the exponential at `A`, which
+/// is regarded as an `n^2 x n^2` matrix acting on `vec(E)`. Its 1-norm is
+/// estimated by the block 1-norm estimator of Higham and Tisseur, using
+/// `L(A)^H = L(A^H)`, and each product costs an [expm_frechet].
+pub fn expm_cond<A>(a: &ArrayView2<A>) -> Result<(Array2<A>, A::Real)>
+where
+    A: Scalar + Lapack,
+{
+    a.ensure_square()?;
+    let n = a.nrows();
+    let exp_a = expm(a)?;
+    let ah = a.t().mapv(|x| x.conj());
+
+    // Apply the Fréchet derivative to each column as `vec(E)`, and keep the
+    // first error since the estimator cannot be interrupted
+    let error = RefCell::new(None);
+    let apply = |a: &ArrayView2<A>, x: ArrayView2<A>| -> Array2<A> {
+        let mut y = Array2::zeros(x.dim());
+        if error.borrow().is_some() {
+            return y;
+        }
+        for (x, mut y) in x.axis_iter(Axis(1)).zip(y.axis_iter_mut(Axis(1))) {
+            let e = Array::from_iter(x.iter().cloned())
+                .into_shape((n, n))
+                .unwrap();
+            match expm_frechet(a, &e.view()) {
+                Ok((_, l)) => y.assign(&Array1::from_iter(l.iter().cloned())),
+                Err(err) => {
+                    *error.borrow_mut() = Some(err);
+                    break;
+                }
+            }
+        }
+        y
+    };
+    let l_norm = normest1(n * n, 2, |x| apply(a, x), |x| apply(&ah.view(), x));
+    if let Some(e) = error.into_inner() {
+        return Err(e);
+    }
+
+    let exp_norm = exp_a.opnorm_one()?;
+    let cond = if exp_norm > A::Real::zero() {
+        l_norm * a.opnorm_one()? / exp_norm
+    } else {
+        A::Real::zero()
+    };
+    Ok((exp_a, cond))
+}
+
+/// Matrix exponential with its Fréchet derivative in the direction `E`
+///
+/// Returns `exp(A)` and the directional derivative
+/// `L(A, E) = d/dt exp(A + tE)|_{t=0}`, which are computed at once by the
+/// block-triangular relation
+///
+/// ```text
+/// exp([[A, E], [0, A]]) = [[exp(A), L(A, E)], [0, exp(A)]]
+/// ```
+///
+/// using [expm] on the `2n x 2n` matrix.
+///
+/// # Panics
+///
+/// Panics if the shapes of `a` and `e` are different.
+pub fn expm_frechet<A>(a: &ArrayView2<A>, e: &ArrayView2<A>) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+{
+    assert_eq!(
+        a.shape(),
+        e.shape(),
+        "The shapes of the matrices must be identical.",
+    );
+    a.ensure_square()?;
+    let n = a.nrows();
+    let mut block = Array2::zeros((2 * n, 2 * n));
+    block.slice_mut(s![..n, ..n]).assign(a);
+    block.slice_mut(s![..n, n..]).assign(e);
+    block.slice_mut(s![n.., n..]).assign(a);
+    let exp_block = expm(&block)?;
+    Ok((
+        exp_block.slice(s![..n, ..n]).to_owned(),
+        exp_block.slice(s![..n, n..]).to_owned(),
+    ))
+}
+
+/// Action of the matrix exponential `exp(tA) B` without forming `exp(tA)`
+///
+/// - `a` applies an `n x n` matrix `A` to a `(n, k)` matrix
+/// - `ah` applies `A^H` to a `(n, k)` matrix, which is used only for
+///   estimating `|A^p|_1` by the block 1-norm estimator
+///
+/// This uses the truncated Taylor series with scaling by Al-Mohy and Higham,
+/// where the degree `m` and the number of steps `s` are chosen from the
+/// estimates of `|A^p|_1^{1/p}` to attain double precision. `exp(tA) B` is
+/// computed by `s` steps of `B <- T_m(tA/s) B` with `T_m` the truncated
+/// Taylor series, which terminates early when the terms become negligible.
+/// Only products with `A` are required, and hence this is suitable for a
+/// large sparse or structured `A`.
+///
+/// Returns `LinalgError::NonFiniteNorm` if `t` is not finite or an estimate
+/// of `|(tA)^p|_1` overflows, since the number of steps cannot be chosen then.
+pub fn expm_multiply<A, S, F, G>(
+    a: F,
+    ah: G,
+    b: &ArrayBase<S, Ix2>,
+    t: A::Real,
+) -> Result<Array2<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView2<A>) -> Array2<A>,
+    G: Fn(ArrayView2<A>) -> Array2<A>,
+{
+    let (n, n0) = b.dim();
+    let mut f = b.to_owned();
+    if n == 0 || n0 == 0 {
+        return Ok(f);
+    }
+    if !Float::is_finite(t) {
+        return Err(LinalgError::NonFiniteNorm);
+    }
+    let t_abs = Float::abs(t).to_f64().unwrap();
+    // Estimate of |(tA)^p|_1
+    let norm_power = |p: usize| -> f64 {
+        let norm = normest1::<A, _, _>(n, 2, |x| power(&a, p, x), |x| power(&ah, p, x));
+        norm.to_f64().unwrap() * t_abs.powi(p as i32)
+    };
+    let (m, s) = taylor_parameters(norm_power(1), n0, norm_power)?;
+
+    let tol = A::Real::epsilon() / A::real(2.0);
+    let mut b = f.clone();
+    for _ in 0..s {
+        let mut c1 = norm_inf(&b);
+        for j in 1..=m {
+            let coef = t / A::real((s * j) as f64);
+            b = a(b.view()).mapv(|x| x.mul_real(coef));
+            let c2 = norm_inf(&b);
+            f += &b;
+            if c1 + c2 <= tol * norm_inf(&f) {
+                break;
+            }
+            c1 = c2;
+        }
+        b = f.clone();
+    }
+    Ok(f)
+}
+
+/// Degree `m` of the Taylor series and the number of steps `s` from the
+/// 1-norm of `tA` and the estimates of `|(tA)^p|_1`, which must be finite
+fn taylor_parameters(
+    norm: f64,
+    n0: usize,
+    norm_power: impl Fn(usize) -> f64,
+) -> Result<(usize, usize)> {
+    if !norm.is_finite() {
+        return Err(LinalgError::NonFiniteNorm);
+    }
+    if norm == 0.0 {
+        return Ok((0, 1));
+    }
+    let (m_max, theta_max) = THETA_TAYLOR[THETA_TAYLOR.len() - 1];
+    // Use only |tA|_1 if it is small enough that estimating the norms of
+    // the powers does not pay
+    if norm <= (4 * P_MAX * (P_MAX + 3)) as f64 * theta_max / (n0 * m_max) as f64 {
+        return Ok(THETA_TAYLOR
+            .iter()
+            .map(|&(m, theta)| (m, (norm / theta).ceil() as usize))
+            .min_by_key(|&(m, s)| m.saturating_mul(s))
+            .unwrap());
+    }
+    let d = (2..=P_MAX + 1)
+        .map(|p| {
+            let d = norm_power(p).powf(1.0 / p as f64);
+            if d.is_finite() {
+                Ok(d)
+            } else {
+                Err(LinalgError::NonFiniteNorm)
+            }
+        })
+        .collect::<Result<Vec<f64>>>()?;
+    let (m, s) = (2..=P_MAX)
+        .flat_map(|p| {
+            let alpha = d[p - 2].max(d[p - 1]);
+            THETA_TAYLOR
+                .iter()
+                .filter(move |&&(m, _)| m + 1 >= p * (p - 1))
+                .map(move |&(m, theta)| (m, (alpha / theta).ceil() as usize))
+        })
+        .min_by_key(|&(m, s)| m.saturating_mul(s))
+        .unwrap();
+    Ok((m, s.max(1)))
+}
+
+/// Apply `A^p` by `p` products with `A`
+fn power<A, F>(a: &F, p: usize, x: ArrayView2<A>) -> Array2<A>
+where
+    A: Scalar,
+    F: Fn(ArrayView2<A>) -> Array2<A>,
+{
+    (1..p).fold(a(x), |y, _| a(y.view()))
+}
+
+/// Maximum absolute row sum
+fn norm_inf<A: Scalar>(b: &Array2<A>) -> A::Real {
+    b.outer_iter()
+        .map(|row| row.iter().map(|x| x.abs()).sum::<A::Real>())
+        .fold(A::Real::zero(), Float::max)
+}
+
+fn coef<A: Scalar>(b: f64) -> A {
+    A::from_real(A::real(b))
+}
+
+/// Solve `(V - U) R = V + U` for the Padé approximant `R`
+fn solve_pade<A: Scalar + Lapack>(u: &Array2<A>, v: &Array2<A>) -> Result<Array2<A>> {
+    let f = (v - u).factorize_into()?;
+    let mut r = Array2::zeros(u.dim().f());
+    r.assign(&(v + u));
+    for mut col in r.axis_iter_mut(Axis(1)) {
+        f.solve_inplace(&mut col)?;
+    }
+    Ok(r)
+}
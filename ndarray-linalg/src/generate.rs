@@ -1,13 +1,18 @@
 //! Generator functions for matrices
 
 use ndarray::*;
+use num_traits::Zero;
 use rand::prelude::*;
 
 use super::convert::*;
 use super::error::*;
+use super::layout::*;
 use super::qr::*;
 use super::types::*;
 
+pub use super::convert::{hstack, vstack};
+pub use lax::Symmetry;
+
 /// Hermite conjugate matrix
 pub fn conjugate<A, Si, So>(a: &ArrayBase<Si, Ix2>) -> ArrayBase<So, Ix2>
 where
@@ -24,8 +29,8 @@ where
 
 /// Generate random array with given shape
 ///
-/// - This function uses [rand::thread_rng].
-///   See [random_using] for using another RNG
+/// - This function uses [rand::thread_rng], and thus the result differs in each run.
+///   See [random_seeded] or [random_using] for reproducible arrays.
 pub fn random<A, S, Sh, D>(sh: Sh) -> ArrayBase<S, D>
 where
     A: Scalar,
@@ -37,9 +42,27 @@ where
     random_using(sh, &mut rng)
 }
 
+/// Generate random array with given seed
+///
+/// - This function uses [rand::rngs::StdRng] seeded by `seed`, and the result
+///   is identical across runs with the same seed and the same version of `rand`.
+///   Since the algorithm of [rand::rngs::StdRng] may change between versions of `rand`,
+///   use [random_using] with a portable RNG, e.g. `rand_pcg`, to keep the arrays in the long term.
+pub fn random_seeded<A, S, Sh, D>(sh: Sh, seed: u64) -> ArrayBase<S, D>
+where
+    A: Scalar,
+    S: DataOwned<Elem = A>,
+    D: Dimension,
+    Sh: ShapeBuilder<Dim = D>,
+{
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    random_using(sh, &mut rng)
+}
+
 /// Generate random array with given RNG
 ///
 /// - See [random] for using default RNG
+/// - See [random_seeded] for using a seeded RNG
 pub fn random_using<A, S, Sh, D, R>(sh: Sh, rng: &mut R) -> ArrayBase<S, D>
 where
     A: Scalar,
@@ -51,10 +74,8 @@ where
     ArrayBase::from_shape_fn(sh, |_| A::rand(rng))
 }
 
-/// Generate random unitary matrix using QR decomposition
+/// Generate random unitary matrix from the Haar measure
 ///
-/// - Be sure that this it **NOT** a uniform distribution.
-///   Use it only for test purpose.
 /// - This function uses [rand::thread_rng].
 ///   See [random_unitary_using] for using another RNG.
 pub fn random_unitary<A>(n: usize) -> Array2<A>
@@ -65,21 +86,82 @@ where
     random_unitary_using(n, &mut rng)
 }
 
-/// Generate random unitary matrix using QR decomposition with given RNG
+/// Generate random unitary matrix from the Haar measure with given RNG
+///
+/// The matrix is computed as `Q` of the QR decomposition of a matrix with
+/// independent standard (complex) normal entries. Since QR decomposition is
+/// unique only up to the phases of the diagonal of `R`, the columns of `Q` are
+/// rotated to make the diagonal of `R` positive, which makes the distribution
+/// uniform (Mezzadri, 2007).
 ///
-/// - Be sure that this it **NOT** a uniform distribution.
-///   Use it only for test purpose.
 /// - See [random_unitary] for using default RNG.
 pub fn random_unitary_using<A, R>(n: usize, rng: &mut R) -> Array2<A>
 where
     A: Scalar + Lapack,
     R: Rng,
 {
-    let a: Array2<A> = random_using((n, n), rng);
-    let (q, _r) = a.qr_into().unwrap();
+    let a: Array2<A> = random_normal_using((n, n), rng);
+    let (mut q, r) = a.qr_into().unwrap();
+    for (mut col, d) in q.axis_iter_mut(Axis(1)).zip(r.diag()) {
+        if !d.is_zero() {
+            let phase = d.div_real(d.abs());
+            col.mapv_inplace(|x| x * phase);
+        }
+    }
     q
 }
 
+/// Generate random orthogonal matrix from the Haar measure
+///
+/// - This function uses [rand::thread_rng].
+///   See [random_orthogonal_using] for using another RNG.
+pub fn random_orthogonal<A>(n: usize) -> Array2<A>
+where
+    A: Scalar<Real = A> + Lapack,
+{
+    let mut rng = thread_rng();
+    random_orthogonal_using(n, &mut rng)
+}
+
+/// Generate random orthogonal matrix from the Haar measure with given RNG
+///
+/// - This is [random_unitary_using] restricted to real scalars.
+/// - See [random_orthogonal] for using default RNG.
+pub fn random_orthogonal_using<A, R>(n: usize, rng: &mut R) -> Array2<A>
+where
+    A: Scalar<Real = A> + Lapack,
+    R: Rng,
+{
+    random_unitary_using(n, rng)
+}
+
+/// Random array whose entries are independent standard normal,
+/// with independent real and imaginary parts for complex scalars
+fn random_normal_using<A, Sh, R>(sh: Sh, rng: &mut R) -> Array2<A>
+where
+    A: Scalar,
+    Sh: ShapeBuilder<Dim = Ix2>,
+    R: Rng,
+{
+    // `sqrt(-1)` is the imaginary unit for complex scalars, and its imaginary
+    // part is zero for real scalars
+    let i = A::from_real(A::real(-1.0)).sqrt();
+    let is_complex = !i.im().is_zero();
+    Array2::from_shape_simple_fn(sh, || {
+        // Box-Muller transform
+        let u1: f64 = rng.gen();
+        let u2: f64 = rng.gen();
+        let r = (-2.0 * (1.0 - u1).ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * u2;
+        let re = A::from_real(A::real(r * theta.cos()));
+        if is_complex {
+            re + A::from_real(A::real(r * theta.sin())) * i
+        } else {
+            re
+        }
+    })
+}
+
 /// Generate random regular matrix
 ///
 /// - Be sure that this it **NOT** a uniform distribution.
@@ -144,6 +226,46 @@ where
     a
 }
 
+/// Random Hermitian matrix with prescribed eigenvalues
+///
+/// - The matrix is `U diag(eigs) U^H` with a random unitary `U` by [random_unitary].
+///   The eigenvalues may be negative, i.e. the matrix can be indefinite.
+/// - This function uses [rand::thread_rng].
+///   See [random_hermitian_with_eigs_using] for using another RNG.
+pub fn random_hermitian_with_eigs<A>(eigs: &[A::Real]) -> Array2<A>
+where
+    A: Scalar + Lapack,
+{
+    let mut rng = thread_rng();
+    random_hermitian_with_eigs_using(eigs, &mut rng)
+}
+
+/// Random Hermitian matrix with prescribed eigenvalues with given RNG
+///
+/// - The result is exactly Hermitian, i.e. `a[(i, j)] == a[(j, i)].conj()`.
+/// - See [random_hermitian_with_eigs] for using default RNG.
+pub fn random_hermitian_with_eigs_using<A, R>(eigs: &[A::Real], rng: &mut R) -> Array2<A>
+where
+    A: Scalar + Lapack,
+    R: Rng,
+{
+    let n = eigs.len();
+    let u: Array2<A> = random_unitary_using(n, rng);
+    let mut ud = u.clone();
+    for (mut col, &e) in ud.axis_iter_mut(Axis(1)).zip(eigs) {
+        col.mapv_inplace(|x| x.mul_real(e));
+    }
+    let uh: Array2<A> = conjugate(&u);
+    let mut a = ud.dot(&uh);
+    for i in 0..n {
+        a[(i, i)] = A::from_real(a[(i, i)].re());
+        for j in (i + 1)..n {
+            a[(j, i)] = a[(i, j)].conj();
+        }
+    }
+    a
+}
+
 /// Random Hermite Positive-definite matrix
 ///
 /// - Eigenvalue of matrix must be larger than 1 (thus non-singular)
@@ -175,35 +297,66 @@ where
     ArrayBase::eye(n) + &ah.dot(&a)
 }
 
-/// construct matrix from diag
-pub fn from_diag<A>(d: &[A]) -> Array2<A>
+/// Generate random matrix with prescribed singular values using `*latms`
+///
+/// - For [Symmetry::General], `svals` are the singular values
+///   and must have `min(m, n)` elements.
+/// - For [Symmetry::Hermitian], the matrix must be square
+///   and `svals` are its eigenvalues, which may be negative.
+/// - `*latms` is a part of the matrix generator library of LAPACK,
+///   and may not be shipped by every LAPACK backend.
+/// - This function uses [rand::thread_rng].
+///   See [random_with_svals_using] for using another RNG.
+pub fn random_with_svals<A>(
+    shape: (usize, usize),
+    svals: &[A::Real],
+    sym: Symmetry,
+) -> Result<Array2<A>>
 where
-    A: Scalar,
+    A: Scalar + Lapack,
 {
-    let n = d.len();
-    let mut e = Array::zeros((n, n));
-    for i in 0..n {
-        e[(i, i)] = d[i];
-    }
-    e
+    let mut rng = thread_rng();
+    random_with_svals_using(shape, svals, sym, &mut rng)
 }
 
-/// stack vectors into matrix horizontally
-pub fn hstack<A, S>(xs: &[ArrayBase<S, Ix1>]) -> Result<Array<A, Ix2>>
+/// Generate random matrix with prescribed singular values using `*latms` with given RNG
+///
+/// - The RNG only determines the seed of the LAPACK internal generator.
+/// - See [random_with_svals] for using default RNG.
+pub fn random_with_svals_using<A, R>(
+    shape: (usize, usize),
+    svals: &[A::Real],
+    sym: Symmetry,
+    rng: &mut R,
+) -> Result<Array2<A>>
 where
-    A: Scalar,
-    S: Data<Elem = A>,
+    A: Scalar + Lapack,
+    R: Rng,
 {
-    let views: Vec<_> = xs.iter().map(|x| x.view()).collect();
-    stack(Axis(1), &views).map_err(Into::into)
+    let (m, n) = shape;
+    let mut iseed = [
+        rng.gen_range(0..4096),
+        rng.gen_range(0..4096),
+        rng.gen_range(0..4096),
+        2 * rng.gen_range(0..2048) + 1,
+    ];
+    let l = MatrixLayout::F {
+        col: n as i32,
+        lda: m as i32,
+    };
+    let a = A::latms(l, sym, svals, &mut iseed)?;
+    Ok(Array2::from_shape_vec((m, n).f(), a)?)
 }
 
-/// stack vectors into matrix vertically
-pub fn vstack<A, S>(xs: &[ArrayBase<S, Ix1>]) -> Result<Array<A, Ix2>>
+/// construct matrix from diag
+pub fn from_diag<A>(d: &[A]) -> Array2<A>
 where
     A: Scalar,
-    S: Data<Elem = A>,
 {
-    let views: Vec<_> = xs.iter().map(|x| x.view()).collect();
-    stack(Axis(0), &views).map_err(Into::into)
+    let n = d.len();
+    let mut e = Array::zeros((n, n));
+    for i in 0..n {
+        e[(i, i)] = d[i];
+    }
+    e
 }
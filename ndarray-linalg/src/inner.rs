@@ -1,5 +1,9 @@
+use crate::error::*;
+use crate::layout::*;
+use crate::solve::Transpose;
 use crate::types::*;
 use ndarray::*;
+use num_traits::Zero;
 
 /// Inner Product
 ///
@@ -12,6 +16,40 @@ pub trait InnerProduct {
     fn inner<S>(&self, rhs: &ArrayBase<S, Ix1>) -> Self::Elem
     where
         S: Data<Elem = Self::Elem>;
+
+    /// Weighted inner product `self^H W rhs` with the diagonal weight `W = diag(weights)`
+    fn weighted_inner<S, Sw>(
+        &self,
+        rhs: &ArrayBase<S, Ix1>,
+        weights: &ArrayBase<Sw, Ix1>,
+    ) -> Self::Elem
+    where
+        S: Data<Elem = Self::Elem>,
+        Sw: Data<Elem = <Self::Elem as Scalar>::Real>,
+    {
+        let wy = Zip::from(rhs)
+            .and(weights)
+            .map_collect(|&r, &w| r.mul_real(w));
+        self.inner(&wy)
+    }
+
+    /// Inner product `self^H M rhs` with the metric tensor `M`
+    ///
+    /// `M` is expected to be Hermitian (positive definite for an inner
+    /// product), but this is not checked.
+    fn metric_inner<S, Sm>(&self, rhs: &ArrayBase<S, Ix1>, m: &ArrayBase<Sm, Ix2>) -> Self::Elem
+    where
+        S: Data<Elem = Self::Elem>,
+        Sm: Data<Elem = Self::Elem>,
+    {
+        assert_eq!(m.ncols(), rhs.len());
+        let my = Array1::from_iter(m.outer_iter().map(|row| {
+            Zip::from(&row)
+                .and(rhs)
+                .fold(Self::Elem::zero(), |acc, &mij, &yj| acc + mij * yj)
+        }));
+        self.inner(&my)
+    }
 }
 
 impl<A, S> InnerProduct for ArrayBase<S, Ix1>
@@ -29,4 +67,89 @@ where
             })
             .into_inner()
     }
+
+    fn weighted_inner<St, Sw>(&self, rhs: &ArrayBase<St, Ix1>, weights: &ArrayBase<Sw, Ix1>) -> A
+    where
+        St: Data<Elem = A>,
+        Sw: Data<Elem = A::Real>,
+    {
+        assert_eq!(self.len(), rhs.len());
+        assert_eq!(self.len(), weights.len());
+        Zip::from(self)
+            .and(rhs)
+            .and(weights)
+            .fold_while(A::zero(), |acc, s, r, w| {
+                FoldWhile::Continue(acc + (s.conj() * *r).mul_real(*w))
+            })
+            .into_inner()
+    }
+}
+
+/// Gram matrix of the columns or rows of a matrix
+///
+/// The product is computed by `syrk`/`herk`, which takes half the flops of
+/// a general matrix product, and the result is exactly symmetric/Hermitian.
+pub trait Gram {
+    type Elem: Scalar;
+
+    /// Gram matrix of the columns `A^H A`
+    fn gram(&self) -> Array2<Self::Elem>;
+
+    /// Gram matrix of the rows `A A^H`
+    fn gram_t(&self) -> Array2<Self::Elem>;
+}
+
+impl<A, S> Gram for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn gram(&self) -> Array2<A> {
+        gram(self, Transpose::Hermite)
+    }
+
+    fn gram_t(&self) -> Array2<A> {
+        gram(self, Transpose::No)
+    }
+}
+
+fn gram<A, S>(a: &ArrayBase<S, Ix2>, t: Transpose) -> Array2<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let a = if a.layout().is_ok() {
+        CowArray::from(a.view())
+    } else {
+        a.as_standard_layout()
+    };
+    let l = a.layout().unwrap();
+    let n = match t {
+        Transpose::No => a.nrows(),
+        _ => a.ncols(),
+    };
+    // `Transpose::Transpose` is never passed here, so this cannot fail
+    let c = A::gram(l, t, a.as_allocated().unwrap()).unwrap();
+    Array2::from_shape_vec((n, n).f(), c).unwrap()
+}
+
+/// Frobenius inner product `tr(A^H B) = sum_ij conj(a_ij) b_ij` of matrices
+///
+/// Errors
+/// -------
+/// - `IncompatibleShape` error if the shapes of `a` and `b` differ
+pub fn frobenius_inner<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix2>) -> Result<A>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    if a.dim() != b.dim() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok(Zip::from(a)
+        .and(b)
+        .fold(A::zero(), |acc, x, &y| acc + x.conj() * y))
 }
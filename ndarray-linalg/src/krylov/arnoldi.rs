@@ -1,8 +1,8 @@
 //! Arnoldi iteration
 
 use super::*;
-use crate::{norm::Norm, operator::LinearOperator};
-use num_traits::One;
+use crate::{eig::*, error::*, norm::Norm, operator::LinearOperator};
+use num_traits::{One, ToPrimitive};
 use std::iter::*;
 
 /// Execute Arnoldi iteration as Rust iterator
@@ -56,6 +56,15 @@ where
     /// Iterate until convergent
     pub fn complete(mut self) -> (Q<A>, H<A>) {
         for _ in &mut self {} // execute iteration until convergent
+        let ArnoldiDecomposition { q, h } = self.into_decomposition();
+        (q, h)
+    }
+
+    /// Decomposition by the iterations executed so far
+    ///
+    /// This does not execute further iterations, i.e. the iterator can be
+    /// stopped before convergence, e.g. by `(&mut arnoldi).take(k)`.
+    pub fn into_decomposition(self) -> ArnoldiDecomposition<A> {
         let q = self.ortho.get_q();
         let n = self.h.len();
         let mut h = Array2::zeros((n, n).f());
@@ -65,7 +74,7 @@ where
                 h[(j, i)] = hc[j];
             }
         }
-        (q, h)
+        ArnoldiDecomposition { q, h }
     }
 }
 
@@ -96,6 +105,66 @@ where
     }
 }
 
+/// Result of Arnoldi iteration `A Q_m \approx Q_m H_m`
+///
+/// The Ritz pairs, i.e. the eigenpairs of the `m x m` Hessenberg matrix `H_m`
+/// lifted by the Krylov basis `Q_m`, approximate eigenpairs of `A`. The
+/// eigenvalues of the largest magnitude typically converge first.
+///
+/// ```rust
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = Array2::from_diag(&array![1.0, 2.0, 3.0]);
+/// let mut arnoldi = Arnoldi::new(a, array![1.0, 1.0, 1.0], MGS::new(3, 1e-9));
+/// for _ in &mut arnoldi {}
+/// let decomp = arnoldi.into_decomposition();
+/// let ritz = decomp.ritz_values().unwrap();
+/// assert!((ritz[0] - c64::new(3.0, 0.0)).norm() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArnoldiDecomposition<A: Scalar> {
+    /// Krylov basis. This may have one more column than `h` for the next vector.
+    pub q: Q<A>,
+    /// Upper Hessenberg matrix
+    pub h: H<A>,
+}
+
+impl<A: Scalar + Lapack> ArnoldiDecomposition<A> {
+    /// Dimension `m` of the Krylov subspace spanned by the Ritz vectors
+    pub fn dim(&self) -> usize {
+        self.h.nrows()
+    }
+
+    /// Ritz values, i.e. the eigenvalues of `H`, in descending order of magnitude
+    pub fn ritz_values(&self) -> Result<Array1<A::Complex>> {
+        let mut e = self.h.eigvals()?.to_vec();
+        e.sort_by(|a, b| {
+            let (x, y) = (a.abs().to_f64().unwrap(), b.abs().to_f64().unwrap());
+            y.total_cmp(&x)
+        });
+        Ok(Array1::from(e))
+    }
+
+    /// Ritz values and the corresponding Ritz vectors `Q_m y` as columns,
+    /// where `y` are the eigenvectors of `H`
+    ///
+    /// They are sorted in descending order of the magnitude of the Ritz values.
+    pub fn ritz_vectors(&self) -> Result<(Array1<A::Complex>, Array2<A::Complex>)> {
+        let m = self.dim();
+        let (e, y) = self.h.eig()?;
+        let mut order: Vec<usize> = (0..m).collect();
+        order.sort_by(|&i, &j| {
+            let (x, y) = (e[i].abs().to_f64().unwrap(), e[j].abs().to_f64().unwrap());
+            y.total_cmp(&x)
+        });
+        let e = order.iter().map(|&i| e[i]).collect();
+        let y = y.select(Axis(1), &order);
+        let q = self.q.slice(s![.., ..m]).mapv(|x| x.as_c());
+        Ok((e, q.dot(&y)))
+    }
+}
+
 /// Utility to execute Arnoldi iteration with Householder reflection
 pub fn arnoldi_householder<A, S>(
     a: impl LinearOperator<Elem = A>,
@@ -0,0 +1,124 @@
+//! Biconjugate gradient stabilized method
+//!
+//! - [Biconjugate gradient stabilized method - Wikipedia](https://en.wikipedia.org/wiki/Biconjugate_gradient_stabilized_method)
+//! - H. A. van der Vorst, "Bi-CGSTAB: A fast and smoothly converging variant of
+//!   Bi-CG for the solution of nonsymmetric linear systems",
+//!   SIAM J. Sci. Stat. Comput. 13 (1992)
+
+use super::*;
+use crate::{error::*, inner::*, norm::Norm};
+use num_traits::{Float, Zero};
+
+/// Solve `A x = b` by BiCGStab
+///
+/// - `a` applies the operator `A` to a vector, which need not be symmetric
+/// - Iteration starts from `x = 0`, and stops when the relative residual
+///   `|b - A x| / |b|` becomes smaller than `tol`, or after `max_iter`
+///   iterations. Each iteration applies `A` twice.
+///
+/// Unlike GMRES, the memory usage does not grow with the number of
+/// iterations, while the residual norm may not decrease monotonically.
+///
+/// Returns the approximate solution and the history of relative residual
+/// norms, starting with that of `x = 0`.
+///
+/// Errors
+/// -------
+/// - [LinalgError::Breakdown] if a denominator of the recurrence vanishes
+///   relative to the machine precision before convergence
+pub fn bicgstab<A, S, F>(
+    a: F,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+) -> Result<(Array1<A>, Vec<A::Real>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    bicgstab_with_criterion(a, b, tol, max_iter, StoppingCriterion::RelativeResidual)
+}
+
+/// [bicgstab()] with a custom [StoppingCriterion]
+///
+/// Iteration stops when the measure of `criterion` becomes smaller than
+/// `tol`, and the history of this measure is returned.
+///
+/// Errors
+/// -------
+/// - [LinalgError::Breakdown] as [bicgstab()]
+pub fn bicgstab_with_criterion<A, S, F>(
+    a: F,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+    criterion: StoppingCriterion<A::Real>,
+) -> Result<(Array1<A>, Vec<A::Real>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    let eps = A::Real::epsilon();
+    let b_norm = b.norm_l2();
+
+    let mut x = Array1::<A>::zeros(b.len());
+    let mut r = b.to_owned();
+    let mut history = vec![criterion.measure(b_norm, b_norm, A::Real::zero())];
+    if history[0] <= tol {
+        return Ok((x, history));
+    }
+
+    // shadow residual
+    let r0 = r.clone();
+    let r0_norm = b_norm;
+    let mut p = Array1::<A>::zeros(b.len());
+    let mut v = Array1::<A>::zeros(b.len());
+    let (mut rho, mut alpha, mut omega) = (A::one(), A::one(), A::one());
+
+    for iteration in 1..=max_iter {
+        let rho_new = r0.inner(&r);
+        if rho_new.abs() <= eps * r0_norm * r.norm_l2() {
+            return Err(LinalgError::Breakdown { iteration });
+        }
+        let beta = (rho_new / rho) * (alpha / omega);
+        azip!((p in &mut p, &r in &r, &v in &v) *p = r + beta * (*p - omega * v));
+
+        v = a(p.view());
+        let r0v = r0.inner(&v);
+        if r0v.abs() <= eps * r0_norm * v.norm_l2() {
+            return Err(LinalgError::Breakdown { iteration });
+        }
+        alpha = rho_new / r0v;
+
+        // r <- s = r - alpha v
+        r.scaled_add(-alpha, &v);
+        x.scaled_add(alpha, &p);
+        let res = criterion.measure(r.norm_l2(), b_norm, x.norm_l2());
+        if res <= tol {
+            history.push(res);
+            break;
+        }
+
+        let t = a(r.view());
+        let tt = t.norm_l2();
+        if tt.is_zero() {
+            return Err(LinalgError::Breakdown { iteration });
+        }
+        omega = t.inner(&r).div_real(tt * tt);
+        if omega.abs().is_zero() {
+            return Err(LinalgError::Breakdown { iteration });
+        }
+        x.scaled_add(omega, &r);
+        r.scaled_add(-omega, &t);
+
+        let res = criterion.measure(r.norm_l2(), b_norm, x.norm_l2());
+        history.push(res);
+        if res <= tol {
+            break;
+        }
+        rho = rho_new;
+    }
+    Ok((x, history))
+}
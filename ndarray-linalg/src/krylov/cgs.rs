@@ -0,0 +1,57 @@
+//! Classical Gram-Schmidt with reorthogonalization
+
+use super::*;
+use crate::{error::*, inner::*, norm::Norm};
+use num_traits::Zero;
+
+/// Batch QR decomposition of the columns of `a` using classical Gram-Schmidt
+///
+/// If `reorthogonalize` is true, each column is orthogonalized twice against
+/// the current basis (CGS2, "twice is enough"), which keeps `Q` orthonormal
+/// to working precision even for ill-conditioned `a`. Otherwise a single
+/// classical Gram-Schmidt pass is used, whose loss of orthogonality grows
+/// with the square of the condition number.
+///
+/// - `Q` is a `(n, m)` matrix with orthonormal columns
+/// - `R` is a `(m, m)` upper triangular matrix
+///
+/// If a column is exactly linearly dependent on the preceding ones, the
+/// corresponding column of `Q` and diagonal element of `R` are zero.
+///
+/// Errors
+/// -------
+/// - if `a` has more columns than rows
+pub fn gram_schmidt<A, S>(a: &ArrayBase<S, Ix2>, reorthogonalize: bool) -> Result<(Q<A>, R<A>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (n, m) = a.dim();
+    if m > n {
+        return Err(LinalgError::NotStandardShape {
+            obj: "Orthonormal basis",
+            rows: n as i32,
+            cols: m as i32,
+        });
+    }
+    let mut q: Q<A> = Array2::zeros((n, m).f());
+    let mut r: R<A> = Array2::zeros((m, m).f());
+    let passes = if reorthogonalize { 2 } else { 1 };
+    for j in 0..m {
+        let mut v = a.column(j).to_owned();
+        let mut coef = Array1::zeros(j);
+        for _ in 0..passes {
+            let basis = q.slice(s![.., ..j]);
+            let c: Array1<A> = basis.axis_iter(Axis(1)).map(|q| q.inner(&v)).collect();
+            v -= &basis.dot(&c);
+            coef += &c;
+        }
+        let nrm = v.norm_l2();
+        r.slice_mut(s![..j, j]).assign(&coef);
+        r[(j, j)] = A::from_real(nrm);
+        if nrm > A::Real::zero() {
+            azip!((q in q.column_mut(j), &v in &v) *q = v.div_real(nrm));
+        }
+    }
+    Ok((q, r))
+}
@@ -0,0 +1,165 @@
+//! Flexible generalized minimal residual method
+//!
+//! - [Generalized minimal residual method - Wikipedia](https://en.wikipedia.org/wiki/Generalized_minimal_residual_method)
+//! - Y. Saad, "A flexible inner-outer preconditioned GMRES algorithm",
+//!   SIAM J. Sci. Comput. 14 (1993)
+
+use super::*;
+use crate::{error::*, inner::*, norm::Norm, triangular::*, UPLO};
+use num_traits::{One, Zero};
+
+/// Solve `A x = b` by flexible GMRES with a right preconditioner
+///
+/// - `a` applies the operator `A` to a vector
+/// - `precond` applies the preconditioner `M_j^{-1}` to the `j`-th Krylov
+///   basis vector. It is called exactly once per iteration, and may differ
+///   between calls, e.g. an inner iterative solver with varying tolerance.
+/// - `x0` is the initial guess
+/// - Iteration stops when the relative residual `|b - A x| / |b|` becomes
+///   smaller than `tol`, or after `maxiter` iterations (the dimension of the
+///   Krylov subspace). No restart is performed.
+///
+/// Because the preconditioner may change, the preconditioned vectors
+/// `z_j = M_j^{-1} v_j` are stored alongside the orthonormal Krylov basis
+/// `v_j`, and the solution is updated as `x = x0 + Z y`.
+///
+/// Returns the approximate solution and the history of relative residual
+/// norms, starting with that of `x0`.
+pub fn fgmres<A, S, F, P>(
+    a: F,
+    precond: P,
+    b: &ArrayBase<S, Ix1>,
+    x0: Array1<A>,
+    tol: A::Real,
+    maxiter: usize,
+) -> Result<(Array1<A>, Vec<A::Real>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+    P: FnMut(ArrayView1<A>) -> Array1<A>,
+{
+    fgmres_with_criterion(
+        a,
+        precond,
+        b,
+        x0,
+        tol,
+        maxiter,
+        StoppingCriterion::RelativeResidual,
+    )
+}
+
+/// [fgmres()] with a custom [StoppingCriterion]
+///
+/// Iteration stops when the measure of `criterion` becomes smaller than
+/// `tol`, and the history of this measure is returned. For
+/// [StoppingCriterion::BackwardError], the current iterate `x` is formed in
+/// every iteration, which costs an additional triangular solve and
+/// `O(n k)` operations for the `k`-th iteration.
+pub fn fgmres_with_criterion<A, S, F, P>(
+    a: F,
+    mut precond: P,
+    b: &ArrayBase<S, Ix1>,
+    x0: Array1<A>,
+    tol: A::Real,
+    maxiter: usize,
+    criterion: StoppingCriterion<A::Real>,
+) -> Result<(Array1<A>, Vec<A::Real>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+    P: FnMut(ArrayView1<A>) -> Array1<A>,
+{
+    assert_eq!(b.len(), x0.len());
+    let b_norm = b.norm_l2();
+
+    let r = b - &a(x0.view());
+    let beta = r.norm_l2();
+    let mut history = vec![criterion.measure(beta, b_norm, x0.norm_l2())];
+    if history[0] <= tol {
+        return Ok((x0, history));
+    }
+
+    // Krylov basis V and preconditioned basis Z
+    let mut v = vec![r.mapv(|r| r.div_real(beta))];
+    let mut z: Vec<Array1<A>> = Vec::new();
+    // Hessenberg matrix reduced to upper triangular form by Givens rotations
+    let mut h: H<A> = Array2::zeros((maxiter + 1, maxiter).f());
+    let mut rotations: Vec<(A::Real, A)> = Vec::new();
+    let mut g: Array1<A> = Array1::zeros(maxiter + 1);
+    g[0] = A::from_real(beta);
+
+    for j in 0..maxiter {
+        z.push(precond(v[j].view()));
+        let mut w = a(z[j].view());
+        for (i, vi) in v.iter().enumerate() {
+            let c = vi.inner(&w);
+            azip!((w in &mut w, &vi in vi) *w -= c * vi);
+            h[(i, j)] = c;
+        }
+        let w_norm = w.norm_l2();
+        h[(j + 1, j)] = A::from_real(w_norm);
+
+        for (i, &(c, s)) in rotations.iter().enumerate() {
+            let (hi, hi1) = (h[(i, j)], h[(i + 1, j)]);
+            h[(i, j)] = hi.mul_real(c) + s * hi1;
+            h[(i + 1, j)] = -s.conj() * hi + hi1.mul_real(c);
+        }
+        let (c, s) = givens(h[(j, j)], h[(j + 1, j)]);
+        h[(j, j)] = h[(j, j)].mul_real(c) + s * h[(j + 1, j)];
+        h[(j + 1, j)] = A::zero();
+        rotations.push((c, s));
+        g[j + 1] = -s.conj() * g[j];
+        g[j] = g[j].mul_real(c);
+
+        let x_norm = match criterion {
+            StoppingCriterion::RelativeResidual => A::Real::zero(),
+            StoppingCriterion::BackwardError { .. } => update(&x0, &z, &h, &g)?.norm_l2(),
+        };
+        let res = criterion.measure(g[j + 1].abs(), b_norm, x_norm);
+        history.push(res);
+        if res <= tol || w_norm == A::Real::zero() {
+            break;
+        }
+        v.push(w.mapv(|w| w.div_real(w_norm)));
+    }
+
+    let x = update(&x0, &z, &h, &g)?;
+    Ok((x, history))
+}
+
+/// Form the iterate `x = x0 + Z y` where `y` solves the triangularized least
+/// squares problem in the current Krylov subspace
+fn update<A: Scalar + Lapack>(
+    x0: &Array1<A>,
+    z: &[Array1<A>],
+    h: &H<A>,
+    g: &Array1<A>,
+) -> Result<Array1<A>> {
+    let k = z.len();
+    let y = h.slice(s![..k, ..k]).to_owned().solve_triangular(
+        UPLO::Upper,
+        Diag::NonUnit,
+        &g.slice(s![..k]).to_owned(),
+    )?;
+    let mut x = x0.clone();
+    for (zj, &yj) in z.iter().zip(y.iter()) {
+        azip!((x in &mut x, &zj in zj) *x += yj * zj);
+    }
+    Ok(x)
+}
+
+/// Complex Givens rotation `(c, s)` which eliminates `b` in `(a, b)`
+fn givens<A: Scalar>(a: A, b: A) -> (A::Real, A) {
+    let (a_abs, b_abs) = (a.abs(), b.abs());
+    if b_abs == A::Real::zero() {
+        return (A::Real::one(), A::zero());
+    }
+    if a_abs == A::Real::zero() {
+        return (A::Real::zero(), b.conj().div_real(b_abs));
+    }
+    let r = (a_abs * a_abs + b_abs * b_abs).sqrt();
+    (a_abs / r, a.div_real(a_abs) * b.conj().div_real(r))
+}
@@ -0,0 +1,166 @@
+//! Restarted generalized minimal residual method
+//!
+//! - [Generalized minimal residual method - Wikipedia](https://en.wikipedia.org/wiki/Generalized_minimal_residual_method)
+
+use super::*;
+use crate::error::*;
+
+/// Solve `A x = b` by GMRES restarted every `restart` iterations
+///
+/// - `a` applies the operator `A` to a vector, which need not be symmetric
+/// - Iteration starts from `x = 0`, and stops when the relative residual
+///   `|b - A x| / |b|` becomes smaller than `tol`, or after `max_iter`
+///   iterations in total
+/// - Every `restart` iterations, the Krylov subspace is discarded and GMRES
+///   is restarted from the current iterate, which bounds the memory to
+///   `restart` vectors of the dimension of `b`
+///
+/// Returns the approximate solution and the history of relative residual
+/// norms, starting with that of `x = 0`. Each restart cycle is run by
+/// [fgmres()] without preconditioning.
+///
+/// ```rust
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a = array![[4.0, 1.0, 0.0], [-1.0, 3.0, 1.0], [0.0, -1.0, 2.0]];
+/// let b = array![1.0, 2.0, 3.0];
+/// let (x, history) = gmres(|v| a.dot(&v), &b, 1e-12, 10, 3).unwrap();
+/// assert!(*history.last().unwrap() <= 1e-12);
+/// assert_close_l2!(&a.dot(&x), &b, 1e-10);
+/// ```
+///
+/// Panics
+/// -------
+/// - if `restart` is zero
+pub fn gmres<A, S, F>(
+    a: F,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+    restart: usize,
+) -> Result<(Array1<A>, Vec<A::Real>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    gmres_preconditioned(
+        a,
+        |v: ArrayView1<A>| v.to_owned(),
+        b,
+        tol,
+        max_iter,
+        restart,
+    )
+}
+
+/// [gmres()] with a custom [StoppingCriterion]
+///
+/// Iteration stops when the measure of `criterion` becomes smaller than
+/// `tol`, and the history of this measure is returned. Each restart cycle is
+/// run by [fgmres_with_criterion()].
+///
+/// Panics
+/// -------
+/// - if `restart` is zero
+pub fn gmres_with_criterion<A, S, F>(
+    a: F,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+    restart: usize,
+    criterion: StoppingCriterion<A::Real>,
+) -> Result<(Array1<A>, Vec<A::Real>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    restarted(
+        a,
+        |v: ArrayView1<A>| v.to_owned(),
+        b,
+        tol,
+        max_iter,
+        restart,
+        criterion,
+    )
+}
+
+/// [gmres()] with a right [Preconditioner]
+///
+/// This solves `A M^{-1} y = b` and returns `x = M^{-1} y`. The history is of
+/// the relative residual `|b - A x| / |b|` of the original system, so that it
+/// can be compared with that of [gmres()].
+///
+/// Panics
+/// -------
+/// - if `restart` is zero
+pub fn gmres_preconditioned<A, S, F, P>(
+    a: F,
+    precond: P,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+    restart: usize,
+) -> Result<(Array1<A>, Vec<A::Real>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+    P: Preconditioner<A>,
+{
+    restarted(
+        a,
+        precond,
+        b,
+        tol,
+        max_iter,
+        restart,
+        StoppingCriterion::RelativeResidual,
+    )
+}
+
+/// Run [fgmres_with_criterion()] from the current iterate every `restart` iterations
+fn restarted<A, S, F, P>(
+    a: F,
+    mut precond: P,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+    restart: usize,
+    criterion: StoppingCriterion<A::Real>,
+) -> Result<(Array1<A>, Vec<A::Real>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+    P: Preconditioner<A>,
+{
+    assert!(restart > 0, "restart must be positive");
+    let mut x = Array1::zeros(b.len());
+    let mut history: Vec<A::Real> = Vec::new();
+    let mut remaining = max_iter;
+    loop {
+        let (x_new, hist) = fgmres_with_criterion(
+            &a,
+            |v| precond.apply(v),
+            b,
+            x,
+            tol,
+            remaining.min(restart),
+            criterion,
+        )?;
+        x = x_new;
+        // The first entry of a restarted cycle duplicates the last one of the previous cycle
+        let skip = if history.is_empty() { 0 } else { 1 };
+        let iter = hist.len() - 1;
+        history.extend_from_slice(&hist[skip..]);
+        remaining -= iter;
+        if *history.last().unwrap() <= tol || remaining == 0 || iter == 0 {
+            break;
+        }
+    }
+    Ok((x, history))
+}
@@ -0,0 +1,126 @@
+//! Lanczos iteration
+//!
+//! - [Lanczos algorithm - Wikipedia](https://en.wikipedia.org/wiki/Lanczos_algorithm)
+
+use super::*;
+use crate::{inner::*, norm::Norm};
+use num_traits::Float;
+
+/// Lanczos tridiagonalization of a symmetric/Hermitian operator
+///
+/// - `a` applies the operator `A` to a vector
+/// - `v0` is the starting vector, which need not be normalized
+/// - At most `k` steps are executed
+///
+/// Returns the Lanczos vectors `V` as the columns of an `n x m` matrix and the
+/// real symmetric tridiagonal `m x m` matrix `T = V^H A V`, where `m <= k`.
+/// The iteration stops early if the Krylov subspace becomes invariant under `A`.
+///
+/// The three-term recurrence only orthogonalizes against the last two vectors,
+/// so that the orthogonality of `V` is lost in floating point arithmetic once
+/// a Ritz value converges. Use [lanczos_reorthogonalized] if `V` is required
+/// to be orthonormal.
+///
+/// ```rust
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = Array2::from_diag(&array![1.0, 2.0, 3.0, 4.0]);
+/// let (v, t) = lanczos(|x| a.dot(&x), &array![1.0, 1.0, 1.0, 1.0], 4);
+/// assert_close_l2!(&v.t().dot(&a).dot(&v), &t, 1e-9);
+/// ```
+pub fn lanczos<A, S, F>(a: F, v0: &ArrayBase<S, Ix1>, k: usize) -> (Q<A>, H<A>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    lanczos_impl::<A, S, F, MGS<A>>(a, v0, k, None)
+}
+
+/// [lanczos()] with full reorthogonalization
+///
+/// Each new Lanczos vector is orthogonalized against all the previous ones
+/// by `ortho`, which must be empty. The tolerance of `ortho` is not used.
+pub fn lanczos_reorthogonalized<A, S, F, Ortho>(
+    a: F,
+    v0: &ArrayBase<S, Ix1>,
+    k: usize,
+    ortho: Ortho,
+) -> (Q<A>, H<A>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+    Ortho: Orthogonalizer<Elem = A>,
+{
+    lanczos_impl(a, v0, k, Some(ortho))
+}
+
+fn lanczos_impl<A, S, F, Ortho>(
+    a: F,
+    v0: &ArrayBase<S, Ix1>,
+    k: usize,
+    mut ortho: Option<Ortho>,
+) -> (Q<A>, H<A>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+    Ortho: Orthogonalizer<Elem = A>,
+{
+    let n = v0.len();
+    if let Some(ortho) = ortho.as_ref() {
+        assert!(ortho.is_empty());
+        assert_eq!(ortho.dim(), n);
+    }
+    let eps = A::Real::epsilon();
+
+    let v0_norm = v0.norm_l2();
+    let mut vs = vec![v0.mapv(|v| v.div_real(v0_norm))];
+    let mut alpha: Vec<A::Real> = Vec::new();
+    let mut beta: Vec<A::Real> = Vec::new();
+    if let Some(ortho) = ortho.as_mut() {
+        ortho.append(vs[0].view());
+    }
+
+    for j in 0..k.min(n) {
+        let mut w = a(vs[j].view());
+        let w_norm = w.norm_l2();
+        let aj = vs[j].inner(&w).re();
+        w.scaled_add(-A::from_real(aj), &vs[j]);
+        if j > 0 {
+            w.scaled_add(-A::from_real(beta[j - 1]), &vs[j - 1]);
+        }
+        if let Some(ortho) = ortho.as_ref() {
+            ortho.decompose(&mut w);
+        }
+        alpha.push(aj);
+
+        let bj = w.norm_l2();
+        if j + 1 == k.min(n) || bj <= eps * w_norm {
+            break;
+        }
+        beta.push(bj);
+        let v = w.mapv(|w| w.div_real(bj));
+        if let Some(ortho) = ortho.as_mut() {
+            ortho.append(v.view());
+        }
+        vs.push(v);
+    }
+
+    let m = alpha.len();
+    let mut q = Array2::zeros((n, m).f());
+    for (j, v) in vs.iter().take(m).enumerate() {
+        q.column_mut(j).assign(v);
+    }
+    let mut t = Array2::zeros((m, m).f());
+    for j in 0..m {
+        t[(j, j)] = A::from_real(alpha[j]);
+        if j + 1 < m {
+            t[(j + 1, j)] = A::from_real(beta[j]);
+            t[(j, j + 1)] = A::from_real(beta[j]);
+        }
+    }
+    (q, t)
+}
@@ -14,6 +14,9 @@ pub struct MGS<A: Scalar> {
 
     /// Tolerance
     tol: A::Real,
+
+    /// Orthogonalize twice if the norm is reduced by cancellation
+    reorthogonalize: bool,
 }
 
 impl<A: Scalar + Lapack> MGS<A> {
@@ -23,6 +26,33 @@ impl<A: Scalar + Lapack> MGS<A> {
             dim,
             q: Vec::new(),
             tol,
+            reorthogonalize: false,
+        }
+    }
+
+    /// Enable the reorthogonalization (double MGS)
+    ///
+    /// If the norm of a vector drops below `1/sqrt(2)` of the original one by
+    /// the orthogonalization, i.e. the vector is nearly dependent on the basis,
+    /// the orthogonalization is repeated once ("twice is enough" criterion by
+    /// Kahan and Parlett). This keeps the basis orthogonal to the machine
+    /// precision even for ill-conditioned sequences of vectors, where the
+    /// loss of orthogonality of single MGS is proportional to the condition
+    /// number.
+    pub fn reorthogonalize(mut self, reorthogonalize: bool) -> Self {
+        self.reorthogonalize = reorthogonalize;
+        self
+    }
+
+    /// Subtract the projection onto the basis from `a`, and accumulate the coefficients
+    fn project_out<S>(&self, a: &mut ArrayBase<S, Ix1>, coef: &mut Array1<A>)
+    where
+        S: DataMut<Elem = A>,
+    {
+        for (i, q) in self.q.iter().enumerate() {
+            let c = q.inner(a);
+            azip!((a in &mut *a, &q in q) *a -= c * q);
+            coef[i] += c;
         }
     }
 }
@@ -48,13 +78,13 @@ impl<A: Scalar + Lapack> Orthogonalizer for MGS<A> {
     {
         assert_eq!(a.len(), self.dim());
         let mut coef = Array1::zeros(self.len() + 1);
-        for i in 0..self.len() {
-            let q = &self.q[i];
-            let c = q.inner(a);
-            azip!((a in &mut *a, &q in q) *a -= c * q);
-            coef[i] = c;
+        let before = a.norm_l2();
+        self.project_out(a, &mut coef);
+        let mut nrm = a.norm_l2();
+        if self.reorthogonalize && nrm < before * A::real(std::f64::consts::FRAC_1_SQRT_2) {
+            self.project_out(a, &mut coef);
+            nrm = a.norm_l2();
         }
-        let nrm = a.norm_l2();
         coef[self.len()] = A::from_real(nrm);
         coef
     }
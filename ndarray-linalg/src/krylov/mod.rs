@@ -4,12 +4,24 @@ use crate::types::*;
 use ndarray::*;
 
 pub mod arnoldi;
+pub mod bicgstab;
+pub mod cgs;
+pub mod fgmres;
+pub mod gmres;
 pub mod householder;
+pub mod lanczos;
 pub mod mgs;
+pub mod preconditioner;
 
-pub use arnoldi::{arnoldi_householder, arnoldi_mgs, Arnoldi};
+pub use arnoldi::{arnoldi_householder, arnoldi_mgs, Arnoldi, ArnoldiDecomposition};
+pub use bicgstab::{bicgstab, bicgstab_with_criterion};
+pub use cgs::gram_schmidt;
+pub use fgmres::{fgmres, fgmres_with_criterion};
+pub use gmres::{gmres, gmres_preconditioned, gmres_with_criterion};
 pub use householder::{householder, Householder};
+pub use lanczos::{lanczos, lanczos_reorthogonalized};
 pub use mgs::{mgs, MGS};
+pub use preconditioner::{IncompleteCholesky, Jacobi, Preconditioner};
 
 /// Q-matrix
 ///
@@ -174,6 +186,82 @@ pub enum Strategy {
     Full,
 }
 
+/// Stopping criterion of iterative solvers for `A x = b`
+///
+/// The residual norm `|r| = |b - A x|` alone is not scale-invariant, and may
+/// be misleading for badly scaled or ill-conditioned systems. The normwise
+/// backward error is the smallest relative perturbation of `A` and `b` for
+/// which the current iterate `x` is an exact solution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StoppingCriterion<R> {
+    /// Relative residual `|r| / |b|`
+    RelativeResidual,
+
+    /// Normwise backward error `|r| / (|A| |x| + |b|)`
+    ///
+    /// `a_norm` is an estimate of `|A|`, which is computed once before the
+    /// iteration. Use [StoppingCriterion::backward_error] to estimate it by
+    /// [normest1()](crate::normest1()) for a matrix-free operator, or
+    /// [OperationNorm](crate::OperationNorm) for an explicit matrix.
+    BackwardError { a_norm: R },
+}
+
+impl<R: num_traits::Float> StoppingCriterion<R> {
+    /// [StoppingCriterion::BackwardError] with `|A|_1` estimated by
+    /// [normest1()](crate::normest1())
+    ///
+    /// - `n` is the dimension of the `n x n` operator `A`
+    /// - `a` applies `A` to a vector, as passed to the solvers
+    /// - `ah` applies `A^H` to a vector, which the estimator requires in
+    ///   addition to `A`
+    ///
+    /// The estimator applies `a` and `ah` to a few vectors. Since
+    /// `|A|_2 <= sqrt(n) |A|_1`, the resulting backward error is within a
+    /// modest factor of the one measured in the 2-norm.
+    pub fn backward_error<A, F, G>(n: usize, a: F, ah: G) -> Self
+    where
+        A: Scalar<Real = R>,
+        F: Fn(ArrayView1<A>) -> Array1<A>,
+        G: Fn(ArrayView1<A>) -> Array1<A>,
+    {
+        let a_norm =
+            crate::normest1::normest1(n, 2, |x| apply_columns(&a, x), |x| apply_columns(&ah, x));
+        StoppingCriterion::BackwardError { a_norm }
+    }
+}
+
+impl<R: num_traits::Float> StoppingCriterion<R> {
+    /// Evaluate the criterion for the norms of the residual `r`, the
+    /// right-hand side `b` and the current iterate `x`
+    ///
+    /// `x_norm` is ignored for [StoppingCriterion::RelativeResidual].
+    /// If the denominator vanishes, the absolute residual norm is returned.
+    pub fn measure(&self, r_norm: R, b_norm: R, x_norm: R) -> R {
+        let scale = match *self {
+            StoppingCriterion::RelativeResidual => b_norm,
+            StoppingCriterion::BackwardError { a_norm } => a_norm * x_norm + b_norm,
+        };
+        if scale > R::zero() {
+            r_norm / scale
+        } else {
+            r_norm
+        }
+    }
+}
+
+/// Apply a vector operator to each column of `x`
+fn apply_columns<A, F>(op: &F, x: ArrayView2<A>) -> Array2<A>
+where
+    A: Scalar,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    let mut y = Array2::zeros(x.dim());
+    for (mut yj, xj) in y.axis_iter_mut(Axis(1)).zip(x.axis_iter(Axis(1))) {
+        yj.assign(&op(xj));
+    }
+    y
+}
+
 /// Online QR decomposition using arbitrary orthogonalizer
 pub fn qr<A, S>(
     iter: impl Iterator<Item = ArrayBase<S, Ix1>>,
@@ -0,0 +1,143 @@
+//! Preconditioners for the Krylov solvers
+//!
+//! A [Preconditioner] applies `M^{-1}` for an approximation `M` of the
+//! operator `A`. Any closure `FnMut(ArrayView1<A>) -> Array1<A>` is a
+//! preconditioner, and [Jacobi] and [IncompleteCholesky] are provided as
+//! reusable implementations for an explicit matrix.
+//!
+//! A preconditioner is used by [gmres_preconditioned], or can be passed to
+//! [fgmres()] as a closure `|v| p.apply(v)`.
+
+use super::*;
+use crate::{error::*, generate::conjugate, layout::*, triangular::*, UPLO};
+use num_traits::Zero;
+
+/// Apply the inverse `M^{-1}` of a preconditioner `M` to a vector
+pub trait Preconditioner<A: Scalar> {
+    /// Compute `M^{-1} v`
+    ///
+    /// This takes `&mut self` so that a preconditioner may keep a state,
+    /// e.g. an inner iterative solver or a workspace.
+    fn apply(&mut self, v: ArrayView1<A>) -> Array1<A>;
+}
+
+impl<A, F> Preconditioner<A> for F
+where
+    A: Scalar,
+    F: FnMut(ArrayView1<A>) -> Array1<A>,
+{
+    fn apply(&mut self, v: ArrayView1<A>) -> Array1<A> {
+        self(v)
+    }
+}
+
+/// Jacobi (diagonal) preconditioner `M = diag(A)`
+#[derive(Debug, Clone)]
+pub struct Jacobi<A: Scalar> {
+    inv_diag: Array1<A>,
+}
+
+impl<A: Scalar> Jacobi<A> {
+    /// Jacobi preconditioner of a square matrix `a`
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NotSquare] if `a` is not square
+    /// - [LinalgError::ZeroDiagonal] if a diagonal element of `a` is zero
+    pub fn new<S: Data<Elem = A>>(a: &ArrayBase<S, Ix2>) -> Result<Self> {
+        a.ensure_square()?;
+        Self::from_diagonal(&a.diag())
+    }
+
+    /// Jacobi preconditioner with the diagonal `d`
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::ZeroDiagonal] if an element of `d` is zero
+    pub fn from_diagonal<S: Data<Elem = A>>(d: &ArrayBase<S, Ix1>) -> Result<Self> {
+        if let Some(index) = d.iter().position(|d| d.is_zero()) {
+            return Err(LinalgError::ZeroDiagonal { index });
+        }
+        Ok(Jacobi {
+            inv_diag: d.mapv(|d| A::one() / d),
+        })
+    }
+}
+
+impl<A: Scalar> Preconditioner<A> for Jacobi<A> {
+    fn apply(&mut self, v: ArrayView1<A>) -> Array1<A> {
+        assert_eq!(v.len(), self.inv_diag.len());
+        &v * &self.inv_diag
+    }
+}
+
+/// Zero fill-in incomplete Cholesky preconditioner `M = L L^H`
+///
+/// `L` is the lower triangular factor of a symmetric/Hermitian positive
+/// definite `A` computed by the Cholesky algorithm, where the elements which
+/// are zero in `A` are kept zero. For a dense `A` without zeros, this is the
+/// exact Cholesky factorization.
+#[derive(Debug, Clone)]
+pub struct IncompleteCholesky<A: Scalar> {
+    l: Array2<A>,
+    lh: Array2<A>,
+}
+
+impl<A: Scalar + Lapack> IncompleteCholesky<A> {
+    /// Incomplete Cholesky preconditioner using the lower triangle of `a`
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NotSquare] if `a` is not square
+    /// - [LinalgError::NotPositiveDefinite] if a pivot is not positive, which
+    ///   may happen even for a positive definite `a` due to the dropped fill-in
+    pub fn new<S: Data<Elem = A>>(a: &ArrayBase<S, Ix2>) -> Result<Self> {
+        a.ensure_square()?;
+        let n = a.nrows();
+        let mut l: Array2<A> = Array2::zeros((n, n));
+        for ((i, j), &aij) in a.indexed_iter() {
+            if i >= j {
+                l[(i, j)] = aij;
+            }
+        }
+        for k in 0..n {
+            let pivot = l[(k, k)].re();
+            if pivot <= A::Real::zero() {
+                return Err(LinalgError::NotPositiveDefinite { minor: k + 1 });
+            }
+            let pivot = pivot.sqrt();
+            l[(k, k)] = A::from_real(pivot);
+            for i in k + 1..n {
+                l[(i, k)] = l[(i, k)].div_real(pivot);
+            }
+            for j in k + 1..n {
+                let ljk = l[(j, k)].conj();
+                for i in j..n {
+                    if !l[(i, j)].is_zero() {
+                        let lik = l[(i, k)];
+                        l[(i, j)] -= lik * ljk;
+                    }
+                }
+            }
+        }
+        let lh = conjugate(&l);
+        Ok(IncompleteCholesky { l, lh })
+    }
+
+    /// Lower triangular factor `L`
+    pub fn factor(&self) -> &Array2<A> {
+        &self.l
+    }
+}
+
+impl<A: Scalar + Lapack> Preconditioner<A> for IncompleteCholesky<A> {
+    fn apply(&mut self, v: ArrayView1<A>) -> Array1<A> {
+        let y = self
+            .l
+            .solve_triangular(UPLO::Lower, Diag::NonUnit, &v.to_owned())
+            .expect("Non-singular factor");
+        self.lh
+            .solve_triangular(UPLO::Upper, Diag::NonUnit, &y)
+            .expect("Non-singular factor")
+    }
+}
@@ -1,7 +1,9 @@
 //! Convert ndarray into LAPACK-compatible matrix format
 
 use super::error::*;
+use lax::layout::{ensure_fortran, transpose_over};
 use ndarray::*;
+use num_traits::Zero;
 
 pub use lax::layout::MatrixLayout;
 
@@ -81,3 +83,34 @@ where
             .ok_or(LinalgError::MemoryNotCont)
     }
 }
+
+/// Call `f` with the elements of `b` in the column-major order, and write
+/// them back to `b`
+///
+/// A column-major `b` is passed without copy, and a row-major one is
+/// transposed by [ensure_fortran]. Otherwise, `b` is copied into a
+/// column-major array.
+pub(crate) fn with_fortran<A, S, F>(b: &mut ArrayBase<S, Ix2>, f: F) -> Result<()>
+where
+    A: Copy + Zero,
+    S: DataMut<Elem = A>,
+    F: FnOnce(&mut [A]) -> Result<()>,
+{
+    match (b.layout(), b.as_allocated().is_ok()) {
+        (Ok(MatrixLayout::F { .. }), true) => f(b.as_allocated_mut()?),
+        (Ok(l), true) => {
+            let (l, t) = ensure_fortran(l, b.as_allocated()?);
+            let mut t = t.unwrap();
+            f(&mut t)?;
+            transpose_over(l, &t, b.as_allocated_mut()?);
+            Ok(())
+        }
+        _ => {
+            let mut x = Array2::zeros(b.dim().f());
+            x.assign(b);
+            f(x.as_allocated_mut()?)?;
+            b.assign(&x);
+            Ok(())
+        }
+    }
+}
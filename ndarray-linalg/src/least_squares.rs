@@ -20,6 +20,8 @@
 //!
 //! All methods use the Lapacke family of methods `*gelsd` which solves the least
 //! squares problem using the SVD with a divide-and-conquer strategy.
+//! For full-rank `A`, [LeastSquaresQr] is a faster alternative using the QR
+//! (or LQ) factorization by `*gels`.
 //!
 //! The traits are implemented for value types `f32`, `f64`, `c32` and `c64`
 //! and vector or matrix right-hand-sides (`ArrayBase<S, Ix1>` or `ArrayBase<S, Ix2>`).
@@ -63,9 +65,13 @@
 use lax::*;
 use ndarray::*;
 
+use crate::cholesky::*;
 use crate::error::*;
+use crate::generate::conjugate;
 use crate::layout::*;
+use crate::triangular::SolveTriangular;
 use crate::types::*;
+use crate::validate::check_input;
 
 /// Result of a LeastSquares computation
 ///
@@ -105,6 +111,19 @@ where
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
     fn least_squares(&self, rhs: &ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// with the tolerance `rcond` for the effective rank of `A`.
+    ///
+    /// Singular values `s_i <= rcond * s_1` are treated as zero, so
+    /// that a larger `rcond` discards the ill-conditioned directions
+    /// of a near-singular `A`. A negative `rcond` means the machine
+    /// precision, which is used by [LeastSquaresSvd::least_squares].
+    fn least_squares_rcond(
+        &self,
+        rhs: &ArrayBase<D, I>,
+        rcond: E::Real,
+    ) -> Result<LeastSquaresResult<E, I>>;
 }
 
 /// Solve least squares for owned matrices
@@ -169,6 +188,16 @@ where
         let b = rhs.to_owned();
         a.least_squares_into(b)
     }
+
+    fn least_squares_rcond(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+        rcond: E::Real,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        let mut a = self.to_owned();
+        let mut b = rhs.to_owned();
+        least_squares_srhs(&mut a, &mut b, rcond)
+    }
 }
 
 /// Solve least squares for immutable references and matrix
@@ -193,6 +222,16 @@ where
         let b = rhs.to_owned();
         a.least_squares_into(b)
     }
+
+    fn least_squares_rcond(
+        &self,
+        rhs: &ArrayBase<D2, Ix2>,
+        rcond: E::Real,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        let mut a = self.to_owned();
+        let mut b = rhs.to_owned();
+        least_squares_nrhs(&mut a, &mut b, rcond)
+    }
 }
 
 /// Solve least squares for owned values and a single
@@ -272,31 +311,47 @@ where
         &mut self,
         rhs: &mut ArrayBase<D2, Ix1>,
     ) -> Result<LeastSquaresResult<E, Ix1>> {
-        if self.shape()[0] != rhs.shape()[0] {
-            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
-        }
-        let (m, n) = (self.shape()[0], self.shape()[1]);
-        if n > m {
-            // we need a new rhs b/c it will be overwritten with the solution
-            // for which we need `n` entries
-            let mut new_rhs = Array1::<E>::zeros((n,));
-            new_rhs.slice_mut(s![0..m]).assign(rhs);
-            compute_least_squares_srhs(self, &mut new_rhs)
-        } else {
-            compute_least_squares_srhs(self, rhs)
-        }
+        least_squares_srhs(self, rhs, E::real(-1.0))
+    }
+}
+
+fn least_squares_srhs<E, D1, D2>(
+    a: &mut ArrayBase<D1, Ix2>,
+    rhs: &mut ArrayBase<D2, Ix1>,
+    rcond: E::Real,
+) -> Result<LeastSquaresResult<E, Ix1>>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    if a.shape()[0] != rhs.shape()[0] {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let (m, n) = (a.shape()[0], a.shape()[1]);
+    if n > m {
+        // we need a new rhs b/c it will be overwritten with the solution
+        // for which we need `n` entries
+        let mut new_rhs = Array1::<E>::zeros((n,));
+        new_rhs.slice_mut(s![0..m]).assign(rhs);
+        compute_least_squares_srhs(a, &mut new_rhs, rcond)
+    } else {
+        compute_least_squares_srhs(a, rhs, rcond)
     }
 }
 
 fn compute_least_squares_srhs<E, D1, D2>(
     a: &mut ArrayBase<D1, Ix2>,
     rhs: &mut ArrayBase<D2, Ix1>,
+    rcond: E::Real,
 ) -> Result<LeastSquaresResult<E, Ix1>>
 where
     E: Scalar + Lapack,
     D1: DataMut<Elem = E>,
     D2: DataMut<Elem = E>,
 {
+    check_input(a)?;
+    check_input(rhs)?;
     let LeastSquaresOwned::<E> {
         singular_values,
         rank,
@@ -305,6 +360,7 @@ where
         a.as_allocated_mut()?,
         rhs.as_slice_memory_order_mut()
             .ok_or(LinalgError::MemoryNotCont)?,
+        rcond,
     )?;
 
     let (m, n) = (a.shape()[0], a.shape()[1]);
@@ -355,35 +411,51 @@ where
         &mut self,
         rhs: &mut ArrayBase<D2, Ix2>,
     ) -> Result<LeastSquaresResult<E, Ix2>> {
-        if self.shape()[0] != rhs.shape()[0] {
-            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
-        }
-        let (m, n) = (self.shape()[0], self.shape()[1]);
-        if n > m {
-            // we need a new rhs b/c it will be overwritten with the solution
-            // for which we need `n` entries
-            let k = rhs.shape()[1];
-            let mut new_rhs = match self.layout()? {
-                MatrixLayout::C { .. } => Array2::<E>::zeros((n, k)),
-                MatrixLayout::F { .. } => Array2::<E>::zeros((n, k).f()),
-            };
-            new_rhs.slice_mut(s![0..m, ..]).assign(rhs);
-            compute_least_squares_nrhs(self, &mut new_rhs)
-        } else {
-            compute_least_squares_nrhs(self, rhs)
-        }
+        least_squares_nrhs(self, rhs, E::real(-1.0))
+    }
+}
+
+fn least_squares_nrhs<E, D1, D2>(
+    a: &mut ArrayBase<D1, Ix2>,
+    rhs: &mut ArrayBase<D2, Ix2>,
+    rcond: E::Real,
+) -> Result<LeastSquaresResult<E, Ix2>>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    if a.shape()[0] != rhs.shape()[0] {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let (m, n) = (a.shape()[0], a.shape()[1]);
+    if n > m {
+        // we need a new rhs b/c it will be overwritten with the solution
+        // for which we need `n` entries
+        let k = rhs.shape()[1];
+        let mut new_rhs = match a.layout()? {
+            MatrixLayout::C { .. } => Array2::<E>::zeros((n, k)),
+            MatrixLayout::F { .. } => Array2::<E>::zeros((n, k).f()),
+        };
+        new_rhs.slice_mut(s![0..m, ..]).assign(rhs);
+        compute_least_squares_nrhs(a, &mut new_rhs, rcond)
+    } else {
+        compute_least_squares_nrhs(a, rhs, rcond)
     }
 }
 
 fn compute_least_squares_nrhs<E, D1, D2>(
     a: &mut ArrayBase<D1, Ix2>,
     rhs: &mut ArrayBase<D2, Ix2>,
+    rcond: E::Real,
 ) -> Result<LeastSquaresResult<E, Ix2>>
 where
     E: Scalar + Lapack,
     D1: DataMut<Elem = E>,
     D2: DataMut<Elem = E>,
 {
+    check_input(a)?;
+    check_input(rhs)?;
     let a_layout = a.layout()?;
     let rhs_layout = rhs.layout()?;
     let LeastSquaresOwned::<E> {
@@ -394,6 +466,7 @@ where
         a.as_allocated_mut()?,
         rhs_layout,
         rhs.as_allocated_mut()?,
+        rcond,
     )?;
 
     let solution: Array2<E> = rhs.slice(s![..a.shape()[1], ..]).to_owned();
@@ -424,6 +497,155 @@ fn compute_residual_array1<E: Scalar, D: Data<Elem = E>>(
     )
 }
 
+/// Solve least squares of full-rank matrices using QR factorization
+pub trait LeastSquaresQr<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// by the QR factorization of `A` if `A` is overdetermined,
+    /// or the minimum norm solution by the LQ factorization of `A`
+    /// if `A` is underdetermined. `A` and `rhs` are unchanged.
+    ///
+    /// This is faster than [LeastSquaresSvd::least_squares], but `A`
+    /// must have full rank.
+    ///
+    /// Errors
+    /// -------
+    /// - `IncompatibleShape` if the numbers of rows of `A` and `rhs` differ
+    /// - LAPACK error if `A` is exactly rank deficient. Numerically rank
+    ///   deficient `A` is not detected, and gives an inaccurate solution;
+    ///   use [LeastSquaresSvd] for such a case.
+    fn least_squares_qr(&self, rhs: &ArrayBase<D, I>) -> Result<Array<E, I>>;
+}
+
+impl<E, D1, D2> LeastSquaresQr<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_qr(&self, rhs: &ArrayBase<D2, Ix1>) -> Result<Array1<E>> {
+        let (m, n) = (self.nrows(), self.ncols());
+        if m != rhs.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        check_input(self)?;
+        check_input(rhs)?;
+        let mut a = self.to_owned();
+        // `rhs` is overwritten by the solution for which we need `n` entries
+        let mut b = Array1::<E>::zeros(m.max(n));
+        b.slice_mut(s![..m]).assign(rhs);
+        let a_layout = a.layout()?;
+        E::least_squares_qr(
+            a_layout,
+            a.as_allocated_mut()?,
+            a_layout.resized(b.len() as i32, 1),
+            b.as_slice_mut().unwrap(),
+        )?;
+        Ok(b.slice_move(s![..n]))
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQr<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_qr(&self, rhs: &ArrayBase<D2, Ix2>) -> Result<Array2<E>> {
+        let (m, n) = (self.nrows(), self.ncols());
+        if m != rhs.nrows() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        check_input(self)?;
+        check_input(rhs)?;
+        let mut a = self.to_owned();
+        let a_layout = a.layout()?;
+        // `rhs` is overwritten by the solution for which we need `n` rows
+        let k = rhs.ncols();
+        let mut b = match a_layout {
+            MatrixLayout::C { .. } => Array2::<E>::zeros((m.max(n), k)),
+            MatrixLayout::F { .. } => Array2::<E>::zeros((m.max(n), k).f()),
+        };
+        b.slice_mut(s![..m, ..]).assign(rhs);
+        let b_layout = b.layout()?;
+        E::least_squares_qr(
+            a_layout,
+            a.as_allocated_mut()?,
+            b_layout,
+            b.as_allocated_mut()?,
+        )?;
+        Ok(b.slice_move(s![..n, ..]))
+    }
+}
+
+/// Generalized least squares with the error covariance `cov`
+///
+/// This solves `min_x (b - Ax)^H C^{-1} (b - Ax)` for a symmetric/Hermitian
+/// positive definite covariance `C` of the errors in `b`. With the Cholesky
+/// factorization `C = L L^H`, the problem is whitened into the ordinary least
+/// squares `min_x ||L^{-1} b - L^{-1} A x||`, which is solved by [LeastSquaresSvd].
+/// Thus the singular values and the residual sum of squares in the result are
+/// those of the whitened problem.
+///
+/// See [gls_factorized] for a precomputed Cholesky factor of `cov`.
+///
+/// Errors
+/// -------
+/// - LAPACK error if `cov` is not positive definite
+pub fn gls<A, Sa, Sb, Sc, I>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, I>,
+    cov: &ArrayBase<Sc, Ix2>,
+) -> Result<LeastSquaresResult<A, I>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sc: Data<Elem = A>,
+    I: Dimension,
+    Array2<A>: SolveTriangular<A, Sa, Ix2>
+        + SolveTriangular<A, Sb, I>
+        + LeastSquaresSvdInto<OwnedRepr<A>, A, I>,
+{
+    let f = cov.factorizec(UPLO::Lower)?;
+    gls_factorized(a, b, &f)
+}
+
+/// Generalized least squares with the Cholesky factorization of the error covariance
+///
+/// Same as [gls], but the factorization of the covariance, e.g. by
+/// [FactorizeC::factorizec], is reused.
+pub fn gls_factorized<A, Sa, Sb, Sc, I>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, I>,
+    cov: &CholeskyFactorized<Sc>,
+) -> Result<LeastSquaresResult<A, I>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sc: Data<Elem = A>,
+    I: Dimension,
+    Array2<A>: SolveTriangular<A, Sa, Ix2>
+        + SolveTriangular<A, Sb, I>
+        + LeastSquaresSvdInto<OwnedRepr<A>, A, I>,
+{
+    let l: Array2<A> = match cov.uplo {
+        UPLO::Lower => cov.factor.to_owned(),
+        UPLO::Upper => conjugate(&cov.factor),
+    };
+    let wa = l.solve_triangular(UPLO::Lower, Diag::NonUnit, a)?;
+    let wb = l.solve_triangular(UPLO::Lower, Diag::NonUnit, b)?;
+    // `least_squares` requires the same layout of the matrix and the right hand side
+    let wa = wa.as_standard_layout().into_owned();
+    let wb = wb.as_standard_layout().into_owned();
+    wa.least_squares_into(wb)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{error::LinalgError, *};
@@ -50,51 +50,96 @@
 extern crate ndarray;
 
 pub mod assert;
+pub mod balance;
+pub mod banded;
+pub mod bidiagonal;
+pub mod cca;
 pub mod cholesky;
+pub mod cond;
 pub mod convert;
 pub mod diagonal;
+pub mod dmd;
 pub mod eig;
 pub mod eigh;
 pub mod error;
+pub mod expm;
 pub mod generate;
 pub mod inner;
 pub mod krylov;
 pub mod layout;
 pub mod least_squares;
 pub mod lobpcg;
+pub mod logm;
+pub mod lyapunov;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra_interop;
 pub mod norm;
+pub mod normest1;
 pub mod operator;
 pub mod opnorm;
+pub mod pow;
 pub mod qr;
+pub mod ridge;
+pub mod schur;
 pub mod solve;
 pub mod solveh;
+pub mod spd_mean;
+pub mod spectral;
+pub mod sqrtm;
+pub mod stats;
+pub mod subspace;
 pub mod svd;
 pub mod svddc;
 pub mod trace;
 pub mod triangular;
 pub mod tridiagonal;
+pub mod tridiagonalize;
 pub mod types;
+pub mod validate;
 
 pub use crate::assert::*;
+pub use crate::balance::*;
+pub use crate::banded::*;
+pub use crate::bidiagonal::*;
+pub use crate::cca::*;
 pub use crate::cholesky::*;
+pub use crate::cond::*;
 pub use crate::convert::*;
 pub use crate::diagonal::*;
+pub use crate::dmd::*;
 pub use crate::eig::*;
 pub use crate::eigh::*;
+pub use crate::expm::*;
 pub use crate::generate::*;
 pub use crate::inner::*;
+pub use crate::krylov::gmres;
 pub use crate::layout::*;
 pub use crate::least_squares::*;
 pub use crate::lobpcg::{TruncatedEig, TruncatedOrder, TruncatedSvd};
+pub use crate::logm::*;
+pub use crate::lyapunov::*;
+#[cfg(feature = "nalgebra")]
+pub use crate::nalgebra_interop::*;
 pub use crate::norm::*;
+pub use crate::normest1::*;
 pub use crate::operator::*;
 pub use crate::opnorm::*;
+pub use crate::pow::*;
 pub use crate::qr::*;
+pub use crate::ridge::*;
+pub use crate::schur::*;
 pub use crate::solve::*;
 pub use crate::solveh::*;
+pub use crate::spd_mean::*;
+pub use crate::spectral::*;
+pub use crate::sqrtm::*;
+pub use crate::stats::*;
+pub use crate::subspace::*;
 pub use crate::svd::*;
 pub use crate::svddc::*;
 pub use crate::trace::*;
 pub use crate::triangular::*;
 pub use crate::tridiagonal::*;
+pub use crate::tridiagonalize::*;
 pub use crate::types::*;
+pub use crate::validate::*;
@@ -54,7 +54,15 @@ impl<A: Float + PartialOrd + DivAssign<A> + 'static + MagnitudeCorrection> Trunc
         values
     }
 
-    /// Returns singular values, left-singular vectors and right-singular vectors
+    /// Returns left-singular vectors, singular values and right-singular vectors
+    ///
+    /// For a `n x m` problem matrix this returns `(u, sigma, v_t)` where `sigma` contains the
+    /// `k` singular values ordered by magnitude, `u` is a `n x k` matrix with orthonormal columns
+    /// and `v_t` is a `k x m` matrix with orthonormal rows. The product `u diag(sigma) v_t` is the
+    /// rank-`k` approximation of the problem matrix.
+    ///
+    /// `k` is the number of requested singular values in [TruncatedSvd::decompose], less the
+    /// values dropped as numerically zero.
     pub fn values_vectors(&self) -> (Array2<A>, Array1<A>, Array2<A>) {
         let (values, indices) = self.singular_values_with_indices();
 
@@ -228,4 +236,31 @@ mod tests {
 
         close_l2(&a, &reconstructed, 1e-5);
     }
+
+    #[test]
+    fn test_truncated_svd_low_rank() {
+        let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+        let (n, m, k) = (40, 20, 3);
+        let b: Array2<f64> = generate::random_using((n, k), &mut rng);
+        let c: Array2<f64> = generate::random_using((k, m), &mut rng);
+        let noise: Array2<f64> = generate::random_using((n, m), &mut rng);
+        let a = b.dot(&c) + noise * 1e-6;
+
+        let res = TruncatedSvd::new(a.clone(), Order::Largest)
+            .precision(1e-10)
+            .maxiter(100)
+            .decompose(k)
+            .unwrap();
+
+        let (u, sigma, v_t) = res.values_vectors();
+        assert_eq!(u.dim(), (n, k));
+        assert_eq!(sigma.dim(), k);
+        assert_eq!(v_t.dim(), (k, m));
+
+        close_l2(&u.t().dot(&u), &Array2::eye(k), 1e-7);
+        close_l2(&v_t.dot(&v_t.t()), &Array2::eye(k), 1e-7);
+
+        let reconstructed = u.dot(&Array2::from_diag(&sigma).dot(&v_t));
+        close_l2(&a, &reconstructed, 1e-4);
+    }
 }
@@ -0,0 +1,113 @@
+//! Principal logarithm of general matrices
+//!
+//! - N. J. Higham, "Evaluating Padé approximants of the matrix logarithm",
+//!   SIAM J. Matrix Anal. Appl. 22 (2001)
+//! - S. H. Cheng, N. J. Higham, C. S. Kenney, and A. J. Laub, "Approximating
+//!   the logarithm of a matrix to specified accuracy", SIAM J. Matrix Anal.
+//!   Appl. 22 (2001)
+
+use ndarray::*;
+use num_traits::{One, ToPrimitive, Zero};
+
+use crate::error::*;
+use crate::opnorm::*;
+use crate::schur::*;
+use crate::sqrtm::sqrt_upper_triangular;
+use crate::triangular::*;
+use crate::types::*;
+use lax::UPLO;
+
+/// Nodes of the 7-point Gauss-Legendre rule on `[0, 1]`
+const NODES: [f64; 7] = [
+    0.025_446_043_828_620_737,
+    0.129_234_407_200_302_8,
+    0.297_077_424_311_301_4,
+    0.5,
+    0.702_922_575_688_698_6,
+    0.870_765_592_799_697_2,
+    0.974_553_956_171_379_3,
+];
+
+/// Weights of the 7-point Gauss-Legendre rule on `[0, 1]`
+const WEIGHTS: [f64; 7] = [
+    0.064_742_483_084_434_85,
+    0.139_852_695_744_638_33,
+    0.190_915_025_252_559_47,
+    0.208_979_591_836_734_7,
+    0.190_915_025_252_559_47,
+    0.139_852_695_744_638_33,
+    0.064_742_483_084_434_85,
+];
+
+/// Bound of `‖T - I‖_1` for which the `[7/7]` Padé approximant of `log(I + X)`
+/// is accurate to double precision
+const THETA_7: f64 = 0.264;
+
+/// Upper bound of the number of square roots taken in the scaling phase
+const MAX_SQRT: usize = 64;
+
+/// Matrix logarithm
+pub trait LogM {
+    type Output;
+
+    /// Compute the principal logarithm `X` satisfying `exp(X) = A`
+    ///
+    /// This uses the inverse scaling and squaring method on the complex Schur
+    /// form `A = Z T Z^H`. Square roots of `T` are taken by the
+    /// Björck-Hammarling recurrence used in [SqrtM](crate::SqrtM) until
+    /// `‖T^{1/2^s} - I‖_1` is small enough, and then the `[7/7]` Padé
+    /// approximant of `log(I + X)` is evaluated in the partial fraction form
+    ///
+    /// ```text
+    /// r(X) = Σ_j w_j (I + x_j X)^{-1} X
+    /// ```
+    ///
+    /// with the Gauss-Legendre nodes `x_j` and weights `w_j` on `[0, 1]`,
+    /// using triangular solves. The result is `X = 2^s Z r(T^{1/2^s} - I) Z^H`.
+    ///
+    /// `X` is the principal logarithm, i.e. whose eigenvalues have imaginary
+    /// parts in `(-π, π)`, if `A` has no eigenvalue on the closed negative
+    /// real axis. Since the logarithm of a real matrix with negative
+    /// eigenvalues is not real, the result is always a complex matrix.
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NoLogarithm] if `A` has a zero eigenvalue.
+    fn logm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> LogM for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A::Complex>;
+
+    fn logm(&self) -> Result<Self::Output> {
+        let (z, mut t) = self.schur_complex()?;
+        let n = t.nrows();
+        if t.diag().iter().any(|d| d.is_zero()) {
+            return Err(LinalgError::NoLogarithm);
+        }
+
+        let eye = Array2::<A::Complex>::eye(n);
+        let mut s = 0;
+        while s < MAX_SQRT && (&t - &eye).opnorm_one()?.to_f64().unwrap() > THETA_7 {
+            t = sqrt_upper_triangular(&t)?;
+            s += 1;
+        }
+
+        let x = &t - &eye;
+        let mut l = Array2::<A::Complex>::zeros((n, n));
+        for (&node, &weight) in NODES.iter().zip(WEIGHTS.iter()) {
+            let mut d = x.mapv(|v| v * A::Complex::from_real(A::Real::real(node)));
+            d.diag_mut().mapv_inplace(|v| v + A::Complex::one());
+            let y = d.solve_triangular(UPLO::Upper, Diag::NonUnit, &x)?;
+            l.scaled_add(A::Complex::from_real(A::Real::real(weight)), &y);
+        }
+        let scale = A::Complex::from_real(A::Real::real(2.0f64.powi(s as i32)));
+        l.mapv_inplace(|v| v * scale);
+        Ok(z.dot(&l).dot(&z.t().mapv(|x| x.conj())))
+    }
+}
@@ -0,0 +1,150 @@
+//! Continuous and discrete Lyapunov equations
+//!
+//! Both equations are reduced to the Sylvester equation with the upper
+//! (quasi-)triangular Schur factor of `A`, which is solved by `*trsyl`.
+//!
+//! - [Wikipedia article on Lyapunov equation](https://en.wikipedia.org/wiki/Lyapunov_equation)
+//! - R. H. Bartels and G. W. Stewart, "Solution of the matrix equation AX + XB = C",
+//!   Comm. ACM 15 (1972)
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::schur::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Solve the continuous Lyapunov equation `A X + X A^H = Q`
+///
+/// `A = Z T Z^H` is reduced to the Schur form, and `T Y + Y T^H = Z^H Q Z`
+/// is solved by `*trsyl` for `X = Z Y Z^H`. If `Q` is Hermitian, the result
+/// is made exactly Hermitian by `(X + X^H) / 2`.
+///
+/// Errors
+/// -------
+/// - [LinalgError::NotSquare] if `A` is not square
+/// - [LinalgError::NotStandardShape] if `Q` has a different shape from `A`
+/// - [LinalgError::Lapack] if `A` and `-A^H` have common or very close
+///   eigenvalues, i.e. the solution is not unique
+pub fn solve_lyapunov<A, S1, S2>(
+    a: &ArrayBase<S1, Ix2>,
+    q: &ArrayBase<S2, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    check_rhs(a, q)?;
+    let x = lyapunov_schur(a, q)?;
+    Ok(if is_hermitian(q) {
+        hermitian_part(x)
+    } else {
+        x
+    })
+}
+
+/// Solve the discrete Lyapunov equation `A X A^H - X = -Q`
+///
+/// This is converted into the continuous equation `B X + X B^H = C` by the
+/// bilinear transformation
+///
+/// ```text
+/// B = (A + I)^{-1} (A - I)
+/// C = -2 (A + I)^{-1} Q (A^H + I)^{-1}
+/// ```
+///
+/// and solved as [solve_lyapunov]. If `Q` is Hermitian, the result is made
+/// exactly Hermitian by `(X + X^H) / 2`.
+///
+/// Errors
+/// -------
+/// - [LinalgError::NotSquare] if `A` is not square
+/// - [LinalgError::NotStandardShape] if `Q` has a different shape from `A`
+/// - [LinalgError::Lapack] if `A` has an eigenvalue `-1` where the bilinear
+///   transformation is not defined, or if `λ_i λ_j^* = 1` for eigenvalues of
+///   `A`, i.e. the solution is not unique
+pub fn solve_discrete_lyapunov<A, S1, S2>(
+    a: &ArrayBase<S1, Ix2>,
+    q: &ArrayBase<S2, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    check_rhs(a, q)?;
+    let n = a.nrows();
+    let eye: Array2<A> = Array2::eye(n);
+    let ah: Array2<A> = a.t().mapv(|x| x.conj());
+    let ahi_inv = (&ah + &eye).inv()?;
+    let ai_inv = (a + &eye).inv()?;
+    // B^H = (A^H - I) (A^H + I)^{-1}
+    let bh = (&ah - &eye).dot(&ahi_inv);
+    let b = bh.t().mapv(|x| x.conj());
+    let c = ai_inv
+        .dot(q)
+        .dot(&ahi_inv)
+        .mapv_into(|x| x.mul_real(A::real(-2.0)));
+    let x = lyapunov_schur(&b, &c)?;
+    Ok(if is_hermitian(q) {
+        hermitian_part(x)
+    } else {
+        x
+    })
+}
+
+fn check_rhs<A, S1, S2>(a: &ArrayBase<S1, Ix2>, q: &ArrayBase<S2, Ix2>) -> Result<()>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    if q.dim() != a.dim() {
+        return Err(LinalgError::NotStandardShape {
+            obj: "Right hand side",
+            rows: q.nrows() as i32,
+            cols: q.ncols() as i32,
+        });
+    }
+    Ok(())
+}
+
+/// Solve `A X + X A^H = Q` by the Bartels-Stewart algorithm
+fn lyapunov_schur<A, S1, S2>(a: &ArrayBase<S1, Ix2>, q: &ArrayBase<S2, Ix2>) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    let n = a.nrows();
+    let (z, t) = a.schur()?;
+    let zh = z.t().mapv(|x| x.conj());
+    let mut y = Array2::zeros((n, n).f());
+    y.assign(&zh.dot(q).dot(&z));
+    let scale = A::sylvester(
+        Transpose::No,
+        Transpose::Hermite,
+        1,
+        n as i32,
+        t.as_slice_memory_order().unwrap(),
+        t.as_slice_memory_order().unwrap(),
+        y.as_slice_memory_order_mut().unwrap(),
+    )?;
+    y.mapv_inplace(|x| x.div_real(scale));
+    Ok(z.dot(&y).dot(&zh))
+}
+
+fn is_hermitian<A: Scalar, S: Data<Elem = A>>(q: &ArrayBase<S, Ix2>) -> bool {
+    q.indexed_iter().all(|((i, j), &x)| x == q[(j, i)].conj())
+}
+
+fn hermitian_part<A: Scalar>(x: Array2<A>) -> Array2<A> {
+    let half = A::real(0.5);
+    let xh = x.t().mapv(|x| x.conj());
+    (x + xh).mapv_into(|x| x.mul_real(half))
+}
@@ -0,0 +1,33 @@
+//! Conversion between ndarray and [nalgebra](https://nalgebra.org) matrices
+//!
+//! This module is available with the `nalgebra` feature.
+//!
+//! nalgebra matrices are always stored in column-major order, while
+//! ndarray arrays may have any layout. The conversions keep the logical
+//! element `(i, j)` and choose the memory order of the destination.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[1.0, 2.0], [3.0, 4.0]];
+//! let m = to_nalgebra(&a.view());
+//! assert_eq!(m[(0, 1)], 2.0);
+//! assert_eq!(from_nalgebra(&m), a);
+//! ```
+
+use nalgebra::DMatrix;
+use ndarray::*;
+
+/// Convert a nalgebra matrix into an array in Fortran (column-major) layout
+pub fn from_nalgebra<A: nalgebra::Scalar>(m: &DMatrix<A>) -> Array2<A> {
+    Array2::from_shape_vec(m.shape().f(), m.as_slice().to_vec())
+        .expect("nalgebra matrix is contiguous in column-major order")
+}
+
+/// Convert an array of any layout into a nalgebra matrix
+pub fn to_nalgebra<A: nalgebra::Scalar>(a: &ArrayView2<A>) -> DMatrix<A> {
+    let (n, m) = a.dim();
+    // Transposed view iterates the elements in column-major order
+    DMatrix::from_iterator(n, m, a.t().iter().cloned())
+}
@@ -3,6 +3,8 @@
 use ndarray::*;
 use num_traits::Zero;
 
+use super::error::*;
+use super::opnorm::{OperationNorm, SpectralNorm};
 use super::types::*;
 
 /// Define norm as a metric linear space (not as a matrix)
@@ -20,6 +22,27 @@ pub trait Norm {
     fn norm_l2(&self) -> Self::Output;
     /// maximum norm
     fn norm_max(&self) -> Self::Output;
+    /// L-p norm `(Σ |x_i|^p)^{1/p}` for `p > 0` including `p = ∞`
+    ///
+    /// This is the entrywise norm for matrices. The default implementation
+    /// supports only `p = 1, 2, ∞` by the above methods. The implementation
+    /// for arrays supports any positive `p`, and scales the elements by the
+    /// maximum absolute value before taking the `p`-th powers to avoid
+    /// overflow and underflow for large `p`.
+    ///
+    /// Returns [LinalgError::UnsupportedNormOrder] if `p` is not positive,
+    /// or not supported by the implementation.
+    fn norm_p(&self, p: f64) -> Result<Self::Output> {
+        if p == 1.0 {
+            Ok(self.norm_l1())
+        } else if p == 2.0 {
+            Ok(self.norm_l2())
+        } else if p == f64::INFINITY {
+            Ok(self.norm_max())
+        } else {
+            Err(LinalgError::UnsupportedNormOrder { p })
+        }
+    }
 }
 
 impl<A, S, D> Norm for ArrayBase<S, D>
@@ -45,6 +68,54 @@ where
             }
         })
     }
+    fn norm_p(&self, p: f64) -> Result<Self::Output> {
+        if p.is_nan() || p <= 0.0 {
+            return Err(LinalgError::UnsupportedNormOrder { p });
+        }
+        if p == 1.0 {
+            return Ok(self.norm_l1());
+        }
+        let max = self.norm_max();
+        if p.is_infinite() || max.is_zero() {
+            return Ok(max);
+        }
+        let sum: A::Real = self
+            .iter()
+            .map(|x| num_traits::Float::powf(x.abs() / max, A::Real::real(p)))
+            .sum();
+        Ok(max * num_traits::Float::powf(sum, A::Real::real(1.0 / p)))
+    }
+}
+
+/// Induced matrix norm `max_{x ≠ 0} |Ax|_p / |x|_p`
+///
+/// This is supported only for `p = 1, 2, ∞`, for which it is the maximum
+/// column sum, the largest singular value, and the maximum row sum,
+/// respectively. See [OperationNorm] and [SpectralNorm] for the underlying
+/// implementations.
+pub trait InducedNorm {
+    type Output;
+    /// Returns [LinalgError::UnsupportedNormOrder] if `p` is not `1`, `2` or `∞`.
+    fn norm_induced(&self, p: f64) -> Result<Self::Output>;
+}
+
+impl<A, S> InducedNorm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = A::Real;
+    fn norm_induced(&self, p: f64) -> Result<Self::Output> {
+        if p == 1.0 {
+            self.opnorm_one()
+        } else if p == 2.0 {
+            self.opnorm_spectral()
+        } else if p == f64::INFINITY {
+            self.opnorm_inf()
+        } else {
+            Err(LinalgError::UnsupportedNormOrder { p })
+        }
+    }
 }
 
 pub enum NormalizeAxis {
@@ -0,0 +1,127 @@
+//! Estimate of the 1-norm of a matrix only accessible through products
+//!
+//! - N. J. Higham and F. Tisseur, "A Block Algorithm for Matrix 1-Norm
+//!   Estimation, with an Application to 1-Norm Pseudospectra",
+//!   SIAM J. Matrix Anal. Appl. 21 (2000)
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::types::*;
+
+/// Maximum number of iterations of the block algorithm
+const ITER_MAX: usize = 5;
+
+/// Estimate `|A|_1` of an `n x n` matrix `A` with a block of `t` columns
+///
+/// - `a` applies `A` to a `(n, t)` matrix
+/// - `ah` applies `A^H` to a `(n, t)` matrix
+///
+/// The estimate is a lower bound of `|A|_1`, which is usually exact or
+/// within a factor of 3. Larger `t` gives a better estimate with more
+/// products. The starting block is generated from a fixed seed, so the
+/// estimate is reproducible.
+///
+/// This is useful for the condition estimation of an operator which is only
+/// accessible through products, e.g. `|A^{-1}|_1` using a factorization of
+/// `A` without forming the inverse.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, -2.0], [3.0, 4.0]];
+/// let ah = a.t().to_owned();
+/// let est: f64 = normest1(2, 2, |x| a.dot(&x), |x| ah.dot(&x));
+/// assert!((est - 6.0).abs() < 1e-12);
+/// ```
+pub fn normest1<A, F, G>(n: usize, t: usize, a: F, ah: G) -> A::Real
+where
+    A: Scalar,
+    F: Fn(ArrayView2<A>) -> Array2<A>,
+    G: Fn(ArrayView2<A>) -> Array2<A>,
+{
+    let t = t.clamp(1, n.max(1));
+    if n == 0 {
+        return A::Real::zero();
+    }
+    let scale = A::Real::real(n).recip();
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+    let mut x = Array2::from_shape_fn((n, t), |(_, j)| {
+        if j == 0 || rng.gen::<bool>() {
+            A::from_real(scale)
+        } else {
+            A::from_real(-scale)
+        }
+    });
+
+    let mut est_old = A::Real::zero();
+    let mut ind_best = 0;
+    // Indices of the unit vectors in the current block
+    let mut x_ind: Vec<usize> = Vec::new();
+    let mut visited = vec![false; n];
+    for k in 0..ITER_MAX {
+        let y = a(x.view());
+        let (best, est) = max_column_norm(&y);
+        if k > 0 && est <= est_old {
+            return est_old;
+        }
+        est_old = est;
+        if k > 0 {
+            ind_best = x_ind[best];
+        }
+        let s = y.mapv(sign);
+        let z = ah(s.view());
+        let h: Vec<A::Real> = z
+            .outer_iter()
+            .map(|row| {
+                row.iter()
+                    .fold(A::Real::zero(), |m, z| Float::max(m, z.abs()))
+            })
+            .collect();
+        let h_max = h.iter().cloned().fold(A::Real::zero(), Float::max);
+        if k > 0 && h_max == h[ind_best] {
+            return est;
+        }
+        let mut ind: Vec<usize> = (0..n).filter(|&i| !visited[i]).collect();
+        if ind.is_empty() {
+            return est;
+        }
+        ind.sort_by(|&i, &j| h[j].partial_cmp(&h[i]).unwrap());
+        x_ind = ind.into_iter().take(t).collect();
+        x = Array2::zeros((n, x_ind.len()));
+        for (col, &i) in x_ind.iter().enumerate() {
+            x[(i, col)] = A::one();
+            visited[i] = true;
+        }
+    }
+    est_old
+}
+
+/// Index and value of the maximum 1-norm of the columns
+fn max_column_norm<A: Scalar>(y: &Array2<A>) -> (usize, A::Real) {
+    y.axis_iter(Axis(1))
+        .map(|col| col.iter().map(|y| y.abs()).sum::<A::Real>())
+        .enumerate()
+        .fold(
+            (0, A::Real::zero()),
+            |(i, m), (j, v)| {
+                if v > m {
+                    (j, v)
+                } else {
+                    (i, m)
+                }
+            },
+        )
+}
+
+/// `x / |x|`, and one for zero
+fn sign<A: Scalar>(x: A) -> A {
+    let abs = x.abs();
+    if abs == A::Real::zero() {
+        A::one()
+    } else {
+        x.div_real(abs)
+    }
+}
@@ -1,6 +1,6 @@
 //! Linear operator algebra
 
-use crate::generate::hstack;
+use crate::convert::hstack;
 use crate::types::*;
 use ndarray::*;
 
@@ -2,9 +2,11 @@
 
 use lax::Tridiagonal;
 use ndarray::*;
+use num_traits::Zero;
 
 use crate::error::*;
 use crate::layout::*;
+use crate::svd::SVD;
 use crate::types::*;
 
 pub use lax::NormType;
@@ -34,6 +36,15 @@ pub trait OperationNorm {
     }
 }
 
+/// Spectral norm (largest singular value) in addition to [OperationNorm]
+///
+/// This is not supported by `*lange`, and thus not a variant of [NormType],
+/// but computed by the SVD without singular vectors.
+pub trait SpectralNorm: OperationNorm {
+    /// the spectral norm of a matrix (largest singular value)
+    fn opnorm_spectral(&self) -> Result<Self::Output>;
+}
+
 impl<A, S> OperationNorm for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -48,6 +59,20 @@ where
     }
 }
 
+impl<A, S> SpectralNorm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn opnorm_spectral(&self) -> Result<Self::Output> {
+        if self.is_empty() {
+            return Ok(A::Real::zero());
+        }
+        let (_, s, _) = self.svd(false, false)?;
+        Ok(s[0])
+    }
+}
+
 impl<A> OperationNorm for Tridiagonal<A>
 where
     A: Scalar + Lapack,
@@ -101,3 +126,24 @@ where
         Ok(A::opnorm(t, l, a))
     }
 }
+
+impl<A> SpectralNorm for Tridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn opnorm_spectral(&self) -> Result<Self::Output> {
+        let n = self.d.len();
+        if n == 0 {
+            return Ok(A::Real::zero());
+        }
+        let mut a = Array2::<A>::zeros((n, n));
+        a.diag_mut().assign(&aview1(&self.d));
+        a.slice_mut(s![1.., ..])
+            .diag_mut()
+            .assign(&aview1(&self.dl));
+        a.slice_mut(s![.., 1..])
+            .diag_mut()
+            .assign(&aview1(&self.du));
+        a.opnorm_spectral()
+    }
+}
@@ -0,0 +1,73 @@
+//! Integer and real powers of square matrices
+
+use ndarray::*;
+use num_traits::{Float, ToPrimitive, Zero};
+
+use crate::error::*;
+use crate::expm::expm;
+use crate::layout::*;
+use crate::logm::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Matrix power `A^n`
+pub trait Pow<A: Scalar> {
+    /// Compute `A^n` for an integer `n` by repeated squaring
+    ///
+    /// `A^0` is the identity matrix, and `A^{-n}` is computed as
+    /// `(A^{-1})^n` using [Inverse::inv].
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NotSquare] if `A` is not square
+    /// - LAPACK error if `n` is negative and `A` is singular
+    fn powi(&self, n: i32) -> Result<Array2<A>>;
+
+    /// Compute the principal power `A^p` for a real exponent `p`
+    ///
+    /// This is evaluated as `exp(p log(A))`, where the principal logarithm
+    /// is computed on the complex Schur form by [LogM]. Integer exponents
+    /// are dispatched to [powi](Pow::powi). Since a real power of a real
+    /// matrix with negative eigenvalues is not real, the result is always a
+    /// complex matrix.
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NoLogarithm] if `p` is not an integer and `A` is singular
+    fn powf(&self, p: A::Real) -> Result<Array2<A::Complex>>;
+}
+
+impl<A, S> Pow<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    fn powi(&self, n: i32) -> Result<Array2<A>> {
+        self.ensure_square()?;
+        let mut base = if n < 0 { self.inv()? } else { self.to_owned() };
+        let mut e = n.unsigned_abs();
+        let mut result = Array2::eye(self.nrows());
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.dot(&base);
+            }
+            e >>= 1;
+            if e > 0 {
+                base = base.dot(&base);
+            }
+        }
+        Ok(result)
+    }
+
+    fn powf(&self, p: A::Real) -> Result<Array2<A::Complex>> {
+        if Float::fract(p).is_zero() {
+            if let Some(n) = p.to_i32() {
+                return Ok(self.powi(n)?.mapv(|x| x.as_c()));
+            }
+        }
+        let log = self.logm()?;
+        let p = A::Complex::from_real(p);
+        expm(&log.mapv(|x| x * p))
+    }
+}
@@ -3,15 +3,18 @@
 //! [Wikipedia article on QR decomposition](https://en.wikipedia.org/wiki/QR_decomposition)
 
 use ndarray::*;
-use num_traits::Zero;
+use num_traits::{Float, ToPrimitive, Zero};
 
 use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
+use crate::norm::Norm;
+use crate::solve::Transpose;
 use crate::triangular::*;
 use crate::types::*;
+use crate::validate::check_input;
 
-pub use lax::UPLO;
+pub use lax::{Side, UPLO};
 
 /// QR decomposition for matrix reference
 ///
@@ -60,6 +63,7 @@ where
     type R = Array2<A>;
 
     fn qr_square_inplace(&mut self) -> Result<(&mut Self, Self::R)> {
+        check_input(self)?;
         let l = self.square_layout()?;
         let r = A::qr(l, self.as_allocated_mut()?)?;
         let r: Array2<_> = into_matrix(l, r)?;
@@ -106,6 +110,7 @@ where
         let n = self.nrows();
         let m = self.ncols();
         let k = ::std::cmp::min(n, m);
+        check_input(&self)?;
         let l = self.layout()?;
         let r = A::qr(l, self.as_allocated_mut()?)?;
         let r: Array2<_> = into_matrix(l, r)?;
@@ -128,6 +133,534 @@ where
     }
 }
 
+/// QR decomposition with column pivoting `A P = Q R`
+///
+/// The columns are permuted so that the magnitudes of the diagonal elements
+/// of `R` are non-increasing, which reveals the numerical rank of the matrix.
+pub trait QRPivot {
+    type Q;
+    type R;
+    type Real;
+
+    /// Returns `(Q, R, perm)` such that `A.select(Axis(1), &perm) = Q R`,
+    /// i.e. the `j`-th column of `A P` is the `perm[j]`-th column of `A`
+    fn qr_pivot(&self) -> Result<(Self::Q, Self::R, Vec<usize>)>;
+
+    /// Numerical rank as the number of diagonal elements of `R`
+    /// satisfying `|R[(i, i)]| > tol * |R[(0, 0)]|`
+    fn rank(&self, tol: Self::Real) -> Result<usize>;
+}
+
+impl<A, S> QRPivot for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type R = Array2<A>;
+    type Real = A::Real;
+
+    fn qr_pivot(&self) -> Result<(Self::Q, Self::R, Vec<usize>)> {
+        let (n, m) = self.dim();
+        let k = ::std::cmp::min(n, m);
+        let (mut a, tau, perm) = householder_pivot(self)?;
+        let r = take_slice_upper(&a, k, m);
+        let l = a.layout()?;
+        A::q(l, a.as_allocated_mut()?, &tau)?;
+        Ok((take_slice(&a, n, k), r, perm))
+    }
+
+    fn rank(&self, tol: A::Real) -> Result<usize> {
+        let (a, _, _) = householder_pivot(self)?;
+        let diag = a.diag();
+        let r0 = match diag.first() {
+            Some(r0) => r0.abs(),
+            None => return Ok(0),
+        };
+        Ok(diag.iter().take_while(|r| r.abs() > tol * r0).count())
+    }
+}
+
+/// Householder reflection with column pivoting on a Fortran-layout copy
+fn householder_pivot<A, S>(a: &ArrayBase<S, Ix2>) -> Result<(Array2<A>, Vec<A>, Vec<usize>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    check_input(a)?;
+    let mut af = Array2::zeros(a.dim().f());
+    af.assign(a);
+    let l = af.layout()?;
+    let (tau, jpvt) = A::householder_pivot(l, af.as_allocated_mut()?)?;
+    let perm = jpvt.into_iter().map(|j| j as usize - 1).collect();
+    Ok((af, tau, perm))
+}
+
+/// QR decomposition kept in the packed form of Householder reflectors
+///
+/// For a `(m, n)` matrix, `Q = H(1) H(2) ... H(k)` with `k = min(m, n)` is the
+/// product of Householder reflectors `H(i) = I - tau[i] v_i v_i^H`, where the
+/// vector `v_i` is stored below the diagonal of the `i`-th column of
+/// `reflectors` with an implicit unit `i`-th element. `R` is stored in the
+/// upper triangle. This avoids the `O(m^2)` storage of `Q` when it is only
+/// used as an operator through [PackedQR::apply_q].
+#[derive(Debug, Clone)]
+pub struct PackedQR<A> {
+    /// Reflectors and `R` in Fortran layout
+    pub reflectors: Array2<A>,
+    /// Scalar factors of the reflectors
+    pub tau: Array1<A>,
+}
+
+impl<A> PackedQR<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Overwrite `rhs` by `op(Q) * rhs` or `rhs * op(Q)` according to `side`
+    ///
+    /// `Q` is the full `(m, m)` unitary matrix, and thus `rhs` must have `m`
+    /// rows for [Side::Left] and `m` columns for [Side::Right].
+    /// [Transpose::Hermite] and [Transpose::Transpose] are the same for real matrices.
+    pub fn apply_q<S>(
+        &self,
+        side: Side,
+        trans: Transpose,
+        rhs: &mut ArrayBase<S, Ix2>,
+    ) -> Result<()>
+    where
+        S: DataMut<Elem = A> + DataOwned,
+    {
+        let m = self.reflectors.nrows();
+        let (rows, cols) = rhs.dim();
+        let size = match side {
+            Side::Left => rows,
+            Side::Right => cols,
+        };
+        if size != m {
+            return Err(LinalgError::NotStandardShape {
+                obj: "Operand of Q",
+                rows: rows as i32,
+                cols: cols as i32,
+            });
+        }
+        if matches!(rhs.layout()?, MatrixLayout::C { .. }) {
+            transpose_data(rhs)?;
+        }
+        let al = self.reflectors.layout()?;
+        let cl = rhs.layout()?;
+        A::apply_q(
+            al,
+            side,
+            trans,
+            self.reflectors.as_allocated()?,
+            self.tau.as_slice().unwrap(),
+            cl,
+            rhs.as_allocated_mut()?,
+        )?;
+        Ok(())
+    }
+
+    /// Upper triangular `(k, n)` matrix `R`
+    pub fn r(&self) -> Array2<A> {
+        let (m, n) = self.reflectors.dim();
+        take_slice_upper(&self.reflectors, m.min(n), n)
+    }
+}
+
+/// QR decomposition without constructing `Q`, see [PackedQR]
+pub trait QRRaw {
+    type Elem;
+    fn qr_raw(&self) -> Result<PackedQR<Self::Elem>>;
+}
+
+impl<A, S> QRRaw for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn qr_raw(&self) -> Result<PackedQR<A>> {
+        check_input(self)?;
+        let mut reflectors = Array2::zeros(self.dim().f());
+        reflectors.assign(self);
+        let l = reflectors.layout()?;
+        let tau = A::householder(l, reflectors.as_allocated_mut()?)?;
+        Ok(PackedQR {
+            reflectors,
+            tau: Array1::from(tau),
+        })
+    }
+}
+
+/// LQ decomposition `A = L * Q` for matrix reference
+///
+/// For a `(n, m)` matrix and `k = min(n, m)`, `L` is a `(n, k)` lower
+/// triangular matrix and `Q` is a `(k, m)` matrix with orthonormal rows.
+pub trait LQ {
+    type L;
+    type Q;
+    fn lq(&self) -> Result<(Self::L, Self::Q)>;
+}
+
+/// LQ decomposition `A = L * Q`, see [LQ]
+pub trait LQInto: Sized {
+    type L;
+    type Q;
+    fn lq_into(self) -> Result<(Self::L, Self::Q)>;
+}
+
+/// LQ decomposition of square matrix, overwriting it by `Q`
+pub trait LQInplace: Sized {
+    type L;
+    fn lq_inplace(&mut self) -> Result<(Self::L, &mut Self)>;
+}
+
+impl<A, S> LQInplace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type L = Array2<A>;
+
+    fn lq_inplace(&mut self) -> Result<(Self::L, &mut Self)> {
+        check_input(self)?;
+        let l = self.square_layout()?;
+        let r = A::lq(l, self.as_allocated_mut()?)?;
+        let r: Array2<_> = into_matrix(l, r)?;
+        Ok((r.into_triangular(UPLO::Lower), self))
+    }
+}
+
+impl<A, S> LQInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type L = Array2<A>;
+    type Q = Array2<A>;
+
+    fn lq_into(mut self) -> Result<(Self::L, Self::Q)> {
+        let n = self.nrows();
+        let m = self.ncols();
+        let k = ::std::cmp::min(n, m);
+        check_input(&self)?;
+        let l = self.layout()?;
+        let r = A::lq(l, self.as_allocated_mut()?)?;
+        let r: Array2<_> = into_matrix(l, r)?;
+        let lower = r.slice(s![.., ..k]).to_owned().into_triangular(UPLO::Lower);
+        Ok((lower, take_slice(&self, k, m)))
+    }
+}
+
+impl<A, S> LQ for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type L = Array2<A>;
+    type Q = Array2<A>;
+
+    fn lq(&self) -> Result<(Self::L, Self::Q)> {
+        let a = self.to_owned();
+        a.lq_into()
+    }
+}
+
+/// QL decomposition `A = Q * L` for matrix reference
+///
+/// For a `(n, m)` matrix and `k = min(n, m)`, `Q` is a `(n, k)` matrix with
+/// orthonormal columns and `L` is a `(k, m)` lower trapezoidal matrix, i.e.
+/// `L[(i, j)] = 0` for `j > i + m - k`.
+pub trait QL {
+    type Q;
+    type L;
+    fn ql(&self) -> Result<(Self::Q, Self::L)>;
+}
+
+/// QL decomposition `A = Q * L`, see [QL]
+pub trait QLInto: Sized {
+    type Q;
+    type L;
+    fn ql_into(self) -> Result<(Self::Q, Self::L)>;
+}
+
+/// QL decomposition of square matrix, overwriting it by `Q`
+pub trait QLInplace: Sized {
+    type L;
+    fn ql_inplace(&mut self) -> Result<(&mut Self, Self::L)>;
+}
+
+impl<A, S> QLInplace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type L = Array2<A>;
+
+    fn ql_inplace(&mut self) -> Result<(&mut Self, Self::L)> {
+        check_input(self)?;
+        let l = self.square_layout()?;
+        let r = A::ql(l, self.as_allocated_mut()?)?;
+        let r: Array2<_> = into_matrix(l, r)?;
+        Ok((self, r.into_triangular(UPLO::Lower)))
+    }
+}
+
+impl<A, S> QLInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Q = Array2<A>;
+    type L = Array2<A>;
+
+    fn ql_into(mut self) -> Result<(Self::Q, Self::L)> {
+        let n = self.nrows();
+        let m = self.ncols();
+        let k = ::std::cmp::min(n, m);
+        check_input(&self)?;
+        let l = self.layout()?;
+        let r = A::ql(l, self.as_allocated_mut()?)?;
+        let r: Array2<_> = into_matrix(l, r)?;
+        let mut lower = r.slice(s![n - k.., ..]).to_owned();
+        Zip::indexed(&mut lower).for_each(|(i, j), elt| {
+            if j > i + m - k {
+                *elt = A::zero()
+            }
+        });
+        Ok((self.slice(s![.., m - k..]).to_owned(), lower))
+    }
+}
+
+impl<A, S> QL for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type L = Array2<A>;
+
+    fn ql(&self) -> Result<(Self::Q, Self::L)> {
+        let a = self.to_owned();
+        a.ql_into()
+    }
+}
+
+/// Orthonormal basis of the orthogonal complement of the column space
+pub trait OrthonormalComplement {
+    type Output;
+
+    /// Returns a `(m, m - k)` matrix whose columns are orthonormal and
+    /// orthogonal to the columns of the `(m, k)` input matrix
+    ///
+    /// The input is completed to a full orthonormal basis by the full QR
+    /// decomposition, and the trailing `m - k` columns of `Q` are returned.
+    /// The input must have full column rank, but its columns need not be
+    /// orthonormal.
+    ///
+    /// Errors
+    /// -------
+    /// - if the input has more columns than rows
+    fn orthonormal_complement(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> OrthonormalComplement for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn orthonormal_complement(&self) -> Result<Self::Output> {
+        let (m, k) = self.dim();
+        if k > m {
+            return Err(LinalgError::NotStandardShape {
+                obj: "Orthonormal complement",
+                rows: m as i32,
+                cols: k as i32,
+            });
+        }
+        check_input(self)?;
+        // Pad with zero columns to compute the full `m x m` matrix Q
+        let mut q = Array2::zeros((m, m).f());
+        q.slice_mut(s![.., ..k]).assign(self);
+        let l = q.square_layout()?;
+        let tau = A::householder(l, q.as_allocated_mut()?)?;
+        A::q(l, q.as_allocated_mut()?, &tau)?;
+        Ok(q.slice_move(s![.., k..]))
+    }
+}
+
+/// Update the QR decomposition `A = Q R` for `A` with a row appended at the bottom
+///
+/// `q` and `r` are the `(m, k)` and `(k, n)` factors with `k = min(m, n)`,
+/// e.g. given by [QR::qr], and they are replaced by the factors of the
+/// `(m + 1, n)` matrix `[A; row^T]`. The new row is eliminated from
+/// `[R; row^T]` by `min(k, n)` Givens rotations in `O(n^2 + mn)`, without
+/// recomputing the decomposition. Only `R` is updated if `q` is `None`,
+/// which suffices e.g. for least squares problems using the normal
+/// equations `R^H R = A^H A`.
+///
+/// # Panics
+///
+/// Panics if the length of `row` is not `n` or the shapes of `q` and `r`
+/// are incompatible.
+pub fn qr_append_row<A, S>(q: Option<&mut Array2<A>>, r: &mut Array2<A>, row: &ArrayBase<S, Ix1>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (k, n) = r.dim();
+    assert_eq!(
+        row.len(),
+        n,
+        "The length of row must be the number of columns of R."
+    );
+    let mut r_ext = Array2::zeros((k + 1, n));
+    r_ext.slice_mut(s![..k, ..]).assign(r);
+    r_ext.row_mut(k).assign(row);
+    let mut q_ext = q.as_ref().map(|q| {
+        let m = q.nrows();
+        assert_eq!(
+            q.ncols(),
+            k,
+            "The number of columns of Q must be the rows of R."
+        );
+        let mut q_ext = Array2::zeros((m + 1, k + 1));
+        q_ext.slice_mut(s![..m, ..k]).assign(q);
+        q_ext[(m, k)] = A::one();
+        q_ext
+    });
+    for j in 0..k.min(n) {
+        let (c, sn) = match givens(r_ext[(j, j)], r_ext[(k, j)]) {
+            Some(g) => g,
+            None => continue,
+        };
+        for col in j..n {
+            let (x, y) = (r_ext[(j, col)], r_ext[(k, col)]);
+            r_ext[(j, col)] = x.mul_real(c) + sn * y;
+            r_ext[(k, col)] = y.mul_real(c) - sn.conj() * x;
+        }
+        r_ext[(k, j)] = A::zero();
+        if let Some(q_ext) = q_ext.as_mut() {
+            for mut qi in q_ext.outer_iter_mut() {
+                let (x, y) = (qi[j], qi[k]);
+                qi[j] = x.mul_real(c) + sn.conj() * y;
+                qi[k] = y.mul_real(c) - sn * x;
+            }
+        }
+    }
+    // The last row of R vanishes if the matrix was not wide
+    let k_new = if k < n { k + 1 } else { k };
+    *r = r_ext.slice_move(s![..k_new, ..]);
+    if let (Some(q), Some(q_ext)) = (q, q_ext) {
+        *q = q_ext.slice_move(s![.., ..k_new]);
+    }
+}
+
+/// Update the QR decomposition `A = Q R` for `A` with a column appended at the right
+///
+/// `q` and `r` are the `(m, k)` and `(k, n)` factors with `k = min(m, n)`,
+/// e.g. given by [QR::qr], and they are replaced by the factors of the
+/// `(m, n + 1)` matrix `[A, col]` in `O(mn)`. The new column of `R` is
+/// `Q^H col`, and a new column of `Q` is appended by the Gram-Schmidt
+/// process with reorthogonalization if `A` was tall. Unlike
+/// [qr_append_row], `Q` is required since `Q^H col` cannot be computed
+/// from `R` only.
+///
+/// # Panics
+///
+/// Panics if the length of `col` is not `m` or the shapes of `q` and `r`
+/// are incompatible.
+pub fn qr_append_column<A, S>(q: &mut Array2<A>, r: &mut Array2<A>, col: &ArrayBase<S, Ix1>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (m, k) = q.dim();
+    let n = r.ncols();
+    assert_eq!(
+        col.len(),
+        m,
+        "The length of col must be the number of rows of Q."
+    );
+    assert_eq!(
+        r.nrows(),
+        k,
+        "The number of columns of Q must be the rows of R."
+    );
+    let qh = q.t().mapv(|x| x.conj());
+    let mut w = qh.dot(col);
+    let mut v = col - &q.dot(&w);
+    let dw = qh.dot(&v);
+    v -= &q.dot(&dw);
+    w += &dw;
+
+    if k == m {
+        // Q is already square
+        let mut r_new = Array2::zeros((k, n + 1));
+        r_new.slice_mut(s![.., ..n]).assign(r);
+        r_new.column_mut(n).assign(&w);
+        *r = r_new;
+        return;
+    }
+
+    let mut rho = v.norm_l2();
+    if rho <= A::real(m) * A::Real::epsilon() * col.norm_l2() {
+        // `col` is numerically in the column space, and any unit vector
+        // orthogonal to it can be appended to Q
+        v = orthogonal_unit_vector(q);
+        rho = A::Real::zero();
+    } else {
+        v.mapv_inplace(|x| x.div_real(rho));
+    }
+    let mut q_new = Array2::zeros((m, k + 1));
+    q_new.slice_mut(s![.., ..k]).assign(q);
+    q_new.column_mut(k).assign(&v);
+    let mut r_new = Array2::zeros((k + 1, n + 1));
+    r_new.slice_mut(s![..k, ..n]).assign(r);
+    r_new.slice_mut(s![..k, n]).assign(&w);
+    r_new[(k, n)] = A::from_real(rho);
+    *q = q_new;
+    *r = r_new;
+}
+
+/// Complex Givens rotation `[[c, s], [-conj(s), c]]` which eliminates `b` in `(a, b)`
+fn givens<A: Scalar>(a: A, b: A) -> Option<(A::Real, A)> {
+    if b.is_zero() {
+        return None;
+    }
+    if a.is_zero() {
+        return Some((A::Real::zero(), A::one()));
+    }
+    let abs_a = a.abs();
+    let r = Float::hypot(abs_a, b.abs());
+    Some((abs_a / r, a.div_real(abs_a) * b.conj().div_real(r)))
+}
+
+/// A unit vector orthogonal to the orthonormal columns of a tall `q`
+fn orthogonal_unit_vector<A: Scalar + Lapack>(q: &Array2<A>) -> Array1<A> {
+    let m = q.nrows();
+    let qh = q.t().mapv(|x| x.conj());
+    // The projection of some unit vector `e_i` onto the complement has norm
+    // at least `sqrt((m - k) / m)`
+    (0..m)
+        .map(|i| {
+            let mut v = Array1::zeros(m);
+            v[i] = A::one();
+            for _ in 0..2 {
+                let p = q.dot(&qh.dot(&v));
+                v -= &p;
+            }
+            v
+        })
+        .map(|v| (v.norm_l2(), v))
+        .max_by(|(x, _), (y, _)| x.to_f64().unwrap().total_cmp(&y.to_f64().unwrap()))
+        .map(|(norm, v)| v.mapv(|x| x.div_real(norm)))
+        .unwrap()
+}
+
 fn take_slice<A, S1, S2>(a: &ArrayBase<S1, Ix2>, n: usize, m: usize) -> ArrayBase<S2, Ix2>
 where
     A: Copy,
@@ -0,0 +1,66 @@
+//! Ridge regression
+//!
+//! [Wikipedia article on ridge regression](https://en.wikipedia.org/wiki/Ridge_regression)
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// Leave-one-out cross-validation error of ridge regression
+///
+/// For the `(n, p)` design matrix `X`, the targets `y` and each
+/// regularization strength `λ` in `lambdas`, the ridge estimator minimizes
+/// `|y - X β|^2 + λ |β|^2`. No intercept is fitted, so center `X` and `y`
+/// beforehand if one is needed.
+///
+/// The leave-one-out error is computed without refitting by the PRESS
+/// statistic
+///
+/// ```text
+/// LOOCV(λ) = 1/n Σ_i ((y_i - ŷ_i) / (1 - H_ii))^2
+/// ```
+///
+/// where `ŷ = H y` for the hat matrix `H = X (X^T X + λ I)^{-1} X^T`.
+/// Using a single thin SVD `X = U Σ V^T`, the hat matrix is
+/// `H = U diag(σ_j^2 / (σ_j^2 + λ)) U^T`, and every `λ` costs only
+/// `O(n min(n, p))` operations.
+///
+/// # Panics
+///
+/// Panics if the length of `y` differs from the number of rows of `x`.
+pub fn ridge_loocv<A>(x: &ArrayView2<A>, y: &ArrayView1<A>, lambdas: &[A]) -> Result<Array1<A>>
+where
+    A: Scalar<Real = A> + Lapack,
+{
+    let (n, p) = x.dim();
+    assert_eq!(
+        y.len(),
+        n,
+        "The length of y must be equal to the number of rows of x."
+    );
+    let (u, s, _) = x.svd(true, false)?;
+    let u = u.unwrap();
+    let u = u.slice(s![.., ..n.min(p)]);
+    let uty = u.t().dot(y);
+    let u2 = u.mapv(|u| u * u);
+
+    let loocv = lambdas
+        .iter()
+        .map(|&lambda| {
+            let shrink = s.mapv(|s| s * s / (s * s + lambda));
+            let y_hat = u.dot(&(&shrink * &uty));
+            let h = u2.dot(&shrink);
+            let press = Zip::from(y)
+                .and(&y_hat)
+                .and(&h)
+                .fold(A::zero(), |acc, &y, &y_hat, &h| {
+                    let e = (y - y_hat) / (A::one() - h);
+                    acc + e * e
+                });
+            press / A::real(n)
+        })
+        .collect();
+    Ok(loocv)
+}
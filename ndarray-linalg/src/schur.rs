@@ -0,0 +1,128 @@
+//! Schur decomposition of general square matrices
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use crate::validate::check_input;
+use ndarray::*;
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Schur decomposition $ A = Q T Q^\dagger $ of a general square matrix
+pub trait Schur {
+    type Output;
+    type ComplexOutput;
+
+    /// Compute the Schur decomposition and returns `(Q, T)`
+    ///
+    /// $Q$ is unitary (orthogonal for real matrices). For complex matrices
+    /// $T$ is upper triangular with the eigenvalues on its diagonal. For real
+    /// matrices this is the real Schur form, i.e. $T$ is upper
+    /// quasi-triangular whose 2x2 diagonal blocks correspond to pairs of
+    /// complex conjugate eigenvalues.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[0.0, -1.0], [1.0, 0.0]];
+    /// let (q, t) = a.schur().unwrap();
+    /// assert_close_l2!(&q.dot(&t).dot(&q.t()), &a, 1e-12);
+    /// ```
+    fn schur(&self) -> Result<(Self::Output, Self::Output)>;
+
+    /// Compute the complex Schur decomposition and returns `(Q, T)`
+    ///
+    /// $T$ is always upper triangular with the eigenvalues on its diagonal,
+    /// and thus both $Q$ and $T$ are complex even for real matrices.
+    fn schur_complex(&self) -> Result<(Self::ComplexOutput, Self::ComplexOutput)>;
+}
+
+impl<A, S> Schur for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+    type ComplexOutput = Array2<A::Complex>;
+
+    fn schur(&self) -> Result<(Self::Output, Self::Output)> {
+        self.ensure_square()?;
+        check_input(self)?;
+        let n = self.nrows();
+        let mut t: Array2<A> = Array2::zeros((n, n).f());
+        t.assign(self);
+        let (_, q) = A::schur(t.square_layout()?, t.as_allocated_mut()?)?;
+        Ok((Array2::from_shape_vec((n, n).f(), q)?, t))
+    }
+
+    fn schur_complex(&self) -> Result<(Self::ComplexOutput, Self::ComplexOutput)> {
+        self.ensure_square()?;
+        check_input(self)?;
+        let n = self.nrows();
+        let mut t: Array2<A::Complex> = Array2::zeros((n, n).f());
+        t.zip_mut_with(self, |t, a| *t = a.as_c());
+        let (_, q) = A::Complex::schur(t.square_layout()?, t.as_allocated_mut()?)?;
+        Ok((Array2::from_shape_vec((n, n).f(), q)?, t))
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized Schur (QZ) decomposition of a square matrix pencil $(A, B)$
+pub trait QZ {
+    type Output;
+
+    /// Compute $ A = Q S Z^\dagger, B = Q T Z^\dagger $ and returns `(S, T, Q, Z)`
+    ///
+    /// $Q$ and $Z$ are unitary (orthogonal for real matrices) and span the
+    /// left and right deflating subspaces. $T$ is upper triangular, and $S$ is
+    /// upper triangular for complex matrices and upper quasi-triangular
+    /// with 2x2 diagonal blocks for complex conjugate eigenvalue pairs for
+    /// real matrices. The generalized eigenvalues are `S[i, i] / T[i, i]` for
+    /// the 1x1 diagonal blocks.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[1.0, 2.0], [3.0, 4.0]];
+    /// let b: Array2<f64> = array![[2.0, 0.0], [1.0, 1.0]];
+    /// let (s, t, q, z) = (a.view(), b.view()).qz().unwrap();
+    /// assert_close_l2!(&q.dot(&s).dot(&z.t()), &a, 1e-12);
+    /// assert_close_l2!(&q.dot(&t).dot(&z.t()), &b, 1e-12);
+    /// ```
+    fn qz(&self) -> Result<(Self::Output, Self::Output, Self::Output, Self::Output)>;
+}
+
+impl<A, S, S2> QZ for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn qz(&self) -> Result<(Self::Output, Self::Output, Self::Output, Self::Output)> {
+        let (a, b) = self;
+        a.ensure_square()?;
+        if a.dim() != b.dim() {
+            return Err(LinalgError::NotStandardShape {
+                obj: "Matrix pencil",
+                rows: b.nrows() as i32,
+                cols: b.ncols() as i32,
+            });
+        }
+        check_input(a)?;
+        check_input(b)?;
+        let n = a.nrows();
+        let mut s: Array2<A> = Array2::zeros((n, n).f());
+        let mut t: Array2<A> = Array2::zeros((n, n).f());
+        s.assign(a);
+        t.assign(b);
+        let l = s.square_layout()?;
+        let (q, z) = A::qz(l, s.as_allocated_mut()?, t.as_allocated_mut()?)?;
+        let q = Array2::from_shape_vec((n, n).f(), q)?;
+        let z = Array2::from_shape_vec((n, n).f(), z)?;
+        Ok((s, t, q, z))
+    }
+}
@@ -42,8 +42,10 @@ use num_traits::{Float, Zero};
 use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
+use crate::norm::Norm;
 use crate::opnorm::OperationNorm;
 use crate::types::*;
+use crate::validate::check_input;
 
 pub use lax::{Pivot, Transpose};
 
@@ -64,15 +66,18 @@ pub use lax::{Pivot, Transpose};
 /// If you plan to solve many equations with the same `A` matrix but different
 /// `b` vectors, it's faster to factor the `A` matrix once using the
 /// `Factorize` trait, and then solve using the `LUFactorized` struct.
-pub trait Solve<A: Scalar> {
+///
+/// `b` is a vector for `D = Ix1`, or a matrix whose columns are the right hand
+/// sides for `D = Ix2`, which are solved by a single `*getrs` call.
+pub trait Solve<A: Scalar, D: Dimension = Ix1> {
     /// Solves a system of linear equations `A * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
-    /// of `A`.
-    fn solve<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// columns of `A`.
+    fn solve<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solve_inplace(&mut b)?;
         Ok(b)
@@ -83,12 +88,9 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
-    /// of `A`.
-    fn solve_into<S: DataMut<Elem = A>>(
-        &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// columns of `A`.
+    fn solve_into<S: DataMut<Elem = A>>(&self, mut b: ArrayBase<S, D>) -> Result<ArrayBase<S, D>> {
         self.solve_inplace(&mut b)?;
         Ok(b)
     }
@@ -98,21 +100,21 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
-    /// of `A`.
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// columns of `A`.
     fn solve_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
 
     /// Solves a system of linear equations `A^T * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
-    fn solve_t<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// rows of `A`.
+    fn solve_t<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solve_t_inplace(&mut b)?;
         Ok(b)
@@ -123,12 +125,12 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// rows of `A`.
     fn solve_t_into<S: DataMut<Elem = A>>(
         &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+        mut b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>> {
         self.solve_t_inplace(&mut b)?;
         Ok(b)
     }
@@ -138,21 +140,21 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// rows of `A`.
     fn solve_t_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
 
     /// Solves a system of linear equations `A^H * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
-    fn solve_h<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// rows of `A`.
+    fn solve_h<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solve_h_inplace(&mut b)?;
         Ok(b)
@@ -162,12 +164,12 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// rows of `A`.
     fn solve_h_into<S: DataMut<Elem = A>>(
         &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+        mut b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>> {
         self.solve_h_inplace(&mut b)?;
         Ok(b)
     }
@@ -176,12 +178,59 @@ pub trait Solve<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of rows of
-    /// `A`.
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// rows of `A`.
     fn solve_h_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+
+    /// Solves a system of linear equations `x * A = b` where `A` is `self`, `b`
+    /// is the argument, and `x` is the successful result.
+    ///
+    /// This is equivalent to `A^T * x^T = b^T`, i.e. the rows of `b` are the
+    /// right hand sides. For a vector `b`, this is the same as [Solve::solve_t].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns of `b` is not the equal to the number of
+    /// rows of `A`.
+    fn solve_right<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
+        let mut b = replicate(b);
+        self.solve_right_inplace(&mut b)?;
+        Ok(b)
+    }
+
+    /// Solves a system of linear equations `x * A = b` where `A` is `self`, `b`
+    /// is the argument, and `x` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns of `b` is not the equal to the number of
+    /// rows of `A`.
+    fn solve_right_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>> {
+        self.solve_right_inplace(&mut b)?;
+        Ok(b)
+    }
+
+    /// Solves a system of linear equations `x * A = b` where `A` is `self`, `b`
+    /// is the argument, and `x` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns of `b` is not the equal to the number of
+    /// rows of `A`.
+    fn solve_right_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>> {
+        // A row-major `b` is passed to LAPACK as the column-major `b^T` without copy
+        self.solve_t_inplace(&mut b.view_mut().reversed_axes())?;
+        Ok(b)
+    }
 }
 
 /// Represents the LU factorization of a matrix `A` as `A = P*L*U`.
@@ -301,6 +350,152 @@ where
     }
 }
 
+impl<A, S> LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    /// Solve `op(A) X = B` for the columns of `rhs` at once
+    fn solve_columns<Sb>(&self, t: Transpose, rhs: &mut ArrayBase<Sb, Ix2>) -> Result<()>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let n = match t {
+            Transpose::No => self.a.len_of(Axis(1)),
+            _ => self.a.len_of(Axis(0)),
+        };
+        assert_eq!(
+            rhs.nrows(),
+            n,
+            "The number of rows of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        let l = self.a.square_layout()?;
+        let a = self.a.as_allocated()?;
+        with_fortran(rhs, |b| Ok(A::solve(l, t, a, &self.ipiv, b)?))
+    }
+}
+
+impl<A, S> Solve<A, Ix2> for LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn solve_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_columns(Transpose::No, rhs)?;
+        Ok(rhs)
+    }
+    fn solve_t_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_columns(Transpose::Transpose, rhs)?;
+        Ok(rhs)
+    }
+    fn solve_h_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_columns(Transpose::Hermite, rhs)?;
+        Ok(rhs)
+    }
+}
+
+impl<A, S> Solve<A, Ix2> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize()?;
+        f.solve_inplace(rhs)
+    }
+    fn solve_t_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize()?;
+        f.solve_t_inplace(rhs)
+    }
+    fn solve_h_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize()?;
+        f.solve_h_inplace(rhs)
+    }
+}
+
+/// Solve a linear system, and compute the residual with the original matrix
+///
+/// This is implemented only for the matrix itself, since the factors in
+/// [LUFactorized] have overwritten the original matrix.
+pub trait SolveResidual<A: Scalar, D: Dimension = Ix1> {
+    /// Solves `A * x = b`, and returns `x` with the residual `b - A * x`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `b` is not the equal to the number of
+    /// columns of `A`.
+    fn solve_with_residual<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, D>,
+    ) -> Result<(Array<A, D>, Array<A, D>)>;
+}
+
+impl<A, S> SolveResidual<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_with_residual<Sb: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<(Array1<A>, Array1<A>)> {
+        let x = self.solve(b)?;
+        let residual = b - &self.dot(&x);
+        Ok((x, residual))
+    }
+}
+
+impl<A, S> SolveResidual<A, Ix2> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_with_residual<Sb: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<Sb, Ix2>,
+    ) -> Result<(Array2<A>, Array2<A>)> {
+        let x = self.solve(b)?;
+        let residual = b - &self.dot(&x);
+        Ok((x, residual))
+    }
+}
+
 /// An interface for computing LU factorizations of matrix refs.
 pub trait Factorize<S: Data + RawDataClone> {
     /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
@@ -321,6 +516,7 @@ where
     S: DataMut<Elem = A> + RawDataClone,
 {
     fn factorize_into(mut self) -> Result<LUFactorized<S>> {
+        check_input(&self)?;
         let ipiv = A::lu(self.layout()?, self.as_allocated_mut()?)?;
         Ok(LUFactorized { a: self, ipiv })
     }
@@ -332,6 +528,7 @@ where
     Si: Data<Elem = A>,
 {
     fn factorize(&self) -> Result<LUFactorized<OwnedRepr<A>>> {
+        check_input(self)?;
         let mut a: Array2<A> = replicate(self);
         let ipiv = A::lu(a.layout()?, a.as_allocated_mut()?)?;
         Ok(LUFactorized { a, ipiv })
@@ -631,3 +828,182 @@ where
         self.factorize_into()?.rcond_into()
     }
 }
+
+/// Solve linear equations and invert for orthogonal/unitary matrices
+///
+/// Since `Q^{-1} = Q^H` for an orthogonal/unitary matrix `Q`, these methods
+/// need no factorization and cost only `O(n^2)` for solving.
+///
+/// The orthogonality is not checked if `rtol` is `None`. Otherwise, it is
+/// checked by [is_orthogonal](SolveOrthogonal::is_orthogonal) with the
+/// given tolerance, and `LinalgError::NotOrthogonal` is returned if it fails.
+pub trait SolveOrthogonal<A: Scalar> {
+    /// Solves `Q * x = b` as `x = Q^H * b`
+    fn solve_orthogonal<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+        rtol: Option<A::Real>,
+    ) -> Result<Array1<A>>;
+
+    /// Returns the inverse `Q^H`
+    fn inv_orthogonal(&self, rtol: Option<A::Real>) -> Result<Array2<A>>;
+
+    /// Checks `||Q^H Q - I||_F <= rtol * sqrt(n)`
+    fn is_orthogonal(&self, rtol: A::Real) -> Result<bool>;
+}
+
+impl<A, S> SolveOrthogonal<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_orthogonal<Sb: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        rtol: Option<A::Real>,
+    ) -> Result<Array1<A>> {
+        let qh = self.inv_orthogonal(rtol)?;
+        Ok(qh.dot(b))
+    }
+
+    fn inv_orthogonal(&self, rtol: Option<A::Real>) -> Result<Array2<A>> {
+        self.ensure_square()?;
+        if let Some(rtol) = rtol {
+            if !self.is_orthogonal(rtol)? {
+                return Err(LinalgError::NotOrthogonal);
+            }
+        }
+        Ok(self.t().mapv(|x| x.conj()))
+    }
+
+    fn is_orthogonal(&self, rtol: A::Real) -> Result<bool> {
+        self.ensure_square()?;
+        let n = self.nrows();
+        let qhq = self.t().mapv(|x| x.conj()).dot(self) - Array2::<A>::eye(n);
+        Ok(qhq.norm_l2() <= rtol * Float::sqrt(A::Real::real(n)))
+    }
+}
+
+/// Solve linear system with equilibration of badly scaled matrix
+///
+/// The row and column scale factors `r` and `c` are computed by `*geequ`,
+/// and the scaled system `(diag(r) A diag(c)) y = diag(r) b` is solved by
+/// the LU decomposition. The solution is recovered as `x = diag(c) y`.
+/// This improves the accuracy for matrices whose rows or columns have
+/// wildly varying magnitudes, without the full expert driver `*gesvx`.
+pub trait SolveEquilibrated<A: Scalar> {
+    /// Solves `A * x = b` with equilibration of `A`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` differs from the size of `A`.
+    fn solve_equilibrated<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>>;
+}
+
+impl<A, S> SolveEquilibrated<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_equilibrated<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>> {
+        self.ensure_square()?;
+        assert_eq!(
+            b.len(),
+            self.nrows(),
+            "The length of b must be the size of A."
+        );
+        check_input(self)?;
+        let l = self.layout()?;
+        let lax::Equilibration { r, c, .. } = A::equilibrate(l, self.as_allocated()?)?;
+        let mut a = self.to_owned();
+        for ((i, j), val) in a.indexed_iter_mut() {
+            *val = val.mul_real(r[i] * c[j]);
+        }
+        let mut x = Array1::from_shape_fn(b.len(), |i| b[i].mul_real(r[i]));
+        a.solve_inplace(&mut x)?;
+        Ok(Array1::from_shape_fn(x.len(), |j| x[j].mul_real(c[j])))
+    }
+}
+
+impl<A, S> LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    /// Solves `A * x = b` and improves the solution by iterative refinement
+    ///
+    /// `a` must be the original matrix factorized into `self`, since the
+    /// residual `b - A x` is computed by `*gerfs` using it. Returns the
+    /// solution `x` with the estimated forward error bound
+    /// `||x - x_true||_inf / ||x||_inf` and the componentwise relative
+    /// backward error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shape of `a` differs from the factored matrix, or if the
+    /// length of `b` is not compatible with it.
+    pub fn solve_refine<Sa, Sb>(
+        &self,
+        a: &ArrayBase<Sa, Ix2>,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<(Array1<A>, A::Real, A::Real)>
+    where
+        Sa: Data<Elem = A>,
+        Sb: Data<Elem = A>,
+    {
+        assert_eq!(
+            a.dim(),
+            self.a.dim(),
+            "The shape of `a` must be the same as the factored matrix.",
+        );
+        let l = self.a.square_layout()?;
+        let mut a_orig = Array2::zeros(a.dim().set_f(matches!(l, MatrixLayout::F { .. })));
+        a_orig.assign(a);
+        let mut x = self.solve(b)?;
+        let b = b.to_owned();
+        let lax::ErrorBounds { ferr, berr } = A::solve_refine(
+            l,
+            Transpose::No,
+            a_orig.as_allocated()?,
+            self.a.as_allocated()?,
+            &self.ipiv,
+            b.as_slice().unwrap(),
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok((x, ferr[0], berr[0]))
+    }
+}
+
+/// Solve linear equations with iterative refinement
+///
+/// The solution computed from the LU decomposition may lose precision for
+/// ill-conditioned systems. It is improved by `*gerfs`, which also provides
+/// error bounds for the refined solution.
+/// See [LUFactorized::solve_refine] to reuse an existing factorization.
+pub trait SolveRefine<A: Scalar> {
+    /// Solves `A * x = b` with iterative refinement, and returns `(x, ferr, berr)`
+    /// where `ferr` is the estimated forward error bound and `berr` is the
+    /// componentwise relative backward error
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` differs from the size of `A`.
+    fn solve_refine<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<(Array1<A>, A::Real, A::Real)>;
+}
+
+impl<A, S> SolveRefine<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_refine<Sb: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<(Array1<A>, A::Real, A::Real)> {
+        let f = self.factorize()?;
+        f.solve_refine(self, b)
+    }
+}
@@ -47,6 +47,7 @@ use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
 use crate::types::*;
+use crate::validate::check_input;
 
 pub use lax::{Pivot, UPLO};
 
@@ -56,16 +57,19 @@ pub use lax::{Pivot, UPLO};
 /// symmetric) coefficient matrix `A` but different `b` vectors, it's faster to
 /// factor the `A` matrix once using the `FactorizeH` trait, and then solve
 /// using the `BKFactorized` struct.
-pub trait SolveH<A: Scalar> {
+///
+/// `b` is a vector for `D = Ix1`, or a matrix whose columns are the right hand
+/// sides for `D = Ix2`, which are solved by a single `*sytrs`/`*hetrs` call.
+pub trait SolveH<A: Scalar, D: Dimension = Ix1> {
     /// Solves a system of linear equations `A * x = b` with Hermitian (or real
     /// symmetric) matrix `A`, where `A` is `self`, `b` is the argument, and
     /// `x` is the successful result.
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
+    /// Panics if the length (or the number of rows) of `b` is not equal to the number of columns
     /// of `A`.
-    fn solveh<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+    fn solveh<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>> {
         let mut b = replicate(b);
         self.solveh_inplace(&mut b)?;
         Ok(b)
@@ -77,12 +81,9 @@ pub trait SolveH<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
+    /// Panics if the length (or the number of rows) of `b` is not equal to the number of columns
     /// of `A`.
-    fn solveh_into<S: DataMut<Elem = A>>(
-        &self,
-        mut b: ArrayBase<S, Ix1>,
-    ) -> Result<ArrayBase<S, Ix1>> {
+    fn solveh_into<S: DataMut<Elem = A>>(&self, mut b: ArrayBase<S, D>) -> Result<ArrayBase<S, D>> {
         self.solveh_inplace(&mut b)?;
         Ok(b)
     }
@@ -94,19 +95,33 @@ pub trait SolveH<A: Scalar> {
     ///
     /// # Panics
     ///
-    /// Panics if the length of `b` is not the equal to the number of columns
+    /// Panics if the length (or the number of rows) of `b` is not equal to the number of columns
     /// of `A`.
     fn solveh_inplace<'a, S: DataMut<Elem = A>>(
         &self,
-        b: &'a mut ArrayBase<S, Ix1>,
-    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
 }
 
 /// Represents the Bunch–Kaufman factorization of a Hermitian (or real
-/// symmetric) matrix as `A = P * U * D * U^H * P^T`.
+/// symmetric) matrix as `A = P * U * D * U^H * P^T` (or `P * L * D * L^H * P^T`).
 pub struct BKFactorized<S: Data> {
     pub a: ArrayBase<S, Ix2>,
     pub ipiv: Pivot,
+    uplo: UPLO,
+}
+
+impl<S: Data> BKFactorized<S> {
+    /// Wraps the factors `a` and `ipiv` returned by `*sytrf`/`*hetrf` called
+    /// with `uplo` on the memory of `a` regarded as a column-major matrix
+    pub fn new(a: ArrayBase<S, Ix2>, ipiv: Pivot, uplo: UPLO) -> Self {
+        BKFactorized { a, ipiv, uplo }
+    }
+
+    /// Triangle of `a` passed to LAPACK, i.e. of the column-major matrix
+    pub fn uplo(&self) -> UPLO {
+        self.uplo
+    }
 }
 
 impl<A, S> SolveH<A> for BKFactorized<S>
@@ -128,7 +143,7 @@ where
         );
         A::solveh(
             self.a.square_layout()?,
-            UPLO::Upper,
+            self.uplo,
             self.a.as_allocated()?,
             &self.ipiv,
             rhs.as_slice_mut().unwrap(),
@@ -137,6 +152,50 @@ where
     }
 }
 
+impl<A, S> SolveH<A, Ix2> for BKFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            rhs.nrows(),
+            self.a.len_of(Axis(1)),
+            "The number of rows of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        let l = self.a.square_layout()?;
+        let a = self.a.as_allocated()?;
+        if rhs.t().is_standard_layout() {
+            A::solveh(
+                l,
+                self.uplo,
+                a,
+                &self.ipiv,
+                rhs.as_slice_memory_order_mut().unwrap(),
+            )?;
+        } else {
+            // row-major or non-contiguous `rhs` is solved in a column-major copy
+            let mut x = Array2::zeros(rhs.dim().f());
+            x.assign(rhs);
+            A::solveh(
+                l,
+                self.uplo,
+                a,
+                &self.ipiv,
+                x.as_slice_memory_order_mut().unwrap(),
+            )?;
+            rhs.assign(&x);
+        }
+        Ok(rhs)
+    }
+}
+
 impl<A, S> SolveH<A> for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -154,12 +213,41 @@ where
     }
 }
 
+impl<A, S> SolveH<A, Ix2> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorizeh()?;
+        f.solveh_inplace(rhs)
+    }
+}
+
 /// An interface for computing the Bunch–Kaufman factorization of Hermitian (or
 /// real symmetric) matrix refs.
 pub trait FactorizeH<S: Data> {
     /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
     /// symmetric) matrix.
     fn factorizeh(&self) -> Result<BKFactorized<S>>;
+
+    /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
+    /// symmetric) matrix using only its `uplo` triangle.
+    ///
+    /// The default implementation supports only [UPLO::Upper] by
+    /// [FactorizeH::factorizeh], and returns an error for [UPLO::Lower].
+    fn factorizeh_with(&self, uplo: UPLO) -> Result<BKFactorized<S>> {
+        match uplo {
+            UPLO::Upper => self.factorizeh(),
+            UPLO::Lower => Err(lax::error::Error::InvalidFlag { flag: 'L' }.into()),
+        }
+    }
 }
 
 /// An interface for computing the Bunch–Kaufman factorization of Hermitian (or
@@ -176,8 +264,23 @@ where
     S: DataMut<Elem = A>,
 {
     fn factorizeh_into(mut self) -> Result<BKFactorized<S>> {
-        let ipiv = A::bk(self.square_layout()?, UPLO::Upper, self.as_allocated_mut()?)?;
-        Ok(BKFactorized { a: self, ipiv })
+        check_input(&self)?;
+        let layout = self.square_layout()?;
+        // The row-major storage of `A` is the column-major `A^T = conj(A)`,
+        // whose lower triangle is the upper triangle of `A`
+        let uplo = match layout {
+            MatrixLayout::C { .. } => {
+                self.mapv_inplace(|x| x.conj());
+                UPLO::Lower
+            }
+            MatrixLayout::F { .. } => UPLO::Upper,
+        };
+        let ipiv = A::bk(layout, uplo, self.as_allocated_mut()?)?;
+        Ok(BKFactorized {
+            a: self,
+            ipiv,
+            uplo,
+        })
     }
 }
 
@@ -187,13 +290,35 @@ where
     Si: Data<Elem = A>,
 {
     fn factorizeh(&self) -> Result<BKFactorized<OwnedRepr<A>>> {
-        let mut a: Array2<A> = replicate(self);
-        let ipiv = A::bk(a.square_layout()?, UPLO::Upper, a.as_allocated_mut()?)?;
-        Ok(BKFactorized { a, ipiv })
+        self.factorizeh_with(UPLO::Upper)
+    }
+
+    fn factorizeh_with(&self, uplo: UPLO) -> Result<BKFactorized<OwnedRepr<A>>> {
+        check_input(self)?;
+        // column-major copy so that `uplo` refers to the triangle of `self`
+        let mut a = Array2::zeros(self.dim().f());
+        a.assign(self);
+        let ipiv = A::bk(a.square_layout()?, uplo, a.as_allocated_mut()?)?;
+        Ok(BKFactorized { a, ipiv, uplo })
     }
 }
 
 /// An interface for inverting Hermitian (or real symmetric) matrix refs.
+///
+/// The inverse is computed from the Bunch–Kaufman factorization, which is
+/// stable also for indefinite matrices, and both triangles of the result are
+/// filled. For a matrix, only its upper triangle is used. Use
+/// [FactorizeH::factorizeh_with] to use the lower one:
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, 0.0], [2.0, -3.0]]; // only the lower triangle is set
+/// let inv = a.factorizeh_with(UPLO::Lower).unwrap().invh_into().unwrap();
+/// let full = array![[1.0, 2.0], [2.0, -3.0]];
+/// assert_close_l2!(&inv.dot(&full), &Array2::eye(2), 1e-12);
+/// ```
 pub trait InverseH {
     type Output;
     /// Computes the inverse of the Hermitian (or real symmetric) matrix.
@@ -215,13 +340,18 @@ where
     type Output = ArrayBase<S, Ix2>;
 
     fn invh_into(mut self) -> Result<ArrayBase<S, Ix2>> {
-        A::invh(
-            self.a.square_layout()?,
-            UPLO::Upper,
-            self.a.as_allocated_mut()?,
-            &self.ipiv,
-        )?;
-        triangular_fill_hermitian(&mut self.a, UPLO::Upper);
+        let layout = self.a.square_layout()?;
+        A::invh(layout, self.uplo, self.a.as_allocated_mut()?, &self.ipiv)?;
+        // the triangle of the column-major matrix is the opposite one of a row-major `a`,
+        // which holds the conjugate of the inverse
+        let uplo = match layout {
+            MatrixLayout::C { .. } => {
+                self.a.mapv_inplace(|x| x.conj());
+                self.uplo.t()
+            }
+            MatrixLayout::F { .. } => self.uplo,
+        };
+        triangular_fill_hermitian(&mut self.a, uplo);
         Ok(self.a)
     }
 }
@@ -237,6 +367,7 @@ where
         let f = BKFactorized {
             a: replicate(&self.a),
             ipiv: self.ipiv.clone(),
+            uplo: self.uplo,
         };
         f.invh_into()
     }
@@ -396,7 +527,7 @@ where
     /// determinants since it returns the natural logarithm of the determinant
     /// rather than the determinant itself.
     pub fn sln_deth(&self) -> (A::Real, A::Real) {
-        bk_sln_det(UPLO::Upper, self.ipiv.iter().cloned(), &self.a)
+        bk_sln_det(self.uplo, self.ipiv.iter().cloned(), &self.a)
     }
 
     /// Computes the determinant of the factorized Hermitian (or real
@@ -420,7 +551,7 @@ where
     /// large determinants since it returns the natural logarithm of the
     /// determinant rather than the determinant itself.
     pub fn sln_deth_into(self) -> (A::Real, A::Real) {
-        bk_sln_det(UPLO::Upper, self.ipiv.into_iter(), &self.a)
+        bk_sln_det(self.uplo, self.ipiv.into_iter(), &self.a)
     }
 }
 
@@ -0,0 +1,217 @@
+//! Means of symmetric (Hermitian) positive definite matrices
+//!
+//! The set of SPD matrices is not a vector space, and the arithmetic mean
+//! does not respect its geometry, e.g. it does not commute with inversion.
+//! The means in this module are those of the affine-invariant Riemannian
+//! metric, which are used for averaging covariance matrices and diffusion
+//! tensors.
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::cholesky::*;
+use crate::eigh::*;
+use crate::error::*;
+use crate::norm::Norm;
+use crate::types::*;
+
+/// Weighted geometric mean `A #_t B` of two SPD matrices
+///
+/// This computes
+///
+/// ```text
+/// A #_t B = A^{1/2} (A^{-1/2} B A^{-1/2})^t A^{1/2}
+/// ```
+///
+/// which is the point at `t` on the geodesic from `A` (`t = 0`) to `B`
+/// (`t = 1`). For `t = 0.5` it is the geometric mean, which is symmetric
+/// `A #_{1/2} B = B #_{1/2} A` and is the unique SPD solution `G` of the
+/// Riccati equation `G A^{-1} G = B`.
+///
+/// Both inputs are checked to be positive definite by the Cholesky
+/// decomposition, and an error is returned otherwise.
+///
+/// # Panics
+///
+/// Panics if the shapes of the matrices are different.
+pub fn spd_geometric_mean<A>(a: &ArrayView2<A>, b: &ArrayView2<A>, t: A::Real) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+{
+    assert_eq!(
+        a.shape(),
+        b.shape(),
+        "The shapes of the matrices must be identical.",
+    );
+    check_spd(a)?;
+    check_spd(b)?;
+    let a_sqrt = hermitian_map(a, Float::sqrt)?;
+    let a_inv_sqrt = hermitian_map(a, |x| Float::sqrt(x).recip())?;
+    let c = a_inv_sqrt.dot(b).dot(&a_inv_sqrt);
+    let c_t = hermitian_map(&c, |x| Float::powf(Float::max(x, A::Real::zero()), t))?;
+    Ok(a_sqrt.dot(&c_t).dot(&a_sqrt))
+}
+
+/// Karcher (Fréchet) mean of SPD matrices
+///
+/// The Karcher mean is the Riemannian barycenter `X` minimizing the sum of
+/// squared geodesic distances to `M_i`. It is computed by the fixed point
+/// iteration
+///
+/// ```text
+/// X <- X^{1/2} exp(mean_i log(X^{-1/2} M_i X^{-1/2})) X^{1/2}
+/// ```
+///
+/// starting from the arithmetic mean, which is the gradient descent on the
+/// SPD manifold with unit step size. The iteration stops when the Frobenius
+/// norm of `mean_i log(X^{-1/2} M_i X^{-1/2})`, i.e. the Riemannian
+/// gradient, becomes smaller than `tol`.
+///
+/// Returns the mean and the number of iterations. If the iteration does not
+/// converge, the last iterate is returned with `maxiter` iterations.
+/// For two matrices, the mean is [spd_geometric_mean] with `t = 0.5`.
+///
+/// # Panics
+///
+/// Panics if `mats` is empty, or if the shapes of the matrices are different.
+pub fn spd_karcher_mean<A>(
+    mats: &[ArrayView2<A>],
+    tol: A::Real,
+    maxiter: usize,
+) -> Result<(Array2<A>, usize)>
+where
+    A: Scalar + Lapack,
+{
+    assert!(!mats.is_empty(), "At least one matrix is required.");
+    let shape = mats[0].shape();
+    for m in mats {
+        assert_eq!(
+            m.shape(),
+            shape,
+            "The shapes of the matrices must be identical.",
+        );
+        check_spd(m)?;
+    }
+    let scale = A::Real::real(mats.len()).recip();
+
+    let mut x: Array2<A> = Array2::zeros(mats[0].dim());
+    for m in mats {
+        x += m;
+    }
+    x.mapv_inplace(|x| x.mul_real(scale));
+    for iter in 0..maxiter {
+        let x_sqrt = hermitian_map(&x, Float::sqrt)?;
+        let x_inv_sqrt = hermitian_map(&x, |x| Float::sqrt(x).recip())?;
+        let mut grad = Array2::zeros(x.dim());
+        for m in mats {
+            let c = x_inv_sqrt.dot(m).dot(&x_inv_sqrt);
+            grad += &hermitian_map(&c, Float::ln)?;
+        }
+        grad.mapv_inplace(|x| x.mul_real(scale));
+        if grad.norm_l2() < tol {
+            return Ok((x, iter));
+        }
+        let step = hermitian_map(&grad, Float::exp)?;
+        x = x_sqrt.dot(&step).dot(&x_sqrt);
+    }
+    Ok((x, maxiter))
+}
+
+/// Geometric median of SPD matrices
+///
+/// The geometric median minimizes the sum of (non-squared) geodesic
+/// distances to `M_i`, and unlike [spd_karcher_mean] it is robust against
+/// outlier matrices, e.g. corrupted covariance estimates. It is computed by
+/// the Riemannian Weiszfeld iteration
+///
+/// ```text
+/// L_i = log(X^{-1/2} M_i X^{-1/2}),  w_i = 1 / |L_i|_F
+/// X <- X^{1/2} exp(Σ_i w_i L_i / Σ_i w_i) X^{1/2}
+/// ```
+///
+/// starting from the arithmetic mean, where `|L_i|_F` is the
+/// affine-invariant distance between `X` and `M_i`. The iteration stops when
+/// the Frobenius norm of the step `Σ_i w_i L_i / Σ_i w_i` becomes smaller than
+/// `tol`, or when `X` reaches one of `M_i` within `tol`, which happens when
+/// the majority of the matrices coincide. If the iteration does not converge,
+/// the last iterate of `maxiter` iterations is returned.
+///
+/// # Panics
+///
+/// Panics if `mats` is empty, or if the shapes of the matrices are different.
+pub fn spd_geometric_median<A>(
+    mats: &[ArrayView2<A>],
+    tol: A::Real,
+    maxiter: usize,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+{
+    assert!(!mats.is_empty(), "At least one matrix is required.");
+    let shape = mats[0].shape();
+    for m in mats {
+        assert_eq!(
+            m.shape(),
+            shape,
+            "The shapes of the matrices must be identical.",
+        );
+        check_spd(m)?;
+    }
+    let scale = A::Real::real(mats.len()).recip();
+
+    let mut x: Array2<A> = Array2::zeros(mats[0].dim());
+    for m in mats {
+        x += m;
+    }
+    x.mapv_inplace(|x| x.mul_real(scale));
+    for _ in 0..maxiter {
+        let x_sqrt = hermitian_map(&x, Float::sqrt)?;
+        let x_inv_sqrt = hermitian_map(&x, |x| Float::sqrt(x).recip())?;
+        let mut step = Array2::zeros(x.dim());
+        let mut weight_sum = A::Real::zero();
+        for m in mats {
+            let c = x_inv_sqrt.dot(m).dot(&x_inv_sqrt);
+            let l = hermitian_map(&c, Float::ln)?;
+            let dist = l.norm_l2();
+            if dist < tol {
+                return Ok(x);
+            }
+            let w = dist.recip();
+            step.scaled_add(A::from_real(w), &l);
+            weight_sum += w;
+        }
+        step.mapv_inplace(|x| x.div_real(weight_sum));
+        if step.norm_l2() < tol {
+            return Ok(x);
+        }
+        let step = hermitian_map(&step, Float::exp)?;
+        x = x_sqrt.dot(&step).dot(&x_sqrt);
+    }
+    Ok(x)
+}
+
+/// Check positive definiteness by the Cholesky decomposition
+fn check_spd<A, S>(a: &ArrayBase<S, Ix2>) -> Result<()>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    a.cholesky(UPLO::Lower)?;
+    Ok(())
+}
+
+/// Compute `f(A) = V f(Λ) V^H` for a Hermitian matrix `A = V Λ V^H`
+fn hermitian_map<A, S, F>(a: &ArrayBase<S, Ix2>, f: F) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    F: Fn(A::Real) -> A::Real,
+{
+    let (e, v) = a.eigh(UPLO::Lower)?;
+    let fe = e.mapv(f);
+    let mut vf = v.clone();
+    for (mut col, &fe) in vf.axis_iter_mut(Axis(1)).zip(fe.iter()) {
+        col.mapv_inplace(|x| x.mul_real(fe));
+    }
+    Ok(vf.dot(&v.t().mapv(|x| x.conj())))
+}
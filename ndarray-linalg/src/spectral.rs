@@ -0,0 +1,68 @@
+//! Spectral methods for graphs given as affinity matrices
+//!
+//! [Wikipedia article on spectral clustering](https://en.wikipedia.org/wiki/Spectral_clustering)
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::eigh::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Symmetric normalized Laplacian `I - D^{-1/2} W D^{-1/2}` of an affinity matrix `W`
+///
+/// `D` is the diagonal degree matrix whose entries are the row sums of `W`.
+/// Isolated vertices, i.e. rows of `W` summing to zero, are left untouched by
+/// the normalization, so that the corresponding diagonal entry of the result
+/// is one.
+pub fn normalized_laplacian<A, S>(affinity: &ArrayBase<S, Ix2>) -> Result<Array2<A>>
+where
+    A: Float + Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    affinity.ensure_square()?;
+    let d_inv_sqrt = affinity.sum_axis(Axis(1)).mapv_into(|d| {
+        if d > A::zero() {
+            A::one() / Float::sqrt(d)
+        } else {
+            A::zero()
+        }
+    });
+    let n = affinity.nrows();
+    let mut l = Array2::eye(n);
+    Zip::indexed(&mut l)
+        .and(affinity)
+        .for_each(|(i, j), l, &w| *l -= d_inv_sqrt[i] * w * d_inv_sqrt[j]);
+    Ok(l)
+}
+
+/// Embed the vertices of a graph into `k` dimensions
+///
+/// This computes the eigenvectors for the `k` smallest eigenvalues of the
+/// [normalized_laplacian] using [Eigh], and returns them as the columns of a
+/// `(n, k)` matrix, where the `i`-th row is the coordinate of the `i`-th
+/// vertex.
+///
+/// For large sparse graphs where only a few eigenvectors are needed,
+/// consider using [TruncatedEig](crate::TruncatedEig) with
+/// [TruncatedOrder::Smallest](crate::TruncatedOrder::Smallest) on the
+/// Laplacian instead.
+///
+/// # Panics
+///
+/// Panics if `k` is larger than the number of vertices.
+pub fn spectral_embedding<A, S>(affinity: &ArrayBase<S, Ix2>, k: usize) -> Result<Array2<A>>
+where
+    A: Float + Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    assert!(
+        k <= affinity.nrows(),
+        "The embedding dimension must not exceed the number of vertices."
+    );
+    let l = normalized_laplacian(affinity)?;
+    let (_, v) = l.eigh_into(UPLO::Lower)?;
+    Ok(v.slice_move(s![.., ..k]))
+}
@@ -0,0 +1,78 @@
+//! Principal square root of general matrices
+//!
+//! - Å. Björck and S. Hammarling, "A Schur method for the square root of a
+//!   matrix", Linear Algebra Appl. 52/53 (1983)
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::schur::*;
+use crate::types::*;
+
+/// Matrix square root
+pub trait SqrtM {
+    type Output;
+
+    /// Compute the square root `X` satisfying `X X = A`
+    ///
+    /// The matrix is reduced to the complex Schur form `A = Z T Z^H`, and the
+    /// square root `U` of the upper triangular `T` is computed column by column
+    /// by the Björck-Hammarling recurrence
+    ///
+    /// ```text
+    /// U[i, i] = sqrt(T[i, i])
+    /// U[i, j] = (T[i, j] - Σ_{i<k<j} U[i, k] U[k, j]) / (U[i, i] + U[j, j])
+    /// ```
+    ///
+    /// which gives `X = Z U Z^H`. The principal branch `Re(sqrt(λ)) >= 0` is
+    /// used for each eigenvalue `λ`, and thus `X` is the principal square root
+    /// if `A` has no eigenvalue on the closed negative real axis. For negative
+    /// real eigenvalues, the result is a complex square root even if `A` is
+    /// real, and hence the result is always a complex matrix.
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NoSquareRoot] if `U[i, i] + U[j, j]` vanishes with
+    ///   non-zero numerator, i.e. `A` is singular and the zero eigenvalue is
+    ///   defective, for which no square root exists.
+    fn sqrtm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> SqrtM for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A::Complex>;
+
+    fn sqrtm(&self) -> Result<Self::Output> {
+        let (z, t) = self.schur_complex()?;
+        let u = sqrt_upper_triangular(&t)?;
+        Ok(z.dot(&u).dot(&z.t().mapv(|x| x.conj())))
+    }
+}
+
+/// Björck-Hammarling recurrence for the square root of an upper triangular matrix
+pub(crate) fn sqrt_upper_triangular<A: Scalar>(t: &Array2<A>) -> Result<Array2<A>> {
+    let n = t.nrows();
+    let mut u = Array2::<A>::zeros((n, n));
+    for j in 0..n {
+        u[(j, j)] = t[(j, j)].sqrt();
+        for i in (0..j).rev() {
+            let mut s = t[(i, j)];
+            for k in (i + 1)..j {
+                s -= u[(i, k)] * u[(k, j)];
+            }
+            let d = u[(i, i)] + u[(j, j)];
+            u[(i, j)] = if !d.is_zero() {
+                s / d
+            } else if s.is_zero() {
+                A::zero()
+            } else {
+                return Err(LinalgError::NoSquareRoot);
+            };
+        }
+    }
+    Ok(u)
+}
@@ -0,0 +1,155 @@
+//! Streaming estimation of mean and covariance
+//!
+//! [CovarianceAccumulator] ingests samples one at a time using Welford's
+//! algorithm, which is numerically stable compared to accumulating the sums of
+//! `x` and `x x^H`. Once the Cholesky factor of the covariance is requested, it
+//! is kept up to date by the rank-1 update [cholesky_rank1_update] in `O(n^2)`
+//! per sample instead of refactorizing in `O(n^3)`.
+
+use ndarray::*;
+use num_traits::{Float, One};
+
+use crate::cholesky::*;
+use crate::error::*;
+use crate::types::*;
+
+/// Running mean and covariance of a stream of samples
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let mut acc = CovarianceAccumulator::new(2);
+/// acc.push(&arr1(&[1.0, 2.0]));
+/// acc.push(&arr1(&[3.0, 2.0]));
+/// acc.push(&arr1(&[2.0, 5.0]));
+/// assert_eq!(acc.count(), 3);
+/// assert_close_l2!(acc.mean(), &arr1(&[2.0, 3.0]), 1e-12);
+///
+/// let cov = acc.covariance();
+/// let l = acc.cholesky().unwrap();
+/// assert_close_l2!(&l.dot(&l.t()), &cov, 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CovarianceAccumulator<A: Scalar> {
+    count: usize,
+    mean: Array1<A>,
+    /// Sum of `(x - mean)(x - mean)^H` over the samples
+    scatter: Array2<A>,
+    /// Lower Cholesky factor of `scatter`, kept after the first request
+    factor: Option<Array2<A>>,
+}
+
+impl<A> CovarianceAccumulator<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Empty accumulator for samples of dimension `dim`
+    pub fn new(dim: usize) -> Self {
+        CovarianceAccumulator {
+            count: 0,
+            mean: Array1::zeros(dim),
+            scatter: Array2::zeros((dim, dim)),
+            factor: None,
+        }
+    }
+
+    /// Dimension of the samples
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Number of samples ingested so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Mean of the samples
+    pub fn mean(&self) -> &Array1<A> {
+        &self.mean
+    }
+
+    /// Add a sample
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `x` differs from [CovarianceAccumulator::dim].
+    pub fn push<S>(&mut self, x: &ArrayBase<S, Ix1>)
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(x.len(), self.dim(), "Sample has a wrong dimension");
+        self.count += 1;
+        let n = A::Real::real(self.count);
+        let d = x - &self.mean;
+        self.mean.scaled_add(A::one().div_real(n), &d);
+        // scatter += (x - mean_old)(x - mean_new)^H = (n - 1) / n d d^H
+        let w = d.mapv(|v| v.mul_real(Float::sqrt((n - A::Real::one()) / n)));
+        for j in 0..self.dim() {
+            let wj = w[j].conj();
+            self.scatter
+                .column_mut(j)
+                .zip_mut_with(&w, |s, &wi| *s += wi * wj);
+        }
+        if let Some(l) = self.factor.as_mut() {
+            cholesky_rank1_update(l, &w).expect("Factor is square");
+        }
+    }
+
+    /// Add each row of `xs` as a sample
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns of `xs` differs from [CovarianceAccumulator::dim].
+    pub fn extend<S>(&mut self, xs: &ArrayBase<S, Ix2>)
+    where
+        S: Data<Elem = A>,
+    {
+        for x in xs.outer_iter() {
+            self.push(&x);
+        }
+    }
+
+    /// Unbiased sample covariance `sum (x - mean)(x - mean)^H / (count - 1)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if less than two samples are ingested.
+    pub fn covariance(&self) -> Array2<A> {
+        assert!(self.count >= 2, "Covariance needs at least two samples");
+        let n1 = A::Real::real(self.count - 1);
+        self.scatter.mapv(|s| s.div_real(n1))
+    }
+
+    /// Lower triangular Cholesky factor `L` of the covariance `L L^H`
+    ///
+    /// The factor is computed by the Cholesky decomposition at the first
+    /// request, and is updated by the subsequent [CovarianceAccumulator::push].
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::NotEnoughSamples] if less than two samples are ingested
+    /// - [LinalgError::NotPositiveDefinite] if the covariance is singular, e.g. while `count <= dim`
+    pub fn cholesky(&mut self) -> Result<Array2<A>> {
+        if self.count < 2 {
+            return Err(LinalgError::NotEnoughSamples {
+                count: self.count,
+                required: 2,
+            });
+        }
+        if self.factor.is_none() {
+            self.factor = Some(self.scatter.cholesky(UPLO::Lower)?);
+        }
+        let l = self.factor.as_ref().unwrap();
+        let n1 = Float::sqrt(A::Real::real(self.count - 1));
+        Ok(l.mapv(|x| x.div_real(n1)))
+    }
+
+    /// Reset to the empty state
+    pub fn clear(&mut self) {
+        self.count = 0;
+        self.mean.fill(A::zero());
+        self.scatter.fill(A::zero());
+        self.factor = None;
+    }
+}
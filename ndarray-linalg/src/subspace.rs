@@ -0,0 +1,63 @@
+//! Tracking the dominant eigenspace of a slowly changing operator
+//!
+//! For an operator which changes step by step, e.g. a covariance matrix
+//! updated with new data, the dominant eigenspace of the current operator is
+//! close to that of the previous one. [SubspaceTracker] keeps an orthonormal
+//! basis and refines it by a block power step for each change, which is far
+//! cheaper than a truncated eigendecomposition from scratch.
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::qr::*;
+use crate::types::*;
+
+/// Orthonormal basis `Q` (m x k) tracking the dominant k-dimensional eigenspace
+#[derive(Debug, Clone)]
+pub struct SubspaceTracker<A> {
+    q: Array2<A>,
+}
+
+impl<A> SubspaceTracker<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Start tracking from the column space of `initial_q`
+    ///
+    /// `initial_q` is orthonormalized by the QR decomposition, and an error is
+    /// returned if it has more columns than rows.
+    pub fn new(initial_q: Array2<A>) -> Result<Self> {
+        let (m, k) = initial_q.dim();
+        if k > m {
+            return Err(LinalgError::NotStandardShape {
+                obj: "Subspace basis",
+                rows: m as i32,
+                cols: k as i32,
+            });
+        }
+        let (q, _) = initial_q.qr_into()?;
+        Ok(SubspaceTracker { q })
+    }
+
+    /// Block power step `Q <- orth(A Q)`
+    ///
+    /// `matvec` applies the current operator `A` to a vector, and is called
+    /// once for each column of `Q`.
+    pub fn update<F>(&mut self, mut matvec: F) -> Result<()>
+    where
+        F: FnMut(ArrayView1<A>) -> Array1<A>,
+    {
+        let mut aq = Array2::zeros(self.q.dim().f());
+        for (q, mut aq) in self.q.axis_iter(Axis(1)).zip(aq.axis_iter_mut(Axis(1))) {
+            aq.assign(&matvec(q));
+        }
+        let (q, _) = aq.qr_into()?;
+        self.q = q;
+        Ok(())
+    }
+
+    /// Current orthonormal basis of the tracked eigenspace
+    pub fn eigenspace(&self) -> ArrayView2<'_, A> {
+        self.q.view()
+    }
+}
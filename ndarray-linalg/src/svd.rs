@@ -2,8 +2,14 @@
 //!
 //! [Wikipedia article on SVD](https://en.wikipedia.org/wiki/Singular_value_decomposition)
 
-use crate::{convert::*, error::*, layout::*, types::*};
+use crate::{
+    convert::*, error::*, generate::*, layout::*, norm::Norm, qr::*, triangular::*, types::*,
+    validate::check_input,
+};
+use lax::SvdRef;
 use ndarray::*;
+use num_traits::Zero;
+use rand::{rngs::StdRng, SeedableRng};
 
 /// singular-value decomposition of matrix reference
 pub trait SVD {
@@ -92,6 +98,7 @@ where
         calc_u: bool,
         calc_vt: bool,
     ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        check_input(self)?;
         let l = self.layout()?;
         let svd_res = A::svd(l, calc_u, calc_vt, self.as_allocated_mut()?)?;
         let (n, m) = l.size();
@@ -104,3 +111,345 @@ where
         Ok((u, s, vt))
     }
 }
+
+/// Error of the best rank-`k` approximation, see [TruncateRank]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RankApproxError<R> {
+    /// Error in spectral norm, i.e. `s[k]`
+    pub spectral: R,
+    /// Error in Frobenius norm, i.e. `sqrt(Σ_{i≥k} s[i]^2)`
+    pub frobenius: R,
+}
+
+/// Best low-rank approximation of matrix
+///
+/// By the Eckart-Young theorem, truncating the SVD `A = U Σ V^H` to the
+/// largest `k` singular values gives the best rank-`k` approximation
+/// `U[:, :k] Σ[:k] V^H[:k, :]` both in Frobenius and spectral norm.
+pub trait TruncateRank {
+    type Output;
+    type Real;
+
+    /// Returns the best rank-`k` approximation of the matrix
+    ///
+    /// If `k` is not smaller than the number of singular values,
+    /// this reconstructs the matrix itself.
+    fn truncate_rank(&self, k: usize) -> Result<Self::Output>;
+
+    /// Returns the best rank-`k` approximation and its approximation error
+    fn truncate_rank_with_error(
+        &self,
+        k: usize,
+    ) -> Result<(Self::Output, RankApproxError<Self::Real>)>;
+}
+
+impl<A, S> TruncateRank for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+    type Real = A::Real;
+
+    fn truncate_rank(&self, k: usize) -> Result<Self::Output> {
+        let (a, _) = self.truncate_rank_with_error(k)?;
+        Ok(a)
+    }
+
+    fn truncate_rank_with_error(
+        &self,
+        k: usize,
+    ) -> Result<(Self::Output, RankApproxError<Self::Real>)> {
+        let (u, s, vt) = self.svd(true, true)?;
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        let k = k.min(s.len());
+
+        let mut uk = u.slice(s![.., ..k]).to_owned();
+        for (mut col, &s) in uk.axis_iter_mut(Axis(1)).zip(s.iter()) {
+            col.mapv_inplace(|x| x.mul_real(s));
+        }
+        let approx = uk.dot(&vt.slice(s![..k, ..]));
+
+        let tail = s.slice(s![k..]);
+        let error = RankApproxError {
+            spectral: tail.first().cloned().unwrap_or_else(A::Real::zero),
+            frobenius: tail.iter().map(|&s| s * s).sum::<A::Real>().sqrt(),
+        };
+        Ok((approx, error))
+    }
+}
+
+/// Effective rank of matrix
+///
+/// The effective rank is the exponential of the spectral entropy
+/// `exp(-Σ_i p_i log p_i)` of the normalized singular values
+/// `p_i = s_i / Σ_j s_j`, which is a smooth measure of the dimensionality
+/// of the matrix without a cutoff of the singular values.
+pub trait EffectiveRank {
+    type Real;
+
+    /// Returns the effective rank in `[1, min(n, m)]`
+    ///
+    /// The terms of `p_i = 0` are omitted by the convention `0 log 0 = 0`.
+    /// Returns zero for the zero matrix and for an empty matrix.
+    fn effective_rank(&self) -> Result<Self::Real>;
+}
+
+impl<A, S> EffectiveRank for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Real = A::Real;
+
+    fn effective_rank(&self) -> Result<A::Real> {
+        let (_, s, _) = self.svd(false, false)?;
+        let total = s.sum();
+        if total == A::Real::zero() {
+            return Ok(A::Real::zero());
+        }
+        let entropy = s
+            .iter()
+            .filter(|&&s| s > A::Real::zero())
+            .map(|&s| {
+                let p = s / total;
+                -p * p.ln()
+            })
+            .sum::<A::Real>();
+        Ok(entropy.exp())
+    }
+}
+
+/// Moore-Penrose pseudoinverse
+pub trait PInv<A: Scalar> {
+    /// Compute the pseudoinverse `A^+` from the SVD `A = U Σ V^H`
+    ///
+    /// Singular values smaller than `rcond * s_max` are treated as zero,
+    /// where `rcond` defaults to `max(n, m) * epsilon` as `numpy.linalg.pinv`.
+    /// Then `A^+ = V_r Σ_r^{-1} U_r^H` for the numerical rank `r`, which is an
+    /// `m x n` matrix for `n x m` matrix `A`.
+    fn pinv(&self, rcond: Option<A::Real>) -> Result<Array2<A>>;
+}
+
+impl<A, S> PInv<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn pinv(&self, rcond: Option<A::Real>) -> Result<Array2<A>> {
+        let (v, uh) = pinv_factors(self, rcond)?;
+        Ok(v.dot(&uh))
+    }
+}
+
+/// Moore-Penrose pseudoinverse as a reusable operator
+pub trait PInvOperator<A: Scalar> {
+    /// Compute the SVD once and return a closure applying `A^+` to vectors
+    ///
+    /// Singular values smaller than `rcond * s_max` are treated as zero,
+    /// where `rcond` defaults to `max(n, m) * epsilon`.
+    /// The closure keeps `V_r Σ_r^{-1}` and `U_r^H` of the truncated SVD,
+    /// so that each application costs `O((n + m) r)` for the numerical rank `r`.
+    /// It panics if the length of the vector differs from the number of rows.
+    fn pinv_operator(
+        &self,
+        rcond: Option<A::Real>,
+    ) -> Result<impl Fn(ArrayView1<A>) -> Array1<A> + 'static>;
+}
+
+impl<A, S> PInvOperator<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn pinv_operator(
+        &self,
+        rcond: Option<A::Real>,
+    ) -> Result<impl Fn(ArrayView1<A>) -> Array1<A> + 'static> {
+        let (v, uh) = pinv_factors(self, rcond)?;
+        Ok(move |b: ArrayView1<A>| v.dot(&uh.dot(&b)))
+    }
+}
+
+/// `V_r Σ_r^{-1}` and `U_r^H` of the SVD truncated at `rcond * s_max`
+fn pinv_factors<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    rcond: Option<A::Real>,
+) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (u, s, vt) = a.svd(true, true)?;
+    let (u, vt) = (u.unwrap(), vt.unwrap());
+    let r = numerical_rank::<A>(a.dim(), &s, rcond);
+
+    let uh = u.slice(s![.., ..r]).t().mapv(|x| x.conj());
+    let mut v = vt.slice(s![..r, ..]).t().mapv(|x| x.conj());
+    for (mut col, &s) in v.axis_iter_mut(Axis(1)).zip(s.iter()) {
+        col.mapv_inplace(|x| x.div_real(s));
+    }
+    Ok((v, uh))
+}
+
+/// Number of singular values larger than `rcond * s_max`, where `rcond`
+/// defaults to `max(n, m) * epsilon`
+fn numerical_rank<A: Scalar>(
+    (n, m): (usize, usize),
+    s: &Array1<A::Real>,
+    rcond: Option<A::Real>,
+) -> usize {
+    let rcond =
+        rcond.unwrap_or_else(|| A::real(n.max(m)) * <A::Real as num_traits::Float>::epsilon());
+    let tol = s.first().map_or_else(A::Real::zero, |&s0| rcond * s0);
+    s.iter().take_while(|&&s| s > tol).count()
+}
+
+/// Orthonormal bases of the kernel and the image of a matrix
+///
+/// Singular values smaller than `rcond * s_max` are treated as zero as
+/// [PInv::pinv], where `rcond` defaults to `max(n, m) * epsilon`.
+pub trait SVDSubspace<A: Scalar> {
+    /// Orthonormal basis of the null space `{x | A x = 0}` of `n x m` matrix `A`
+    ///
+    /// Returns a `(m, m - r)` matrix for the numerical rank `r`, whose columns
+    /// are the right singular vectors of the vanishing singular values.
+    /// It has no column if `A` has full column rank.
+    fn null_space(&self, rcond: Option<A::Real>) -> Result<Array2<A>>;
+
+    /// Orthonormal basis of the range `{A x}` of `n x m` matrix `A`
+    ///
+    /// Returns a `(n, r)` matrix for the numerical rank `r`, whose columns
+    /// are the left singular vectors of the non-vanishing singular values.
+    /// It has no column if `A` vanishes.
+    fn range(&self, rcond: Option<A::Real>) -> Result<Array2<A>>;
+}
+
+impl<A, S> SVDSubspace<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn null_space(&self, rcond: Option<A::Real>) -> Result<Array2<A>> {
+        let (_, s, vt) = self.svd(false, true)?;
+        let r = numerical_rank::<A>(self.dim(), &s, rcond);
+        Ok(vt.unwrap().slice(s![r.., ..]).t().mapv(|x| x.conj()))
+    }
+
+    fn range(&self, rcond: Option<A::Real>) -> Result<Array2<A>> {
+        let (u, s, _) = self.svd(true, false)?;
+        let r = numerical_rank::<A>(self.dim(), &s, rcond);
+        Ok(u.unwrap().slice_move(s![.., ..r]))
+    }
+}
+
+/// Estimate of the smallest singular value by inverse power iteration
+pub trait SmallestSingularValue {
+    type Real;
+
+    /// Estimate `σ_min` by inverse power iteration on `A^H A`
+    ///
+    /// `A = QR` is factorized once, and `(A^H A)^{-1} = (R^H R)^{-1}` is
+    /// applied by two triangular solves with `R^H` and `R`, starting from a
+    /// random vector with a fixed seed. The iteration stops when the relative
+    /// change of the estimate becomes smaller than `tol`, or after `maxiter`
+    /// iterations. Returns `|R x| = sqrt(x^H A^H A x)`, i.e. the square root of
+    /// the Rayleigh quotient of the last normalized vector `x`.
+    ///
+    /// `A` must have full column rank so that `A^H A` is invertible. An error
+    /// is returned if `A` has less rows than columns, or if `R` is exactly
+    /// singular.
+    fn smallest_singular_value_est(&self, maxiter: usize, tol: Self::Real) -> Result<Self::Real>;
+}
+
+impl<A, S> SmallestSingularValue for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Real = A::Real;
+
+    fn smallest_singular_value_est(&self, maxiter: usize, tol: A::Real) -> Result<A::Real> {
+        let (n, m) = self.dim();
+        if n < m {
+            return Err(LinalgError::NotStandardShape {
+                obj: "Full column rank matrix",
+                rows: n as i32,
+                cols: m as i32,
+            });
+        }
+        let (_, r): (Array2<A>, Array2<A>) = self.qr()?;
+        let rh: Array2<A> = conjugate(&r);
+
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let mut x: Array1<A> = random_using(m, &mut rng);
+        let x_norm = x.norm_l2();
+        x.mapv_inplace(|x| x.div_real(x_norm));
+        let mut sigma = r.dot(&x).norm_l2();
+        for _ in 0..maxiter {
+            let y = rh.solve_triangular(UPLO::Lower, Diag::NonUnit, &x)?;
+            let z = r.solve_triangular_into(UPLO::Upper, Diag::NonUnit, y)?;
+            let z_norm = z.norm_l2();
+            x = z.mapv_into(|z| z.div_real(z_norm));
+            let sigma_new = r.dot(&x).norm_l2();
+            let converged = (sigma - sigma_new).abs() <= tol * sigma_new;
+            sigma = sigma_new;
+            if converged {
+                break;
+            }
+        }
+        Ok(sigma)
+    }
+}
+
+/// Best rank-`rank` approximation of each matrix in a stack
+///
+/// The matrices are `stack.index_axis(Axis(0), i)`, and each of them is
+/// truncated independently as [TruncateRank::truncate_rank], e.g. to
+/// denoise the frames of a video or a batch of image patches.
+///
+/// A single LAPACK workspace is allocated and reused for all matrices in
+/// the stack. For `(n, m)` matrices, it holds `U` and `V^H` of the full SVD
+/// (`n^2 + m^2` elements), the `min(n, m)` singular values, the LAPACK work
+/// array of `O(max(n, m))` elements. In addition to the returned stack, the
+/// input stack is copied once into the Fortran layout for LAPACK, which
+/// destroys the copy.
+pub fn denoise_lowrank_batch<A>(stack: &ArrayView3<A>, rank: usize) -> Result<Array3<A>>
+where
+    A: Scalar + Lapack,
+{
+    let (_, n, m) = stack.dim();
+    let mut out = Array3::zeros(stack.dim());
+    if n == 0 || m == 0 {
+        return Ok(out);
+    }
+    check_input(stack)?;
+    // Each `(m, n)` matrix in the standard layout is an `(n, m)` matrix in the Fortran layout
+    let mut a = stack
+        .permuted_axes([0, 2, 1])
+        .as_standard_layout()
+        .into_owned();
+    let layout = MatrixLayout::F {
+        col: m as i32,
+        lda: n as i32,
+    };
+    A::svd_batch(
+        layout,
+        true,
+        true,
+        a.as_slice_mut().unwrap(),
+        |i, SvdRef { s, u, vt }| -> Result<()> {
+            let u = ArrayView2::from_shape((n, n).f(), u.unwrap())?;
+            let vt = ArrayView2::from_shape((m, m).f(), vt.unwrap())?;
+            let k = rank.min(s.len());
+            let mut uk = u.slice(s![.., ..k]).to_owned();
+            for (mut col, &s) in uk.axis_iter_mut(Axis(1)).zip(s.iter()) {
+                col.mapv_inplace(|x| x.mul_real(s));
+            }
+            out.index_axis_mut(Axis(0), i)
+                .assign(&uk.dot(&vt.slice(s![..k, ..])));
+            Ok(())
+        },
+    )?;
+    Ok(out)
+}
@@ -1,6 +1,6 @@
 //! Singular-value decomposition (SVD) by divide-and-conquer (?gesdd)
 
-use super::{convert::*, error::*, layout::*, types::*};
+use super::{convert::*, error::*, layout::*, types::*, validate::check_input};
 use ndarray::*;
 
 pub use lax::JobSvd;
@@ -11,6 +11,18 @@ pub trait SVDDC {
     type VT;
     type Sigma;
     fn svddc(&self, uvt_flag: JobSvd) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+
+    /// Economy SVD computing only the requested singular vectors
+    ///
+    /// `U` is `(m, k)` and `V^H` is `(k, n)` for `k = min(m, n)` as
+    /// `JobSvd::Some`. If only one of them is requested, `*gesdd` stores the
+    /// `(m, k)` or `(k, n)` factor in the working copy of the matrix, and
+    /// allocates the other one only if it is the small `(k, k)` factor.
+    fn svddc_flags(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
 }
 
 /// Singular-value decomposition of matrix by divide-and-conquer
@@ -22,6 +34,13 @@ pub trait SVDDCInto {
         self,
         uvt_flag: JobSvd,
     ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+
+    /// Economy SVD computing only the requested singular vectors, see [SVDDC::svddc_flags]
+    fn svddc_flags_into(
+        self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
 }
 
 /// Singular-value decomposition of matrix reference by divide-and-conquer
@@ -33,6 +52,13 @@ pub trait SVDDCInplace {
         &mut self,
         uvt_flag: JobSvd,
     ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+
+    /// Economy SVD computing only the requested singular vectors, see [SVDDC::svddc_flags]
+    fn svddc_flags_inplace(
+        &mut self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
 }
 
 impl<A, S> SVDDC for ArrayBase<S, Ix2>
@@ -47,6 +73,14 @@ where
     fn svddc(&self, uvt_flag: JobSvd) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
         self.to_owned().svddc_into(uvt_flag)
     }
+
+    fn svddc_flags(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        self.to_owned().svddc_flags_into(calc_u, calc_vt)
+    }
 }
 
 impl<A, S> SVDDCInto for ArrayBase<S, Ix2>
@@ -64,6 +98,14 @@ where
     ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
         self.svddc_inplace(uvt_flag)
     }
+
+    fn svddc_flags_into(
+        mut self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        self.svddc_flags_inplace(calc_u, calc_vt)
+    }
 }
 
 impl<A, S> SVDDCInplace for ArrayBase<S, Ix2>
@@ -79,6 +121,7 @@ where
         &mut self,
         uvt_flag: JobSvd,
     ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        check_input(self)?;
         let l = self.layout()?;
         let svd_res = A::svddc(l, uvt_flag, self.as_allocated_mut()?)?;
         let (m, n) = l.size();
@@ -101,4 +144,71 @@ where
         let s = ArrayBase::from(svd_res.s);
         Ok((u, s, vt))
     }
+
+    fn svddc_flags_inplace(
+        &mut self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        check_input(self)?;
+        let l = self.layout()?;
+        let svd_res = A::svddc_partial(l, calc_u, calc_vt, self.as_allocated_mut()?)?;
+        let (m, n) = l.size();
+        let k = m.min(n);
+        let u = svd_res.u.map(|u| into_matrix(l.resized(m, k), u).unwrap());
+        let vt = svd_res
+            .vt
+            .map(|vt| into_matrix(l.resized(k, n), vt).unwrap());
+        let s = ArrayBase::from(svd_res.s);
+        Ok((u, s, vt))
+    }
+}
+
+/// Economy (thin) singular-value decomposition of matrix reference
+pub trait SVDThin {
+    type U;
+    type VT;
+    type Sigma;
+    /// Computes `A = U Σ V^H` of `m x n` matrix `A` where `U` is `(m, k)` and
+    /// `V^H` is `(k, n)` for `k = min(m, n)`, by `*gesdd` with `JobSvd::Some`
+    fn svd_thin(&self) -> Result<(Self::U, Self::Sigma, Self::VT)>;
+}
+
+/// Economy (thin) singular-value decomposition
+pub trait SVDThinInto {
+    type U;
+    type VT;
+    type Sigma;
+    /// Computes `A = U Σ V^H` of `m x n` matrix `A` where `U` is `(m, k)` and
+    /// `V^H` is `(k, n)` for `k = min(m, n)`, by `*gesdd` with `JobSvd::Some`
+    fn svd_thin_into(self) -> Result<(Self::U, Self::Sigma, Self::VT)>;
+}
+
+impl<A, S> SVDThin for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_thin(&self) -> Result<(Self::U, Self::Sigma, Self::VT)> {
+        self.to_owned().svd_thin_into()
+    }
+}
+
+impl<A, S> SVDThinInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_thin_into(mut self) -> Result<(Self::U, Self::Sigma, Self::VT)> {
+        let (u, s, vt) = self.svddc_inplace(JobSvd::Some)?;
+        Ok((u.unwrap(), s, vt.unwrap()))
+    }
 }
@@ -29,3 +29,26 @@ where
         Ok((0..n as usize).map(|i| self[(i, i)]).sum())
     }
 }
+
+/// Compute `tr(AB)` without forming the product `AB`
+///
+/// This is the sum of the element-wise product of `A` and `B^T`,
+/// which takes `O(mn)` operations for a `m x n` matrix `A` and `n x m` matrix `B`
+/// instead of `O(m^2 n)` of the matrix product.
+///
+/// Errors
+/// -------
+/// - `IncompatibleShape` error if the shape of `B` is not the transpose of that of `A`
+pub fn trace_product<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix2>) -> Result<A>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    if a.dim() != b.t().dim() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok(Zip::from(a)
+        .and(b.t())
+        .fold(A::zero(), |acc, &x, &y| acc + x * y))
+}
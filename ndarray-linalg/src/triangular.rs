@@ -7,9 +7,10 @@ use num_traits::Zero;
 use super::convert::*;
 use super::error::*;
 use super::layout::*;
+use super::solve::*;
 use super::types::*;
 
-pub use lax::Diag;
+pub use lax::{Diag, Side};
 
 /// solve a triangular system with upper triangular matrix
 pub trait SolveTriangular<A, S, D>
@@ -182,3 +183,165 @@ where
         self
     }
 }
+
+/// Check whether a matrix is triangular
+pub trait IsTriangular {
+    /// Returns true if all elements below the diagonal are zero
+    fn is_upper_triangular(&self) -> bool;
+    /// Returns true if all elements above the diagonal are zero
+    fn is_lower_triangular(&self) -> bool;
+}
+
+impl<A, S> IsTriangular for ArrayBase<S, Ix2>
+where
+    A: Zero + PartialEq,
+    S: Data<Elem = A>,
+{
+    fn is_upper_triangular(&self) -> bool {
+        self.indexed_iter()
+            .all(|((i, j), val)| i <= j || val.is_zero())
+    }
+
+    fn is_lower_triangular(&self) -> bool {
+        self.indexed_iter()
+            .all(|((i, j), val)| i >= j || val.is_zero())
+    }
+}
+
+/// Solve a linear system, skipping the LU factorization for triangular matrices
+///
+/// This checks whether the square matrix is upper or lower triangular using
+/// [IsTriangular], which costs `O(n^2)`, and solves by `*trtrs` without
+/// factorization if so. Otherwise, this falls back to [Solve::solve].
+pub trait SolveAuto<A: Scalar> {
+    /// Solves `A x = b` for `x`, where `A` is `self`
+    ///
+    /// The solver is chosen as follows:
+    ///
+    /// - an upper triangular `A`, including a diagonal one, is solved by
+    ///   [SolveTriangular] with [UPLO::Upper] and [Diag::NonUnit]
+    /// - a lower triangular `A` is solved with [UPLO::Lower]
+    /// - any other `A`, including a non-square one, is solved by [Solve::solve]
+    ///   via the LU factorization
+    ///
+    /// Errors
+    /// -------
+    /// - [LinalgError::Lapack] if a diagonal element of a triangular `A` is
+    ///   exactly zero, or the LU factorization finds `A` singular
+    /// - [LinalgError::NotSquare] if `A` is not square
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` differs from the number of columns of `A`.
+    fn solve_auto<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>>;
+}
+
+impl<A, Si> SolveAuto<A> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+{
+    fn solve_auto<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+        match self.solve_triangular_if(b)? {
+            Some(x) => Ok(x),
+            None => self.solve(b),
+        }
+    }
+}
+
+/// Solve a triangular system if the matrix is triangular
+pub trait SolveTriangularIf<A: Scalar> {
+    /// Returns `Ok(None)` if the matrix is neither square upper nor lower
+    /// triangular, and the solution of the triangular system otherwise.
+    fn solve_triangular_if<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<Option<Array1<A>>>;
+}
+
+impl<A, Si> SolveTriangularIf<A> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+{
+    fn solve_triangular_if<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<Option<Array1<A>>> {
+        if !self.is_square() {
+            return Ok(None);
+        }
+        let uplo = if self.is_upper_triangular() {
+            UPLO::Upper
+        } else if self.is_lower_triangular() {
+            UPLO::Lower
+        } else {
+            return Ok(None);
+        };
+        let b: Array1<A> = replicate(b);
+        Ok(Some(self.solve_triangular_into(uplo, Diag::NonUnit, b)?))
+    }
+}
+
+/// Solve a triangular system with a matrix right hand side from either side
+///
+/// This solves `op(A) X = B` for [Side::Left] and `X op(A) = B` for
+/// [Side::Right], where `A` is a triangular matrix and `op` is specified by
+/// [Transpose]. Both cases are solved by `*trsm` without forming `op(A)`.
+pub trait SolveTriangularSide<A: Scalar> {
+    /// Returns `X` with the shape of `b`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows (for [Side::Left]) or columns
+    /// (for [Side::Right]) of `b` differs from the size of `A`.
+    fn solve_triangular_side<S: Data<Elem = A>>(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        trans: Transpose,
+        b: &ArrayBase<S, Ix2>,
+    ) -> Result<Array2<A>>;
+}
+
+impl<A, Si> SolveTriangularSide<A> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+{
+    fn solve_triangular_side<S: Data<Elem = A>>(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        trans: Transpose,
+        b: &ArrayBase<S, Ix2>,
+    ) -> Result<Array2<A>> {
+        self.ensure_square()?;
+        let k = match side {
+            Side::Left => b.nrows(),
+            Side::Right => b.ncols(),
+        };
+        assert_eq!(self.nrows(), k, "size of the triangular matrix mismatches");
+        let la = self.layout()?;
+        // `*trsm` requires `b` in the same order as `a`
+        let mut x = match la {
+            MatrixLayout::F { .. } => Array2::zeros(b.dim().f()),
+            MatrixLayout::C { .. } => Array2::zeros(b.dim()),
+        };
+        x.assign(b);
+        let lx = x.layout()?;
+        A::solve_triangular_side(
+            la,
+            lx,
+            side,
+            uplo,
+            diag,
+            trans,
+            self.as_allocated()?,
+            x.as_allocated_mut()?,
+        )?;
+        Ok(x)
+    }
+}
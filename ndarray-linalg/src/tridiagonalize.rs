@@ -0,0 +1,67 @@
+//! Reduction of Hermitian matrices into real symmetric tridiagonal form
+//!
+//! For a Hermitian (or real symmetric) matrix `A`, this computes a unitary
+//! matrix `Q` and a real symmetric tridiagonal matrix `T` such that
+//! `A = Q T Q^H`. This is the first step of the eigenvalue decomposition
+//! used in [Eigh](crate::Eigh).
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use crate::validate::check_input;
+use crate::UPLO;
+
+/// Tridiagonalize a Hermitian matrix reference
+pub trait Tridiagonalize {
+    type Q;
+    type T;
+    /// Returns `(Q, T)` where `A = Q T Q^H`
+    fn tridiagonalize(&self, uplo: UPLO) -> Result<(Self::Q, Self::T)>;
+}
+
+/// Tridiagonalize a Hermitian matrix, reusing its memory for `Q`
+pub trait TridiagonalizeInto: Sized {
+    type T;
+    /// Returns `(Q, T)` where `A = Q T Q^H`
+    fn tridiagonalize_into(self, uplo: UPLO) -> Result<(Self, Self::T)>;
+}
+
+impl<A, S> TridiagonalizeInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type T = Array2<A::Real>;
+
+    fn tridiagonalize_into(mut self, uplo: UPLO) -> Result<(Self, Self::T)> {
+        check_input(&self)?;
+        let layout = self.square_layout()?;
+        let lax::TridiagonalizeOwned { d, e, tau } =
+            A::tridiagonalize(layout, uplo, self.as_allocated_mut()?)?;
+        A::q_tridiagonal(layout, uplo, self.as_allocated_mut()?, &tau)?;
+        let n = d.len();
+        let mut t = Array2::zeros((n, n));
+        t.diag_mut().assign(&ArrayView1::from(&d));
+        for (i, &e) in e.iter().enumerate() {
+            t[(i, i + 1)] = e;
+            t[(i + 1, i)] = e;
+        }
+        Ok((self, t))
+    }
+}
+
+impl<A, S> Tridiagonalize for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type T = Array2<A::Real>;
+
+    fn tridiagonalize(&self, uplo: UPLO) -> Result<(Self::Q, Self::T)> {
+        let a = self.to_owned();
+        a.tridiagonalize_into(uplo)
+    }
+}
@@ -0,0 +1,54 @@
+//! Validation of input arrays
+//!
+//! LAPACK routines do not check their inputs for NaN or infinity, and may
+//! return misleading errors or meaningless results for such inputs.
+//! [ValidateFinite] checks an array up front and reports the first
+//! non-finite element.
+//!
+//! When the `strict-input` feature is enabled, the decomposition routines of
+//! this crate (LU, Cholesky, QR, eigenvalue and singular value
+//! decompositions, and least squares) run this check before calling LAPACK.
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::error::*;
+use crate::types::*;
+
+/// Check that all elements of an array are finite
+pub trait ValidateFinite {
+    /// Returns `LinalgError::InvalidInput` with the index of the first
+    /// element (in logical order) which is NaN or infinite.
+    fn validate_finite(&self) -> Result<()>;
+}
+
+impl<A, S, D> ValidateFinite for ArrayBase<S, D>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn validate_finite(&self) -> Result<()> {
+        match self
+            .indexed_iter()
+            .find(|(_, x)| !(Float::is_finite(x.re()) && Float::is_finite(x.im())))
+        {
+            Some((index, _)) => Err(LinalgError::InvalidInput {
+                index: index.into_dimension().slice().to_vec(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Validate inputs of decompositions if the `strict-input` feature is enabled
+#[cfg(feature = "strict-input")]
+pub(crate) fn check_input<T: ValidateFinite>(a: &T) -> Result<()> {
+    a.validate_finite()
+}
+
+/// Validate inputs of decompositions if the `strict-input` feature is enabled
+#[cfg(not(feature = "strict-input"))]
+pub(crate) fn check_input<T: ValidateFinite>(_a: &T) -> Result<()> {
+    Ok(())
+}
@@ -64,3 +64,34 @@ fn aq_qh_householder_complex() {
     println!("QH = \n{:?}", &qh);
     close_l2(&aq, &qh, 1e-9);
 }
+
+#[test]
+fn ritz_values_dominant() {
+    const N: usize = 40;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // nonsymmetric matrix with the dominant eigenvalues 10 and -8
+    let mut d: Array1<f64> = random_using(N, &mut rng);
+    d[0] = 10.0;
+    d[1] = -8.0;
+    let s: Array2<f64> = random_regular_using(N, &mut rng);
+    let a = s.dot(&Array2::from_diag(&d)).dot(&s.inv().unwrap());
+
+    let v: Array1<f64> = random_using(N, &mut rng);
+    let mut arnoldi = Arnoldi::new(a.clone(), v, MGS::new(N, 1e-9));
+    for _ in (&mut arnoldi).take(20) {}
+    let decomp = arnoldi.into_decomposition();
+    assert_eq!(decomp.dim(), 20);
+
+    let ritz = decomp.ritz_values().unwrap();
+    assert!((ritz[0] - c64::new(10.0, 0.0)).norm() < 1e-6);
+    assert!((ritz[1] - c64::new(-8.0, 0.0)).norm() < 1e-6);
+
+    let (e, x) = decomp.ritz_vectors().unwrap();
+    assert_close_l2!(&e, &ritz, 1e-9);
+    let ac = a.mapv(|x| c64::new(x, 0.0));
+    for i in 0..2 {
+        let xi = x.column(i);
+        let residual = ac.dot(&xi) - xi.mapv(|x| x * e[i]);
+        assert!(residual.norm_l2() < 1e-5 * xi.norm_l2());
+    }
+}
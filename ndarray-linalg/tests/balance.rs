@@ -0,0 +1,55 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn sorted(eigs: &Array1<c64>) -> Array1<c64> {
+    let mut v = eigs.to_vec();
+    v.sort_by(|a, b| {
+        a.re.partial_cmp(&b.re)
+            .unwrap()
+            .then(a.im.partial_cmp(&b.im).unwrap())
+    });
+    Array1::from(v)
+}
+
+macro_rules! impl_test_balance {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<balance_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let r: Array2<$elem> = random_using((4, 4), &mut rng);
+                // badly scaled similarity transform of r
+                let d0 = [1.0, 1e3, 1e-3, 1e6];
+                let a = Array2::from_shape_fn((4, 4), |(i, j)| r[(i, j)].mul_real(d0[i] / d0[j]));
+                let a_t = a.t().to_owned();
+                let mut a_f: Array2<$elem> = Array2::zeros((4, 4).f());
+                a_f.assign(&a);
+                for a in [a.view(), a_f.view(), a_t.view()] {
+                    let (b, d) = a.balance().unwrap();
+                    // applying the scaling to the balanced matrix restores the original one
+                    let a2 = Array2::from_shape_fn((4, 4), |(i, j)| b[(i, j)].mul_real(d[i] / d[j]));
+                    assert_close_l2!(&a2, &a, $rtol);
+                    // and undoing it balances again
+                    let b2 = Array2::from_shape_fn((4, 4), |(i, j)| a[(i, j)].mul_real(d[j] / d[i]));
+                    assert_close_l2!(&b2, &b, $rtol);
+
+                    let e_a = a.eigvals().unwrap();
+                    let (e_b, v_b) = b.eig().unwrap();
+                    assert_close_l2!(&sorted(&e_b.mapv(|e| e.as_c())), &sorted(&e_a.mapv(|e| e.as_c())), 1e-6);
+
+                    // eigenvectors mapped back
+                    let v = unbalance(&d, &v_b);
+                    let a_c = a.mapv(|x| x.as_c());
+                    for (e, v) in e_b.iter().zip(v.axis_iter(Axis(1))) {
+                        let v = v.mapv(|x| x.as_c());
+                        let e = e.as_c();
+                        assert_close_l2!(&a_c.dot(&v), &v.mapv(|x| x * e), 1e-6);
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_test_balance!(f64, 1e-14);
+impl_test_balance!(c64, 1e-14);
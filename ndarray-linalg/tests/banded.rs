@@ -0,0 +1,178 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Random Hermitian matrix whose elements vanish outside of the band
+fn random_hermite_banded<T: Scalar + Lapack>(n: usize, kd: usize) -> Array2<T> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<T> = random_hermite_using(n, &mut rng);
+    for ((i, j), v) in a.indexed_iter_mut() {
+        if i.max(j) - i.min(j) > kd {
+            *v = T::zero();
+        }
+    }
+    a
+}
+
+fn test_eigh_banded<T: Scalar + Lapack>(a: &Array2<T>, kd: usize) {
+    let banded = a.extract_banded(kd).unwrap();
+    assert_close_l2!(&banded.to_dense(), a, T::real(1e-12));
+    let (e0, _) = a.eigh(UPLO::Upper).unwrap();
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let (e, v) = banded.eigh_banded(uplo).unwrap();
+        assert_close_l2!(&e.mapv(T::from_real), &e0.mapv(T::from_real), T::real(1e-9));
+        let av = a.dot(&v);
+        let ev = v.dot(&Array2::from_diag(&e.mapv(T::from_real)));
+        assert_close_l2!(&av, &ev, T::real(1e-9));
+        let e = banded.eigvalsh_banded(uplo).unwrap();
+        assert_close_l2!(&e.mapv(T::from_real), &e0.mapv(T::from_real), T::real(1e-9));
+    }
+}
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<eigh_banded_ $elem>]() {
+                for kd in 0..4 {
+                    let a: Array2<$elem> = random_hermite_banded(6, kd);
+                    test_eigh_banded(&a, kd);
+                }
+            }
+
+            #[test]
+            fn [<eigh_banded_one_sided_ $elem>]() {
+                // Only the referred triangle is stored
+                let a: Array2<$elem> = random_hermite_banded(6, 2);
+                let (e0, _) = a.eigh(UPLO::Upper).unwrap();
+                let upper = a.extract_banded_with(0, 2).unwrap();
+                let e = upper.eigvalsh_banded(UPLO::Upper).unwrap();
+                assert_close_l2!(&e, &e0, 1e-9);
+                let lower = a.extract_banded_with(2, 0).unwrap();
+                let e = lower.eigvalsh_banded(UPLO::Lower).unwrap();
+                assert_close_l2!(&e, &e0, 1e-9);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
+
+#[test]
+fn banded_storage() {
+    let a: Array2<f64> = array![
+        [1.0, 2.0, 3.0, 0.0],
+        [4.0, 5.0, 6.0, 7.0],
+        [0.0, 8.0, 9.0, 10.0],
+        [0.0, 0.0, 11.0, 12.0]
+    ];
+    let banded = a.extract_banded_with(1, 2).unwrap();
+    let ab = array![
+        [0.0, 0.0, 3.0, 7.0],
+        [0.0, 2.0, 6.0, 10.0],
+        [1.0, 5.0, 9.0, 12.0],
+        [4.0, 8.0, 11.0, 0.0]
+    ];
+    assert_eq!(banded.ab, ab);
+    assert_eq!(banded.to_dense(), a);
+    assert_eq!(BandedMatrix::from_band(1, 2, &ab).unwrap(), banded);
+    assert!(BandedMatrix::from_band(1, 1, &ab).is_err());
+}
+
+/// Random well-conditioned matrix whose elements vanish outside of the band
+fn random_banded<T: Scalar + Lapack>(n: usize, kl: usize, ku: usize) -> Array2<T> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<T> = random_using((n, n), &mut rng);
+    for ((i, j), v) in a.indexed_iter_mut() {
+        if i > j + kl || j > i + ku {
+            *v = T::zero();
+        } else if i == j {
+            *v += T::from_real(T::real(n as f64));
+        }
+    }
+    a
+}
+
+macro_rules! impl_test_solve {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<solve_banded_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // 7-diagonal matrix
+                let a: Array2<$elem> = random_banded(8, 3, 3);
+                let banded = a.extract_banded(3).unwrap();
+                let b: Array1<$elem> = random_using(8, &mut rng);
+                assert_close_l2!(&banded.solve_banded(&b).unwrap(), &a.solve(&b).unwrap(), 1e-9);
+                assert_close_l2!(&banded.solve_t_banded(&b).unwrap(), &a.solve_t(&b).unwrap(), 1e-9);
+                assert_close_l2!(&banded.solve_h_banded(&b).unwrap(), &a.solve_h(&b).unwrap(), 1e-9);
+            }
+
+            #[test]
+            fn [<solve_banded_multi_rhs_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_banded(8, 2, 3);
+                let f = a.extract_banded_with(2, 3).unwrap().factorize_banded().unwrap();
+                let b: Array2<$elem> = random_using((8, 3), &mut rng);
+                let x = f.solve_banded(&b).unwrap();
+                assert_close_l2!(&a.dot(&x), &b, 1e-9);
+                let b: Array2<$elem> = random_using((8, 3).f(), &mut rng);
+                let x = f.solve_banded_into(b.clone()).unwrap();
+                assert_close_l2!(&a.dot(&x), &b, 1e-9);
+                // non-contiguous right hand sides
+                let b: Array2<$elem> = random_using((8, 6), &mut rng);
+                let x = f.solve_banded(&b.slice(s![.., ..;2])).unwrap();
+                assert_close_l2!(&a.dot(&x), &b.slice(s![.., ..;2]), 1e-9);
+                assert!(f.solve_banded(&Array1::<$elem>::zeros(7)).is_err());
+            }
+        }
+    };
+}
+
+impl_test_solve!(f64);
+impl_test_solve!(c64);
+
+/// Random Hermitian positive definite matrix whose elements vanish outside of the band
+fn random_hpd_banded<T: Scalar + Lapack>(n: usize, kd: usize) -> Array2<T> {
+    let mut a: Array2<T> = random_hermite_banded(n, kd);
+    for i in 0..n {
+        a[(i, i)] += T::from_real(T::real(2 * n));
+    }
+    a
+}
+
+macro_rules! impl_test_cholesky {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_banded_ $elem>]() {
+                // pentadiagonal matrix
+                let a: Array2<$elem> = random_hpd_banded(8, 2);
+                let banded = a.extract_banded(2).unwrap();
+                for uplo in [UPLO::Upper, UPLO::Lower] {
+                    let factor = banded.cholesky_banded(uplo).unwrap();
+                    assert_close_l2!(&factor.to_dense(), &a.cholesky(uplo).unwrap(), 1e-9);
+                }
+            }
+
+            #[test]
+            fn [<solvec_banded_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_banded(8, 2);
+                let banded = a.extract_banded(2).unwrap();
+                let b: Array1<$elem> = random_using(8, &mut rng);
+                let x0 = a.solvec(&b).unwrap();
+                for uplo in [UPLO::Upper, UPLO::Lower] {
+                    let f = banded.factorizec_banded(uplo).unwrap();
+                    assert_close_l2!(&f.solvec_banded(&b).unwrap(), &x0, 1e-9);
+                    let b: Array2<$elem> = random_using((8, 3), &mut rng);
+                    let x = f.solvec_banded(&b).unwrap();
+                    assert_close_l2!(&a.dot(&x), &b, 1e-9);
+                }
+            }
+        }
+    };
+}
+
+impl_test_cholesky!(f64);
+impl_test_cholesky!(c64);
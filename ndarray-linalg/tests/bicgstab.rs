@@ -0,0 +1,69 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn bicgstab_nonsymmetric<A: Scalar + Lapack>() {
+    const N: usize = 12;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // moderately conditioned nonsymmetric matrix by diagonal shift
+    let shift = Array2::<A>::eye(N).mapv(|e| e.mul_real(A::real(5.0)));
+    let a: Array2<A> = random_using((N, N), &mut rng) + shift;
+    let b: Array1<A> = random_using(N, &mut rng);
+    let expected = a.solve(&b).unwrap();
+
+    let (x, history) = bicgstab(|v| a.dot(&v), &b, A::real(1e-12), 200).unwrap();
+    assert!(*history.last().unwrap() <= A::real(1e-12));
+    assert!(history.len() <= 201);
+    assert_close_l2!(&x, &expected, A::real(1e-8));
+}
+
+#[test]
+fn bicgstab_nonsymmetric_real() {
+    bicgstab_nonsymmetric::<f64>();
+}
+
+#[test]
+fn bicgstab_nonsymmetric_complex() {
+    bicgstab_nonsymmetric::<c64>();
+}
+
+#[test]
+fn bicgstab_zero_rhs() {
+    let a: Array2<f64> = Array2::eye(3);
+    let (x, history) = bicgstab(|v| a.dot(&v), &Array1::zeros(3), 1e-12, 10).unwrap();
+    assert_eq!(x, Array1::zeros(3));
+    assert_eq!(history.len(), 1);
+}
+
+#[test]
+fn bicgstab_breakdown() {
+    // `(b, A b) = 0` for a skew-symmetric `A`
+    let a = array![[0.0, 1.0], [-1.0, 0.0]];
+    let b = array![1.0, 2.0];
+    match bicgstab(|v| a.dot(&v), &b, 1e-12, 10) {
+        Err(error::LinalgError::Breakdown { iteration }) => assert_eq!(iteration, 1),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn bicgstab_backward_error() {
+    const N: usize = 12;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // badly scaled system
+    let a: Array2<f64> = (random_using((N, N), &mut rng) + Array2::<f64>::eye(N) * 5.0) * 1e8;
+    let b: Array1<f64> = random_using(N, &mut rng);
+    let a_norm = a.opnorm_fro().unwrap();
+
+    let (x, history) = bicgstab_with_criterion(
+        |v| a.dot(&v),
+        &b,
+        1e-12,
+        200,
+        StoppingCriterion::BackwardError { a_norm },
+    )
+    .unwrap();
+    let r = &b - &a.dot(&x);
+    let backward_error = r.norm_l2() / (a_norm * x.norm_l2() + b.norm_l2());
+    assert!(backward_error < 1e-12);
+    assert_rclose!(*history.last().unwrap(), backward_error, 1e-3);
+}
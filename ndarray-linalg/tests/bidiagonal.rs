@@ -0,0 +1,50 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let f = a.bidiagonal().unwrap();
+    assert_eq!(f.q.dim(), (m, k));
+    assert_eq!(f.pt.dim(), (k, n));
+    assert_eq!(f.uplo, if m >= n { UPLO::Upper } else { UPLO::Lower });
+
+    let qh: Array2<T> = conjugate(&f.q);
+    let p: Array2<T> = conjugate(&f.pt);
+    assert_close_l2!(&qh.dot(&f.q), &Array2::eye(k), rtol);
+    assert_close_l2!(&f.pt.dot(&p), &Array2::eye(k), rtol);
+
+    let b = f.b().mapv(T::from_real);
+    assert_close_l2!(&f.q.dot(&b).dot(&f.pt), a, rtol);
+    for ((i, j), &b) in b.indexed_iter() {
+        let on_band = match f.uplo {
+            UPLO::Upper => j == i || j == i + 1,
+            UPLO::Lower => j == i || i == j + 1,
+        };
+        if !on_band {
+            assert_eq!(b, T::zero());
+        }
+    }
+}
+
+macro_rules! impl_test {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<bidiagonal_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &shape in &[(4, 4), (5, 3), (3, 5)] {
+                    let a: Array2<$elem> = random_using(shape, &mut rng);
+                    test(&a, $rtol);
+                    let a: Array2<$elem> = random_using(shape.f(), &mut rng);
+                    test(&a, $rtol);
+                }
+            }
+        }
+    };
+}
+
+impl_test!(f64, 1e-9);
+impl_test!(f32, 1e-4);
+impl_test!(c64, 1e-9);
+impl_test!(c32, 1e-4);
@@ -0,0 +1,46 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn center(x: &Array2<f64>) -> Array2<f64> {
+    x - &x.mean_axis(Axis(0)).unwrap()
+}
+
+#[test]
+fn cca_correlations() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 50;
+    let x: Array2<f64> = random_using((n, 3), &mut rng);
+    let noise: Array2<f64> = random_using((n, 2), &mut rng);
+    // y depends linearly on the first column of x with small noise
+    let mut y = noise.mapv(|v| 0.01 * v);
+    y.column_mut(0).scaled_add(2.0, &x.column(0));
+    y.column_mut(1).scaled_add(-1.0, &x.column(1));
+
+    let res = cca(&x.view(), &y.view(), 2).unwrap();
+    assert_eq!(res.correlations.len(), 2);
+    assert_eq!(res.x_weights.dim(), (3, 2));
+    assert_eq!(res.y_weights.dim(), (2, 2));
+    assert!(res.correlations[0] >= res.correlations[1]);
+    assert!(res.correlations[1] > 0.99);
+
+    // The canonical variates are orthonormal, and their inner products are
+    // the canonical correlations
+    let u = center(&x).dot(&res.x_weights);
+    let v = center(&y).dot(&res.y_weights);
+    assert_close_l2!(&u.t().dot(&u), &Array2::eye(2), 1e-9);
+    assert_close_l2!(&v.t().dot(&v), &Array2::eye(2), 1e-9);
+    assert_close_l2!(&u.t().dot(&v), &Array2::from_diag(&res.correlations), 1e-9);
+}
+
+#[test]
+fn cca_truncate() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let x: Array2<f64> = random_using((20, 3), &mut rng);
+    let y: Array2<f64> = random_using((20, 2), &mut rng);
+    let res = cca(&x.view(), &y.view(), 5).unwrap();
+    assert_eq!(res.correlations.len(), 2);
+    assert!(res
+        .correlations
+        .iter()
+        .all(|&c| (0.0..=1.0 + 1e-12).contains(&c)));
+}
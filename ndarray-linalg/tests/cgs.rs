@@ -0,0 +1,61 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn cgs<A: Scalar + Lapack>(reorthogonalize: bool) {
+    let rtol: A::Real = A::real(1e-9);
+
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<A> = random_using((5, 3), &mut rng);
+    let (q, r) = gram_schmidt(&a, reorthogonalize).unwrap();
+    assert_close_l2!(&q.dot(&r), &a, rtol);
+    assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, rtol);
+
+    let qc: Array2<A> = conjugate(&q);
+    assert_close_l2!(&qc.dot(&q), &Array::eye(3), rtol);
+}
+
+#[test]
+fn cgs_real() {
+    cgs::<f64>(false);
+}
+
+#[test]
+fn cgs_complex() {
+    cgs::<c64>(false);
+}
+
+#[test]
+fn cgs2_real() {
+    cgs::<f64>(true);
+}
+
+#[test]
+fn cgs2_complex() {
+    cgs::<c64>(true);
+}
+
+#[test]
+fn cgs2_ill_conditioned() {
+    // Nearly rank-deficient matrix with singular values 1, ..., 1e-10
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let u: Array2<f64> = random_unitary_using(8, &mut rng);
+    let v: Array2<f64> = random_unitary_using(6, &mut rng);
+    let s = Array1::from_iter((0..6).map(|i| 10f64.powi(-2 * i)));
+    let a = u.slice(s![.., ..6]).dot(&Array2::from_diag(&s)).dot(&v.t());
+
+    let loss = |q: &Array2<f64>| (q.t().dot(q) - Array2::<f64>::eye(6)).norm_max();
+
+    let (q1, _) = gram_schmidt(&a, false).unwrap();
+    let (q2, r2) = gram_schmidt(&a, true).unwrap();
+    let (qh, _) = a.qr().unwrap();
+    assert!(loss(&q2) < 1e-12);
+    assert!(loss(&qh) < 1e-12);
+    assert!(loss(&q2) < loss(&q1));
+    assert_close_l2!(&q2.dot(&r2), &a, 1e-12);
+}
+
+#[test]
+fn cgs_wide() {
+    let a: Array2<f64> = Array::zeros((2, 3));
+    assert!(gram_schmidt(&a, true).is_err());
+}
@@ -1,5 +1,5 @@
 use ndarray::*;
-use ndarray_linalg::*;
+use ndarray_linalg::{error::LinalgError, *};
 
 macro_rules! cholesky {
     ($elem:ty, $rtol:expr) => {
@@ -231,3 +231,178 @@ cholesky_solve!(f64, 1e-9);
 cholesky_solve!(f32, 1e-3);
 cholesky_solve!(c64, 1e-9);
 cholesky_solve!(c32, 1e-3);
+
+macro_rules! cholesky_solve_multi_rhs {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_solve_multi_rhs_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(3, &mut rng);
+                // row-major, column-major, and non-contiguous right hand sides
+                let b_c: Array2<$elem> = random_using((3, 5), &mut rng);
+                let b_f: Array2<$elem> = random_using((3, 5).f(), &mut rng);
+                let b_s: Array2<$elem> = random_using((3, 10), &mut rng);
+                for b in [b_c.view(), b_f.view(), b_s.slice(s![.., ..;2])] {
+                    for uplo in [UPLO::Upper, UPLO::Lower] {
+                        let f = a.factorizec(uplo).unwrap();
+                        let x = f.solvec(&b).unwrap();
+                        for (j, col) in b.axis_iter(Axis(1)).enumerate() {
+                            let xj = f.solvec(&col.to_owned()).unwrap();
+                            assert_close_l2!(&x.column(j), &xj, $rtol);
+                        }
+                        assert_close_l2!(&a.dot(&x), &b, $rtol);
+                        assert_close_l2!(&f.solvec_into(b.to_owned()).unwrap(), &x, $rtol);
+                    }
+                    let mut x = b.to_owned();
+                    a.solvec_inplace(&mut x).unwrap();
+                    assert_close_l2!(&a.dot(&x), &b, $rtol);
+                }
+            }
+        }
+    };
+}
+cholesky_solve_multi_rhs!(f64, 1e-9);
+cholesky_solve_multi_rhs!(f32, 1e-3);
+cholesky_solve_multi_rhs!(c64, 1e-9);
+cholesky_solve_multi_rhs!(c32, 1e-3);
+
+macro_rules! gp_log_marginal_likelihood {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<gp_log_marginal_likelihood_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let k: Array2<$elem> = random_hpd_using(4, &mut rng);
+                let y: Array1<$elem> = random_using(4, &mut rng);
+                let noise = 0.1;
+                let lml = gp_log_marginal_likelihood(&k.view(), &y.view(), noise).unwrap();
+
+                let kn = &k + &(Array2::<$elem>::eye(4) * noise);
+                let expected = -0.5 * y.dot(&kn.inv().unwrap().dot(&y))
+                    - 0.5 * kn.det().unwrap().ln()
+                    - 2.0 * (2.0 * std::f64::consts::PI as $elem).ln();
+                assert_rclose!(lml, expected, $rtol);
+            }
+        }
+    };
+}
+gp_log_marginal_likelihood!(f64, 1e-9);
+gp_log_marginal_likelihood!(f32, 1e-3);
+
+macro_rules! cholesky_rank1 {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_rank1_update_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(4, &mut rng);
+                let x: Array1<$elem> = random_using(4, &mut rng);
+                let xxh = Array2::from_shape_fn((4, 4), |(i, j)| x[i] * x[j].conj());
+
+                let mut l = a.cholesky(UPLO::Lower).unwrap();
+                cholesky_rank1_update(&mut l, &x).unwrap();
+                let ans = (&a + &xxh).cholesky(UPLO::Lower).unwrap();
+                assert_close_l2!(&l, &ans, $rtol);
+
+                // downdate back to the original factor
+                cholesky_rank1_downdate(&mut l, &x).unwrap();
+                assert_close_l2!(&l, &a.cholesky(UPLO::Lower).unwrap(), $rtol);
+            }
+
+            #[test]
+            fn [<cholesky_rank1_downdate_not_pd_ $elem>]() {
+                // diag(1, 4) - x x^H with x = (0, 3) is not positive definite
+                let a: Array2<$elem> = Array2::from_diag(&array![1.0, 4.0].mapv(<$elem>::from_real));
+                let x: Array1<$elem> = array![0.0, 3.0].mapv(<$elem>::from_real);
+                let mut l = a.cholesky(UPLO::Lower).unwrap();
+                match cholesky_rank1_downdate(&mut l, &x) {
+                    Err(LinalgError::NotPositiveDefinite { minor }) => assert_eq!(minor, 2),
+                    _ => panic!("should fail"),
+                }
+            }
+        }
+    };
+}
+
+cholesky_rank1!(f64, 1e-9);
+cholesky_rank1!(c64, 1e-9);
+
+#[test]
+fn cholesky_uplo_c_layout() {
+    // Only the upper triangle is filled, as it is displayed
+    let a = arr2(&[[4.0, 2.0], [0.0, 5.0]]);
+    assert!(a.is_standard_layout());
+    let u = a.cholesky(UPLO::Upper).unwrap();
+    assert_close_l2!(&u.t().dot(&u), &arr2(&[[4.0, 2.0], [2.0, 5.0]]), 1e-9);
+}
+
+macro_rules! cholesky_pivot {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_pivot_rank_deficient_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let x: Array2<$elem> = random_using((5, 2), &mut rng);
+                // positive semidefinite with rank 2
+                let a: Array2<$elem> = x.dot(&x.t().mapv(|v| v.conj()));
+                let mut a_f: Array2<$elem> = Array2::zeros((5, 5).f());
+                a_f.assign(&a);
+                for a in [a.view(), a_f.view()] {
+                    assert!(a.cholesky(UPLO::Lower).is_err());
+                    for uplo in [UPLO::Upper, UPLO::Lower] {
+                        let c = a.cholesky_pivot(uplo, None).unwrap();
+                        assert_eq!(c.rank, 2);
+                        let mut perm = c.perm.clone();
+                        perm.sort_unstable();
+                        assert_eq!(perm, (0..5).collect::<Vec<_>>());
+                        let pap = Array2::from_shape_fn((5, 5), |(i, j)| a[(c.perm[i], c.perm[j])]);
+                        let fh = c.factor.t().mapv(|v| v.conj());
+                        let product = match uplo {
+                            UPLO::Upper => fh.dot(&c.factor),
+                            UPLO::Lower => c.factor.dot(&fh),
+                        };
+                        let r = c.rank;
+                        assert_close_l2!(&product.slice(s![..r, ..r]), &pap.slice(s![..r, ..r]), $rtol);
+                        assert_close_l2!(&product, &pap, $rtol);
+                    }
+                }
+            }
+
+            #[test]
+            fn [<cholesky_pivot_full_rank_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(4, &mut rng);
+                let c = a.cholesky_pivot(UPLO::Lower, None).unwrap();
+                assert_eq!(c.rank, 4);
+                let pap = Array2::from_shape_fn((4, 4), |(i, j)| a[(c.perm[i], c.perm[j])]);
+                let fh = c.factor.t().mapv(|v| v.conj());
+                assert_close_l2!(&c.factor.dot(&fh), &pap, $rtol);
+            }
+        }
+    };
+}
+
+cholesky_pivot!(f64, 1e-9);
+cholesky_pivot!(c64, 1e-9);
+
+#[test]
+fn cholesky_not_positive_definite_minor() {
+    // The leading minors of order 1 and 2 are positive, but not of order 3
+    let a: Array2<f64> = array![
+        [4.0, 2.0, 0.0, 0.0],
+        [2.0, 5.0, 3.0, 0.0],
+        [0.0, 3.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0]
+    ];
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        match a.cholesky(uplo) {
+            Err(LinalgError::NotPositiveDefinite { minor }) => assert_eq!(minor, 3),
+            _ => panic!("should fail"),
+        }
+        match a.factorizec(uplo) {
+            Err(LinalgError::NotPositiveDefinite { minor }) => assert_eq!(minor, 3),
+            _ => panic!("should fail"),
+        }
+    }
+}
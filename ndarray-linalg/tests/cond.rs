@@ -0,0 +1,89 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Matrix `U diag(s) V^H` with random unitary `U` and `V`
+fn with_singular_values<T: Scalar + Lapack>(s: &[f64], m: usize) -> Array2<T> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = s.len();
+    let u: Array2<T> = random_using((n, n), &mut rng);
+    let (u, _) = u.qr().unwrap();
+    let v: Array2<T> = random_using((m, n), &mut rng);
+    let (v, _) = v.qr().unwrap();
+    let s = Array1::from_iter(s.iter().map(|&s| T::from_real(T::real(s))));
+    let vh: Array2<T> = conjugate(&v);
+    u.dot(&Array2::from_diag(&s)).dot(&vh)
+}
+
+fn test_cond<T: Scalar + Lapack>() {
+    let a: Array2<T> = with_singular_values(&[8.0, 2.0, 0.5], 3);
+    assert_rclose!(a.cond(None).unwrap(), T::real(16.0), T::real(1e-9));
+    let fro = ((64.0 + 4.0 + 0.25) * (1.0 / 64.0 + 1.0 / 4.0 + 4.0)).sqrt();
+    assert_rclose!(
+        a.cond(Some(NormType::Frobenius)).unwrap(),
+        T::real(fro),
+        T::real(1e-9)
+    );
+    // rectangular matrix for 2-norm
+    let a: Array2<T> = with_singular_values(&[3.0, 1.5], 5);
+    assert_rclose!(a.cond(None).unwrap(), T::real(2.0), T::real(1e-9));
+    assert!(a.cond(Some(NormType::One)).is_err());
+}
+
+fn test_cond_rcond<T: Scalar + Lapack>() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_regular_using(4, &mut rng);
+    let rcond = a.rcond().unwrap();
+    assert_rclose!(
+        a.cond(Some(NormType::One)).unwrap(),
+        T::real(1.0) / rcond,
+        T::real(1e-9)
+    );
+    let rcond_t = a.t().to_owned().rcond().unwrap();
+    assert_rclose!(
+        a.cond(Some(NormType::Infinity)).unwrap(),
+        T::real(1.0) / rcond_t,
+        T::real(1e-9)
+    );
+    // κ_1 <= n κ_2 holds for the exact 1-norm condition number, and the
+    // estimate does not exceed it
+    let k2 = a.cond(None).unwrap();
+    assert!(a.cond(Some(NormType::One)).unwrap() <= T::real(4.0 * (1.0 + 1e-9)) * k2);
+}
+
+fn test_cond_singular<T: Scalar + Lapack>() {
+    let a: Array2<T> = with_singular_values(&[2.0, 1.0, 0.0], 3);
+    assert!(a.cond(None).unwrap() > T::real(1e12));
+    let a = Array2::<T>::zeros((3, 3));
+    for norm in [
+        None,
+        Some(NormType::One),
+        Some(NormType::Infinity),
+        Some(NormType::Frobenius),
+    ] {
+        assert_eq!(a.cond(norm).unwrap(), T::real(f64::INFINITY));
+    }
+}
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<cond_ $elem>]() {
+                test_cond::<$elem>();
+            }
+
+            #[test]
+            fn [<cond_rcond_ $elem>]() {
+                test_cond_rcond::<$elem>();
+            }
+
+            #[test]
+            fn [<cond_singular_ $elem>]() {
+                test_cond_singular::<$elem>();
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
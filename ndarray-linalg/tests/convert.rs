@@ -9,3 +9,140 @@ fn generalize() {
     let a: Array3<f64> = convert::generalize(a);
     assert_eq!(a, ans);
 }
+
+#[test]
+fn block_diag() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((2, 2), &mut rng);
+    let b: Array2<f64> = random_using((3, 1), &mut rng);
+    let c: Array2<f64> = random_using((1, 3), &mut rng);
+    let d = convert::block_diag(&[&a, &b, &c]);
+    assert_eq!(d.dim(), (6, 6));
+    assert_eq!(d.slice(s![0..2, 0..2]), a);
+    assert_eq!(d.slice(s![2..5, 2..3]), b);
+    assert_eq!(d.slice(s![5..6, 3..6]), c);
+    let nonzero = d.iter().filter(|x| **x != 0.0).count();
+    assert_eq!(nonzero, a.len() + b.len() + c.len());
+}
+
+#[test]
+fn block_diag_trivial() {
+    let d = convert::block_diag::<f64>(&[]);
+    assert_eq!(d.dim(), (0, 0));
+    let a = arr2(&[[1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(convert::block_diag(&[&a]), a);
+}
+
+#[test]
+fn block_diag_solve() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_regular_using(3, &mut rng);
+    let b: Array2<f64> = random_regular_using(2, &mut rng);
+    let xa: Array1<f64> = random_using(3, &mut rng);
+    let xb: Array1<f64> = random_using(2, &mut rng);
+    let d = convert::block_diag(&[&a, &b]);
+    let rhs = concatenate![Axis(0), a.dot(&xa), b.dot(&xb)];
+    let x = d.solve(&rhs).unwrap();
+    assert_close_l2!(&x.slice(s![..3]), &xa, 1e-7);
+    assert_close_l2!(&x.slice(s![3..]), &xb, 1e-7);
+}
+
+#[test]
+fn stack_layout() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for k in [1, 3] {
+        let xs: Vec<Array1<f64>> = (0..k).map(|_| random_using(4, &mut rng)).collect();
+        let h = hstack(&xs).unwrap();
+        assert_eq!(h.dim(), (4, k));
+        assert!(h.layout().is_ok());
+        let v = vstack(&xs).unwrap();
+        assert_eq!(v.dim(), (k, 4));
+        assert!(v.layout().is_ok());
+        for (i, x) in xs.iter().enumerate() {
+            assert_eq!(h.column(i), x);
+            assert_eq!(v.row(i), x);
+        }
+    }
+}
+
+#[test]
+fn hstack_least_squares() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let cols: Vec<Array1<f64>> = (0..3).map(|_| random_using(6, &mut rng)).collect();
+    let a = hstack(&cols).unwrap();
+    let x: Array1<f64> = random_using(3, &mut rng);
+    let b = a.dot(&x);
+    let result = a.least_squares(&b).unwrap();
+    assert_close_l2!(&result.solution, &x, 1e-7);
+}
+
+#[test]
+fn complex_parts_roundtrip() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((3, 4), &mut rng);
+    let c: Array2<c64> = a.to_complex();
+    assert_eq!(c.real_part(), a);
+    assert_eq!(c.imag_part(), Array2::<f64>::zeros((3, 4)));
+    assert_eq!(a.imag_part(), Array2::<f64>::zeros((3, 4)));
+
+    let z: Array2<c64> = random_using((3, 4), &mut rng);
+    let re = z.real_part().to_complex();
+    let im = z.imag_part().to_complex();
+    assert_eq!(re + im * c64::new(0.0, 1.0), z);
+}
+
+#[test]
+fn complex_parts_eig() {
+    // Rotation by 90 degrees has eigenvalues +i and -i
+    let a = arr2(&[[0.0, -1.0], [1.0, 0.0]]);
+    let (e, vecs) = a.eig().unwrap();
+    assert_close_l2!(&e.real_part(), &arr1(&[0.0, 0.0]), 1e-7);
+    let mut im: Vec<f64> = e.imag_part().to_vec();
+    im.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    assert_close_l2!(&arr1(&im), &arr1(&[-1.0, 1.0]), 1e-7);
+    // A v = lambda v
+    let av = a.to_complex().dot(&vecs);
+    assert_close_l2!(&av, &(&vecs * &e), 1e-7);
+}
+
+#[test]
+fn symmetrize() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((4, 4), &mut rng);
+    let mut s = a.clone();
+    s.symmetrize().unwrap();
+    assert_eq!(s, s.t());
+    assert_close_l2!(&s, &((&a + &a.t()) / c64::new(2.0, 0.0)), 1e-12);
+    assert!(Array2::<f64>::zeros((2, 3)).symmetrize().is_err());
+}
+
+#[test]
+fn hermitianize() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((4, 4), &mut rng);
+    let ah: Array2<c64> = conjugate(&a);
+    let mut h = a.clone();
+    h.hermitianize().unwrap();
+    let hh: Array2<c64> = conjugate(&h);
+    assert_eq!(h, hh);
+    assert_close_l2!(&h, &((&a + &ah) / c64::new(2.0, 0.0)), 1e-12);
+    assert!(Array2::<c64>::zeros((3, 2)).hermitianize().is_err());
+}
+
+#[test]
+fn hermitianize_drifted_eigh() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hpd_using(5, &mut rng);
+    let e = a.eigvalsh(UPLO::Lower).unwrap();
+    // Perturb only one triangle as accumulated rounding errors would do
+    let mut drifted = a.clone();
+    drifted[(0, 1)] += c64::new(1e-9, 1e-9);
+    drifted[(3, 3)] += c64::new(0.0, 1e-9);
+    drifted.hermitianize().unwrap();
+    let hh: Array2<c64> = conjugate(&drifted);
+    assert_eq!(drifted, hh);
+    let (e_upper, _) = drifted.eigh(UPLO::Upper).unwrap();
+    let (e_lower, _) = drifted.eigh(UPLO::Lower).unwrap();
+    assert_close_l2!(&e_upper, &e_lower, 1e-12);
+    assert_close_l2!(&e_upper, &e, 1e-7);
+}
@@ -146,3 +146,35 @@ fn deth_nonsquare() {
         }
     }
 }
+
+#[test]
+fn deth_2x2_blocks() {
+    macro_rules! deth_2x2_blocks {
+        ($elem:ty, $rows:expr, $atol:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            for _ in 0..4 {
+                // Hermitian indefinite matrix with vanishing diagonal, which
+                // requires 2x2 pivots in the Bunch-Kaufman factorization
+                let mut a: Array2<$elem> = random_hermite_using($rows, &mut rng);
+                a.diag_mut().fill(Zero::zero());
+                for a in [a.clone(), a.reversed_axes()] {
+                    let f = a.factorizeh().unwrap();
+                    assert!(f.ipiv.iter().any(|&p| p < 0));
+                    let (sign, ln_det) = a.sln_det().unwrap();
+                    let (signh, ln_deth) = f.sln_deth();
+                    assert_aclose!(<$elem>::from_real(signh), sign, $atol);
+                    assert_aclose!(ln_deth, ln_det, $atol);
+                    assert_aclose!(
+                        <$elem>::from_real(a.deth().unwrap()),
+                        a.det().unwrap(),
+                        $atol
+                    );
+                }
+            }
+        };
+    }
+    for rows in 2..7 {
+        deth_2x2_blocks!(f64, rows, 1e-9);
+        deth_2x2_blocks!(c64, rows, 1e-9);
+    }
+}
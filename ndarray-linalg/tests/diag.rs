@@ -26,3 +26,37 @@ fn diag_2d_multi() {
     println!("dm = {:?}", dm);
     assert_close_l2!(&dm, &arr2(&[[1.0, 1.0], [2.0, 2.0]]), 1e-7);
 }
+
+#[test]
+fn diagonal_offset() {
+    let a = arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(a.diagonal_offset(0), arr1(&[1.0, 5.0]));
+    assert_eq!(a.diagonal_offset(1), arr1(&[2.0, 6.0]));
+    assert_eq!(a.diagonal_offset(2), arr1(&[3.0]));
+    assert_eq!(a.diagonal_offset(-1), arr1(&[4.0]));
+    assert_eq!(a.diagonal_offset(3).len(), 0);
+    assert_eq!(a.diagonal_offset(-5).len(), 0);
+}
+
+#[test]
+fn from_diagonals_roundtrip() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((5, 5), &mut rng);
+    for offsets in [vec![0], vec![-1, 0, 1], vec![-4, -2, 0, 3]] {
+        let diags: Vec<_> = offsets.iter().map(|&k| (k, a.diagonal_offset(k))).collect();
+        let b = from_diagonals(&diags).unwrap();
+        for k in -4..=4 {
+            if offsets.contains(&k) {
+                assert_eq!(b.diagonal_offset(k), a.diagonal_offset(k));
+            } else {
+                assert!(b.diagonal_offset(k).iter().all(|x| *x == 0.0));
+            }
+        }
+    }
+}
+
+#[test]
+fn from_diagonals_inconsistent() {
+    let diags = [(0, Array1::<f64>::ones(3)), (1, Array1::ones(3))];
+    assert!(from_diagonals(&diags).is_err());
+}
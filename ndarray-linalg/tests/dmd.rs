@@ -0,0 +1,50 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn snapshots<T: Scalar>(a: &Array2<T>, x0: Array1<T>, m: usize) -> Array2<T> {
+    let mut x = Array2::zeros((a.nrows(), m + 1));
+    x.column_mut(0).assign(&x0);
+    for k in 0..m {
+        let next = a.dot(&x.column(k));
+        x.column_mut(k + 1).assign(&next);
+    }
+    x
+}
+
+macro_rules! impl_test {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<dmd_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let q: Array2<$elem> = random_unitary_using(3, &mut rng);
+                let d = array![0.9, 0.5, 0.2].mapv(<$elem>::from_real);
+                let a = q.dot(&Array2::from_diag(&d)).dot(&conjugate::<$elem, _, OwnedRepr<_>>(&q));
+                let x0: Array1<$elem> = random_using(3, &mut rng);
+                let x = snapshots(&a, x0, 8);
+
+                let res = dmd(&x.slice(s![.., ..8]), &x.slice(s![.., 1..]), None).unwrap();
+                assert_eq!(res.eigenvalues.len(), 3);
+                let mut eigs: Vec<_> = res.eigenvalues.iter().map(|e| e.re()).collect();
+                eigs.sort_by(|a, b| b.partial_cmp(a).unwrap());
+                assert_close_l2!(&Array1::from(eigs), &array![0.9, 0.5, 0.2], $rtol);
+
+                // Reconstruct the snapshots by x_k = Φ Λ^k b
+                for k in 0..4 {
+                    let coef = &res.amplitudes * &res.eigenvalues.mapv(|e| e.powi(k));
+                    let xk = res.modes.dot(&coef);
+                    let truth = x.column(k as usize).mapv(|x| x.as_c());
+                    assert_close_l2!(&xk, &truth, $rtol);
+                }
+
+                let res = dmd(&x.slice(s![.., ..8]), &x.slice(s![.., 1..]), Some(2)).unwrap();
+                assert_eq!(res.modes.dim(), (3, 2));
+                assert_eq!(res.eigenvalues.len(), 2);
+                assert_eq!(res.amplitudes.len(), 2);
+            }
+        }
+    };
+}
+
+impl_test!(f64, 1e-7);
+impl_test!(c64, 1e-7);
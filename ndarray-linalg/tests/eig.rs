@@ -1,5 +1,6 @@
 use ndarray::*;
 use ndarray_linalg::*;
+use num_traits::Zero;
 
 fn sorted_eigvals<T: Scalar>(eigvals: ArrayView1<'_, T>) -> Array1<T> {
     let mut indices: Vec<usize> = (0..eigvals.len()).collect();
@@ -35,6 +36,17 @@ fn test_eig<T: Scalar>(
     }
 }
 
+// Test that the largest-magnitude component of each eigenvector is real-positive
+fn test_phase<T: Scalar>(vecs: ArrayView2<'_, T>) {
+    for v in vecs.axis_iter(Axis(1)) {
+        let max = v
+            .iter()
+            .fold(v[0], |m, &x| if x.abs() > m.abs() { x } else { m });
+        assert_eq!(max.im(), T::Real::zero());
+        assert!(max.re() > T::Real::zero());
+    }
+}
+
 // Test case for real Eigenvalue problem
 //
 //  -1.01   0.86  -4.60   3.31  -4.81
@@ -265,6 +277,17 @@ macro_rules! impl_test_real {
                 test_eig(a.view(), e2.view(), vecs.view());
             }
 
+            #[test]
+            fn [<$real _eig_normalized>]() {
+                for a in [test_matrix_real::<$real>(), test_matrix_real_t::<$real>()] {
+                    let (e1, vecs1) = a.eig_normalized().unwrap();
+                    let (e2, vecs2) = a.eig_normalized().unwrap();
+                    assert_eq!(e1, e2);
+                    assert_eq!(vecs1, vecs2);
+                    test_eig(a.view(), e1.view(), vecs1.view());
+                    test_phase(vecs1.view());
+                }
+            }
         } // paste::item!
     };
 }
@@ -324,9 +347,60 @@ macro_rules! impl_test_complex {
                 test_eig(a.view(), e1.view(), vecs.view());
                 test_eig(a.view(), e2.view(), vecs.view());
             }
+
+            #[test]
+            fn [<$complex _eig_normalized>]() {
+                for a in [test_matrix_complex::<$complex>(), test_matrix_complex_t::<$complex>()] {
+                    let (e1, vecs1) = a.eig_normalized().unwrap();
+                    let (e2, vecs2) = a.eig_normalized().unwrap();
+                    assert_eq!(e1, e2);
+                    assert_eq!(vecs1, vecs2);
+                    test_eig(a.view(), e1.view(), vecs1.view());
+                    test_phase(vecs1.view());
+                }
+            }
         } // paste::item!
     };
 }
 
 impl_test_complex!(c32);
 impl_test_complex!(c64);
+
+macro_rules! impl_test_eig_cond {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<$elem _eig_cond_normal>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(5, &mut rng);
+                let r = a.eig_cond().unwrap();
+                test_eig(a.view(), r.eigs.view(), r.vecs.view());
+                for &c in &r.rconde {
+                    assert!((c - 1.0).abs() < 1e-8, "rconde = {}", c);
+                }
+                assert!(r.ilo <= r.ihi && r.ihi <= 5);
+            }
+
+            #[test]
+            fn [<$elem _eig_cond_non_normal>]() {
+                let a: Array2<$elem> = array![
+                    [<$elem>::from_real(1.0), <$elem>::from_real(1e4)],
+                    [<$elem>::from_real(0.0), <$elem>::from_real(1.0 + 1e-4)]
+                ];
+                let a_t = a.t().to_owned();
+                let mut a_f: Array2<$elem> = Array2::zeros((2, 2).f());
+                a_f.assign(&a);
+                for a in [a.view(), a_f.view(), a_t.view()] {
+                    let r = a.eig_cond().unwrap();
+                    test_eig(a, r.eigs.view(), r.vecs.view());
+                    for &c in &r.rconde {
+                        assert!(c < 1e-6, "rconde = {}", c);
+                    }
+                }
+            }
+        } // paste::item!
+    };
+}
+
+impl_test_eig_cond!(f64);
+impl_test_eig_cond!(c64);
@@ -0,0 +1,72 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Check `beta A v = alpha B v` for all eigenpairs
+fn test_eig_generalized<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, rtol: T::Real)
+where
+    T::Complex: Lapack,
+{
+    let (eigs, vecs) = (a.view(), b.view()).eig_generalized(None).unwrap();
+    let a_c = a.mapv(|v| v.as_c());
+    let b_c = b.mapv(|v| v.as_c());
+    for (e, v) in eigs.iter().zip(vecs.axis_iter(Axis(1))) {
+        let av = a_c.dot(&v).mapv(|x| x * e.beta());
+        let bv = b_c.dot(&v).mapv(|x| x * e.alpha());
+        assert_close_l2!(&av, &bv, rtol);
+    }
+    let vals = (a.view(), b.view()).eigvals_generalized(None).unwrap();
+    assert_eq!(vals.len(), eigs.len());
+}
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<eig_generalized_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((4, 4), &mut rng);
+                let b: Array2<$elem> = random_using((4, 4), &mut rng);
+                test_eig_generalized(&a, &b, 1e-9);
+            }
+
+            #[test]
+            fn [<eig_generalized_t_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((4, 4).f(), &mut rng);
+                let b: Array2<$elem> = random_using((4, 4).f(), &mut rng);
+                test_eig_generalized(&a, &b, 1e-9);
+                // mixed layout
+                let b: Array2<$elem> = random_using((4, 4), &mut rng);
+                test_eig_generalized(&a, &b, 1e-9);
+            }
+
+            #[test]
+            fn [<eig_generalized_infinite_ $elem>]() {
+                // det(A - λB) = 4 (2 - λ)(3 - λ) has two finite eigenvalues
+                // and B is singular, which gives one infinite eigenvalue
+                let a: Array2<$elem> = array![[2.0, 1.0, 5.0], [0.0, 3.0, 1.0], [0.0, 0.0, 4.0]]
+                    .mapv(|x| <$elem>::from_real(x));
+                let b: Array2<$elem> = Array2::from_diag(&array![1.0, 1.0, 0.0])
+                    .mapv(|x| <$elem>::from_real(x));
+                for (a, b) in [(a.clone(), b.clone()), (a.t().to_owned(), b.t().to_owned())] {
+                    test_eig_generalized(&a, &b, 1e-9);
+                }
+                let eigs = (a.view(), b.view()).eigvals_generalized(Some(1e-9)).unwrap();
+                let mut finite: Vec<_> = eigs.iter().filter_map(|e| e.finite()).map(|e| e.re()).collect();
+                finite.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                assert_eq!(finite.len(), 2);
+                assert_rclose!(finite[0], 2.0, 1e-9);
+                assert_rclose!(finite[1], 3.0, 1e-9);
+                let infinite: Vec<_> = eigs
+                    .iter()
+                    .filter(|e| matches!(e, GeneralizedEigenvalue::Indeterminate(_)))
+                    .collect();
+                assert_eq!(infinite.len(), 1);
+                assert!(infinite[0].alpha().abs() > 1e-9);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
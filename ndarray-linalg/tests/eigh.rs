@@ -1,5 +1,5 @@
 use ndarray::*;
-use ndarray_linalg::*;
+use ndarray_linalg::{error::LinalgError, *};
 
 #[should_panic]
 #[test]
@@ -132,3 +132,343 @@ fn ssqrt_t_lower() {
     println!("ss = {:?}", &ss);
     assert_close_l2!(&ss, &ans, 1e-7);
 }
+
+#[test]
+fn whitening_pair() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(4, &mut rng);
+    let (w, w_inv) = a.whitening_pair(UPLO::Upper, 0.0).unwrap();
+    assert_close_l2!(&w.dot(&w_inv), &Array::eye(4), 1e-10);
+    assert_close_l2!(&w.dot(&a).dot(&w), &Array::eye(4), 1e-10);
+    assert_close_l2!(&w_inv.dot(&w_inv), &a, 1e-10);
+}
+
+#[test]
+fn whitening_pair_complex() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hpd_using(4, &mut rng).reversed_axes();
+    let (w, w_inv) = a.whitening_pair(UPLO::Lower, 0.0).unwrap();
+    assert_close_l2!(&w.dot(&w_inv), &Array::eye(4), 1e-10);
+    assert_close_l2!(&w_inv.dot(&w_inv), &a, 1e-10);
+    let wh: Array2<c64> = conjugate(&w);
+    assert_close_l2!(&wh, &w, 1e-10);
+}
+
+#[test]
+fn eigh_blocks_f64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a1: Array2<f64> = random_hermite_using(3, &mut rng);
+    let a2: Array2<f64> = random_hermite_using(2, &mut rng);
+    let (e, vs) = eigh_blocks(&[a1.view(), a2.view()], UPLO::Upper).unwrap();
+
+    let mut full = Array2::zeros((5, 5));
+    full.slice_mut(s![..3, ..3]).assign(&a1);
+    full.slice_mut(s![3.., 3..]).assign(&a2);
+    let (e_full, _) = full.eigh(UPLO::Upper).unwrap();
+    assert_close_l2!(&e, &e_full, 1e-7);
+
+    for (a, v) in [a1, a2].iter().zip(vs.iter()) {
+        let (e, _) = a.eigh(UPLO::Upper).unwrap();
+        assert_close_l2!(&a.dot(v), &v.dot(&Array2::from_diag(&e)), 1e-7);
+    }
+}
+
+fn test_eigh_refine<T: Scalar + Lapack>(a: &Array2<T>, uplo: UPLO) {
+    let (e0, _) = a.eigh(uplo).unwrap();
+    let (e, v) = a.eigh_refine(uplo, 14).unwrap();
+    assert_close_l2!(&e.mapv(T::from_real), &e0.mapv(T::from_real), T::real(1e-9));
+    let a_norm = a.norm_l2();
+    for (&lambda, col) in e.iter().zip(v.axis_iter(Axis(1))) {
+        let mut r = a.dot(&col);
+        r.scaled_add(-T::from_real(lambda), &col);
+        assert!(r.norm_l2() <= T::real(1e-13) * a_norm);
+    }
+    let vh: Array2<T> = conjugate(&v);
+    assert_close_l2!(&vh.dot(&v), &Array2::eye(a.nrows()), T::real(1e-9));
+}
+
+#[test]
+fn eigh_refine_f64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hermite_using(5, &mut rng);
+    test_eigh_refine(&a, UPLO::Upper);
+    test_eigh_refine(&a.t().to_owned(), UPLO::Lower);
+}
+
+#[test]
+fn eigh_refine_c64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(5, &mut rng);
+    test_eigh_refine(&a, UPLO::Upper);
+    // Only the lower triangle is referred
+    let mut b = a.clone();
+    for i in 0..5 {
+        for j in (i + 1)..5 {
+            b[(i, j)] = c64::new(0.0, 0.0);
+        }
+    }
+    let (e, _) = b.eigh_refine(UPLO::Lower, 14).unwrap();
+    let (e0, _) = a.eigh(UPLO::Upper).unwrap();
+    assert_close_l2!(&e, &e0, 1e-9);
+}
+
+fn test_eigvalsh<T: Scalar + Lapack>(a: &Array2<T>) {
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let (e0, _) = a.eigh(uplo).unwrap();
+        let e = a.eigvalsh(uplo).unwrap();
+        assert_close_l2!(
+            &e.mapv(T::from_real),
+            &e0.mapv(T::from_real),
+            T::real(1e-12)
+        );
+    }
+}
+
+#[test]
+fn eigvalsh_f64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hermite_using(5, &mut rng);
+    test_eigvalsh(&a);
+    test_eigvalsh(
+        &a.reversed_axes()
+            .as_standard_layout()
+            .reversed_axes()
+            .to_owned(),
+    );
+}
+
+#[test]
+fn eigvalsh_c64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(5, &mut rng);
+    test_eigvalsh(&a);
+    test_eigvalsh(
+        &a.reversed_axes()
+            .as_standard_layout()
+            .reversed_axes()
+            .to_owned(),
+    );
+}
+
+fn test_eigh_range<T: Scalar + Lapack>(a: &Array2<T>, uplo: UPLO) {
+    let n = a.nrows();
+    let (e0, _) = a.eigh(uplo).unwrap();
+
+    // index range
+    let (e, v) = a.eigh_range(uplo, EigRange::Index(1..4)).unwrap();
+    assert_eq!(v.dim(), (n, 3));
+    assert_close_l2!(
+        &e.mapv(T::from_real),
+        &e0.slice(s![1..4]).mapv(T::from_real),
+        T::real(1e-9)
+    );
+    for (&lambda, col) in e.iter().zip(v.axis_iter(Axis(1))) {
+        let av = a.dot(&col);
+        let ev = col.mapv(|x| x * T::from_real(lambda));
+        assert_close_l2!(&av, &ev, T::real(1e-9));
+    }
+    let vh: Array2<T> = conjugate(&v);
+    assert_close_l2!(&vh.dot(&v), &Array2::eye(3), T::real(1e-9));
+    let e = a.eigvalsh_range(uplo, EigRange::Index(0..n)).unwrap();
+    assert_close_l2!(&e.mapv(T::from_real), &e0.mapv(T::from_real), T::real(1e-9));
+
+    // value range (lower, upper] containing e0[2] and e0[3]
+    let half = T::real(0.5);
+    let lower = (e0[1] + e0[2]) * half;
+    let upper = (e0[3] + e0[4]) * half;
+    let (e, v) = a.eigh_range(uplo, EigRange::Value(lower, upper)).unwrap();
+    assert_eq!(v.dim(), (n, 2));
+    assert_close_l2!(
+        &e.mapv(T::from_real),
+        &e0.slice(s![2..4]).mapv(T::from_real),
+        T::real(1e-9)
+    );
+
+    // all
+    let e = a.eigvalsh_range(uplo, EigRange::All).unwrap();
+    assert_close_l2!(&e.mapv(T::from_real), &e0.mapv(T::from_real), T::real(1e-9));
+
+    // empty ranges
+    let (e, v) = a.eigh_range(uplo, EigRange::Index(2..2)).unwrap();
+    assert_eq!(e.len(), 0);
+    assert_eq!(v.dim(), (n, 0));
+    let e = a
+        .eigvalsh_range(uplo, EigRange::Value(upper, lower))
+        .unwrap();
+    assert_eq!(e.len(), 0);
+    assert!(matches!(
+        a.eigh_range(uplo, EigRange::Index(0..n + 1)),
+        Err(LinalgError::Lapack(lax::error::Error::InvalidRange { end, n: dim }))
+            if end == n + 1 && dim == n
+    ));
+}
+
+#[test]
+fn eigh_range_f64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hermite_using(6, &mut rng);
+    test_eigh_range(&a, UPLO::Upper);
+    test_eigh_range(&a, UPLO::Lower);
+    let mut a_f = Array2::zeros((6, 6).f());
+    a_f.assign(&a);
+    test_eigh_range(&a_f, UPLO::Upper);
+}
+
+#[test]
+fn eigh_range_c64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(6, &mut rng);
+    test_eigh_range(&a, UPLO::Upper);
+    test_eigh_range(&a, UPLO::Lower);
+    let mut a_f = Array2::zeros((6, 6).f());
+    a_f.assign(&a);
+    test_eigh_range(&a_f, UPLO::Lower);
+}
+
+fn test_eigh_generalized<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>) {
+    let n = a.nrows();
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let (e, v) = (a, b).eigh_generalized(uplo).unwrap();
+        let vh: Array2<T> = conjugate(&v);
+        assert_close_l2!(&vh.dot(b).dot(&v), &Array2::eye(n), T::real(1e-9));
+        let d = Array2::from_diag(&e.mapv(T::from_real));
+        assert_close_l2!(&a.dot(&v), &b.dot(&v).dot(&d), T::real(1e-9));
+        assert!(e.windows(2).into_iter().all(|w| w[0] <= w[1]));
+        let e2 = (a, b).eigvalsh_generalized(uplo).unwrap();
+        assert_close_l2!(&e2.mapv(T::from_real), &e.mapv(T::from_real), T::real(1e-9));
+        // same as the existing tuple interface
+        let e3 = (a.clone(), b.clone()).eigh(uplo).unwrap().0;
+        assert_close_l2!(&e3.mapv(T::from_real), &e.mapv(T::from_real), T::real(1e-9));
+    }
+}
+
+macro_rules! impl_test_eigh_generalized {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<eigh_generalized_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(4, &mut rng);
+                let b: Array2<$elem> = random_hpd_using(4, &mut rng);
+                test_eigh_generalized(&a, &b);
+                // column-major input
+                let a = a.reversed_axes();
+                let b = b.reversed_axes();
+                test_eigh_generalized(&a, &b);
+            }
+
+            #[test]
+            fn [<eigh_generalized_reference_ $elem>]() {
+                // B = diag(2, 4) and A = B diag(1, 3) in a rotated basis
+                let q: Array2<$elem> = array![[3.0, 4.0], [-4.0, 3.0]].mapv(|x| <$elem>::from_real(x / 5.0));
+                let b0: Array2<$elem> = Array2::from_diag(&array![2.0, 4.0].mapv(<$elem>::from_real));
+                let a0: Array2<$elem> = Array2::from_diag(&array![2.0, 12.0].mapv(<$elem>::from_real));
+                let a = q.t().dot(&a0).dot(&q);
+                let b = q.t().dot(&b0).dot(&q);
+                let e = (&a, &b).eigvalsh_generalized(UPLO::Lower).unwrap();
+                assert_close_l2!(&e, &array![1.0, 3.0], 1e-12);
+            }
+        }
+    };
+}
+
+impl_test_eigh_generalized!(f64);
+impl_test_eigh_generalized!(c64);
+
+#[test]
+fn eigh_generalized_errors() {
+    let a = Array2::<f64>::eye(3);
+    let b = Array2::<f64>::eye(2);
+    assert!((&a, &b).eigh_generalized(UPLO::Upper).is_err());
+    // B is not positive definite
+    let b = -Array2::<f64>::eye(3);
+    assert!((&a, &b).eigh_generalized(UPLO::Upper).is_err());
+}
+
+#[test]
+fn eigh_checked_hermitian() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(4, &mut rng);
+    let (e, vecs) = a.eigh_checked(UPLO::Upper, 1e-12).unwrap();
+    let (e_ans, _) = a.eigh(UPLO::Upper).unwrap();
+    assert_close_l2!(&e, &e_ans, 1e-9);
+    let av = a.dot(&vecs);
+    let ve = &vecs * &e.mapv(c64::from_real);
+    assert_close_l2!(&av, &ve, 1e-9);
+}
+
+#[test]
+fn eigh_checked_not_hermitian() {
+    let a = arr2(&[[1.0, 2.0], [0.0, 1.0]]);
+    match a.eigh_checked(UPLO::Upper, 1e-9) {
+        Err(error::LinalgError::NotHermitian { deviation }) => {
+            assert_aclose!(deviation, 8.0_f64.sqrt(), 1e-12)
+        }
+        _ => panic!("should be an error"),
+    }
+    // Accepted with a loose tolerance
+    assert!(a.eigh_checked(UPLO::Upper, 10.0).is_ok());
+}
+
+#[test]
+fn uplo_refers_to_displayed_triangle() {
+    // Only the upper triangle is filled, as it is displayed
+    let upper = arr2(&[[1.0, 2.0], [0.0, 1.0]]);
+    let full = arr2(&[[1.0, 2.0], [2.0, 1.0]]);
+    let mut upper_f = Array2::zeros((2, 2).f());
+    upper_f.assign(&upper);
+    for a in [upper, upper_f] {
+        let (e, vecs) = a.eigh(UPLO::Upper).unwrap();
+        assert_close_l2!(&e, &arr1(&[-1.0, 3.0]), 1e-7);
+        assert_close_l2!(&full.dot(&vecs), &(&vecs * &e), 1e-7);
+        let e = a.eigvalsh(UPLO::Upper).unwrap();
+        assert_close_l2!(&e, &arr1(&[-1.0, 3.0]), 1e-7);
+        // Lower triangle is diagonal
+        let e = a.eigvalsh(UPLO::Lower).unwrap();
+        assert_close_l2!(&e, &arr1(&[1.0, 1.0]), 1e-7);
+    }
+}
+
+#[test]
+fn uplo_c_layout_complex() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(4, &mut rng);
+    assert!(a.is_standard_layout());
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let mut b = a.clone();
+        // Break the triangle which should not be read
+        for i in 0..4 {
+            for j in 0..i {
+                match uplo {
+                    UPLO::Upper => b[(i, j)] = c64::new(100.0, 100.0),
+                    UPLO::Lower => b[(j, i)] = c64::new(100.0, 100.0),
+                }
+            }
+        }
+        let (e, vecs) = b.eigh(uplo).unwrap();
+        let e_c = e.mapv(c64::from_real);
+        assert_close_l2!(&a.dot(&vecs), &(&vecs * &e_c), 1e-9);
+    }
+}
+
+#[test]
+fn eigh_generalized_c_layout_complex() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(4, &mut rng);
+    let b: Array2<c64> = random_hpd_using(4, &mut rng);
+    assert!(a.is_standard_layout() && b.is_standard_layout());
+    let (e, (v, _)) = (a.clone(), b.clone()).eigh(UPLO::Upper).unwrap();
+    let vh: Array2<c64> = conjugate(&v);
+    assert_close_l2!(&vh.dot(&b).dot(&v), &Array2::eye(4), 1e-9);
+    let d = Array2::from_diag(&e.mapv(c64::from_real));
+    assert_close_l2!(&a.dot(&v), &b.dot(&v).dot(&d), 1e-9);
+}
+
+#[should_panic]
+#[test]
+fn eigh_generalized_layout_mismatch() {
+    let a = Array2::<f64>::eye(3);
+    let mut b = Array2::<f64>::zeros((3, 3).f());
+    b.assign(&a);
+    let _ = (a, b).eigh_inplace(UPLO::Upper);
+}
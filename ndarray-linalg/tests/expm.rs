@@ -0,0 +1,158 @@
+use ndarray::*;
+use ndarray_linalg::{error::LinalgError, *};
+
+fn test_diagonalizable<T: Scalar + Lapack>(scale: f64, rtol: f64) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let q: Array2<T> = random_unitary_using(4, &mut rng);
+    let qh: Array2<T> = conjugate(&q);
+    let d: Array1<T> = random_using(4, &mut rng);
+    let d = d.mapv(|d| d.mul_real(T::real(scale)));
+    let a = q.dot(&Array2::from_diag(&d)).dot(&qh);
+    let expected = q.dot(&Array2::from_diag(&d.mapv(|d| d.exp()))).dot(&qh);
+    assert_close_l2!(&expm(&a).unwrap(), &expected, T::real(rtol));
+}
+
+#[test]
+fn expm_diagonalizable() {
+    // Small and large norms use Padé approximants of different degree,
+    // and the latter requires scaling and squaring
+    for &scale in &[1e-3, 0.1, 1.0, 10.0] {
+        test_diagonalizable::<f64>(scale, 1e-9);
+        test_diagonalizable::<c64>(scale, 1e-9);
+        test_diagonalizable::<f32>(scale, 1e-4);
+        test_diagonalizable::<c32>(scale, 1e-4);
+    }
+}
+
+#[test]
+fn expm_nilpotent() {
+    let a: Array2<f64> = array![[0.0, 1.0, 2.0], [0.0, 0.0, 3.0], [0.0, 0.0, 0.0]];
+    let expected = array![[1.0, 1.0, 3.5], [0.0, 1.0, 3.0], [0.0, 0.0, 1.0]];
+    assert_close_l2!(&expm(&a).unwrap(), &expected, 1e-12);
+}
+
+#[test]
+fn expm_rotation() {
+    let theta = 20.0f64;
+    let a = array![[0.0, -theta], [theta, 0.0]];
+    let expected = array![[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]];
+    assert_close_l2!(&expm(&a).unwrap(), &expected, 1e-9);
+}
+
+#[test]
+fn expm_non_finite() {
+    let a: Array2<f64> = array![[1.0, f64::INFINITY], [0.0, 1.0]];
+    assert!(matches!(
+        expm(&a),
+        Err(LinalgError::InvalidInput { index }) if index == vec![0, 1]
+    ));
+    assert!(expm_cond(&a.view()).is_err());
+    // Every element is finite, but the 1-norm overflows
+    let a: Array2<f64> = Array2::from_elem((2, 2), f64::MAX);
+    assert!(matches!(expm(&a), Err(LinalgError::NonFiniteNorm)));
+}
+
+#[test]
+fn expm_non_finite_single() {
+    let a: Array2<f32> = array![[1.0, 0.0], [f32::NAN, 1.0]];
+    assert!(matches!(
+        expm(&a),
+        Err(LinalgError::InvalidInput { index }) if index == vec![1, 0]
+    ));
+    let a: Array2<c32> = Array2::from_elem((2, 2), c32::new(f32::MAX, 0.0));
+    assert!(matches!(expm(&a), Err(LinalgError::NonFiniteNorm)));
+}
+
+#[test]
+fn expm_cond_diagonal() {
+    // For a diagonal matrix, the Fréchet derivative is diagonal with the
+    // divided differences of exp, whose maximum is exp(max a_i), and hence
+    // the relative condition number is max |a_i|.
+    let d = array![1.0, -2.0, 0.5];
+    let a: Array2<f64> = Array2::from_diag(&d);
+    let (exp_a, cond) = expm_cond(&a.view()).unwrap();
+    assert_close_l2!(&exp_a, &Array2::from_diag(&d.mapv(f64::exp)), 1e-12);
+    assert!(cond <= 2.0 * (1.0 + 1e-9));
+    assert!(cond >= 2.0 / 3.0);
+}
+
+fn test_frechet<T: Scalar + Lapack>() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_using((3, 3), &mut rng);
+    let e: Array2<T> = random_using((3, 3), &mut rng);
+    let (exp_a, l) = expm_frechet(&a.view(), &e.view()).unwrap();
+    assert_close_l2!(&exp_a, &expm(&a).unwrap(), T::real(1e-12));
+
+    // Central difference
+    let h = T::real(1e-5);
+    let eh = e.mapv(|e| e.mul_real(h));
+    let diff =
+        (expm(&(&a + &eh)).unwrap() - expm(&(&a - &eh)).unwrap()).mapv(|x| x.div_real(h + h));
+    assert_close_l2!(&l, &diff, T::real(1e-8));
+
+    // L(A, A) = A exp(A) since A commutes with itself
+    let (_, l) = expm_frechet(&a.view(), &a.view()).unwrap();
+    assert_close_l2!(&l, &a.dot(&exp_a), T::real(1e-9));
+}
+
+#[test]
+fn expm_frechet_real() {
+    test_frechet::<f64>();
+}
+
+#[test]
+fn expm_frechet_complex() {
+    test_frechet::<c64>();
+}
+
+fn test_expm_multiply<T: Scalar + Lapack>(a: &Array2<T>, t: f64, rtol: f64) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array2<T> = random_using((a.nrows(), 2), &mut rng);
+    let ah: Array2<T> = conjugate(a);
+    let x = expm_multiply(|x| a.dot(&x), |x| ah.dot(&x), &b, T::real(t)).unwrap();
+    let expected = expm(&a.mapv(|x| x.mul_real(T::real(t)))).unwrap().dot(&b);
+    assert_close_l2!(&x, &expected, T::real(rtol));
+}
+
+#[test]
+fn expm_multiply_random() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((6, 6), &mut rng);
+    for &t in &[0.0, 1e-3, 0.5, -2.0] {
+        test_expm_multiply(&a, t, 1e-9);
+    }
+    let a: Array2<c64> = random_using((6, 6), &mut rng);
+    for &t in &[0.0, 1e-3, 0.5, -2.0] {
+        test_expm_multiply(&a, t, 1e-9);
+    }
+}
+
+#[test]
+fn expm_multiply_large_norm() {
+    // exp(tA) is unitary for a skew-Hermitian A, and the large norm requires
+    // the estimates of the norms of the powers
+    fn skew<T: Scalar>(m: &Array2<T>) -> Array2<T> {
+        let mh: Array2<T> = conjugate(m);
+        (m - &mh).mapv(|x| x.mul_real(T::real(20.0)))
+    }
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let m: Array2<f64> = random_using((6, 6), &mut rng);
+    test_expm_multiply(&skew(&m), 1.0, 1e-9);
+    let m: Array2<c64> = random_using((6, 6), &mut rng);
+    test_expm_multiply(&skew(&m), 1.0, 1e-9);
+}
+
+#[test]
+fn expm_multiply_non_finite() {
+    let a: Array2<f64> = array![[1.0, 2.0], [0.0, 1.0]];
+    let ah = a.t().to_owned();
+    let b: Array2<f64> = Array2::ones((2, 1));
+    for &t in &[f64::INFINITY, f64::NAN] {
+        let x = expm_multiply(|x| a.dot(&x), |x| ah.dot(&x), &b, t);
+        assert!(matches!(x, Err(LinalgError::NonFiniteNorm)));
+    }
+    // The estimates of the norms of the powers overflow
+    let a: Array2<f64> = array![[1e300, 0.0], [0.0, 1e300]];
+    let x = expm_multiply(|x| a.dot(&x), |x| a.dot(&x), &b, 1.0);
+    assert!(matches!(x, Err(LinalgError::NonFiniteNorm)));
+}
@@ -0,0 +1,133 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn fgmres_identity<A: Scalar + Lapack>() {
+    const N: usize = 6;
+    let rtol: A::Real = A::real(1e-9);
+
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<A> = random_regular_using(N, &mut rng);
+    let x: Array1<A> = random_using(N, &mut rng);
+    let b = a.dot(&x);
+
+    let (sol, hist) = fgmres(
+        |v| a.dot(&v),
+        |v| v.to_owned(),
+        &b,
+        Array1::zeros(N),
+        A::real(1e-12),
+        N,
+    )
+    .unwrap();
+    assert_close_l2!(&sol, &x, rtol);
+    assert!(hist.windows(2).all(|w| w[1] <= w[0] * A::real(1.0 + 1e-12)));
+}
+
+#[test]
+fn fgmres_identity_real() {
+    fgmres_identity::<f64>();
+}
+
+#[test]
+fn fgmres_identity_complex() {
+    fgmres_identity::<c64>();
+}
+
+#[test]
+fn fgmres_variable_preconditioner() {
+    const N: usize = 8;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(N, &mut rng);
+    let x: Array1<f64> = random_using(N, &mut rng);
+    let b = a.dot(&x);
+
+    // Alternate between the identity and Jacobi preconditioner
+    let d = a.diag().to_owned();
+    let mut count = 0;
+    let (sol, hist) = fgmres(
+        |v| a.dot(&v),
+        |v| {
+            count += 1;
+            if count % 2 == 0 {
+                &v / &d
+            } else {
+                v.to_owned()
+            }
+        },
+        &b,
+        Array1::zeros(N),
+        1e-12,
+        N,
+    )
+    .unwrap();
+    assert_close_l2!(&sol, &x, 1e-9);
+    assert_eq!(hist.len(), count + 1);
+    assert!(*hist.last().unwrap() < 1e-12);
+}
+
+#[test]
+fn fgmres_exact_initial_guess() {
+    let a: Array2<f64> = array![[2.0, 1.0], [1.0, 3.0]];
+    let x = array![1.0, -1.0];
+    let b = a.dot(&x);
+    let (sol, hist) = fgmres(|v| a.dot(&v), |v| v.to_owned(), &b, x.clone(), 1e-12, 2).unwrap();
+    assert_eq!(sol, x);
+    assert_eq!(hist.len(), 1);
+}
+
+#[test]
+fn fgmres_backward_error() {
+    const N: usize = 6;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // Badly scaled system
+    let a: Array2<f64> = random_regular_using::<f64, _>(N, &mut rng) * 1e8;
+    let x: Array1<f64> = random_using(N, &mut rng);
+    let b = a.dot(&x);
+    let a_norm = a.opnorm_fro().unwrap();
+
+    let (sol, hist) = fgmres_with_criterion(
+        |v| a.dot(&v),
+        |v| v.to_owned(),
+        &b,
+        Array1::zeros(N),
+        1e-12,
+        N,
+        StoppingCriterion::BackwardError { a_norm },
+    )
+    .unwrap();
+    let r = &b - &a.dot(&sol);
+    let backward_error = r.norm_l2() / (a_norm * sol.norm_l2() + b.norm_l2());
+    assert!(backward_error < 1e-12);
+    assert_rclose!(*hist.last().unwrap(), backward_error, 1e-3);
+    assert_close_l2!(&sol, &x, 1e-7);
+}
+
+#[test]
+fn fgmres_backward_error_estimated() {
+    const N: usize = 6;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_regular_using::<f64, _>(N, &mut rng) * 1e8;
+    let x: Array1<f64> = random_using(N, &mut rng);
+    let b = a.dot(&x);
+
+    let criterion = StoppingCriterion::backward_error(N, |v| a.dot(&v), |v| a.t().dot(&v));
+    let a_norm = match criterion {
+        StoppingCriterion::BackwardError { a_norm } => a_norm,
+        _ => unreachable!(),
+    };
+    assert!(a_norm <= a.opnorm_one().unwrap() * (1.0 + 1e-12));
+    assert!(a_norm * 3.0 >= a.opnorm_one().unwrap());
+
+    let (sol, hist) = fgmres_with_criterion(
+        |v| a.dot(&v),
+        |v| v.to_owned(),
+        &b,
+        Array1::zeros(N),
+        1e-12,
+        N,
+        criterion,
+    )
+    .unwrap();
+    assert!(*hist.last().unwrap() < 1e-12);
+    assert_close_l2!(&sol, &x, 1e-7);
+}
@@ -0,0 +1,49 @@
+use ndarray_linalg::*;
+use std::convert::TryFrom;
+
+#[test]
+fn flags_from_char() {
+    assert_eq!(UPLO::try_from('U').unwrap(), UPLO::Upper);
+    assert_eq!(UPLO::try_from('l').unwrap(), UPLO::Lower);
+    assert_eq!(Transpose::try_from('C').unwrap(), Transpose::Hermite);
+    assert_eq!(Diag::try_from('n').unwrap(), Diag::NonUnit);
+    assert_eq!(NormType::try_from('F').unwrap(), NormType::Frobenius);
+    assert!(UPLO::try_from('X').is_err());
+    assert!(Transpose::try_from('H').is_err());
+}
+
+#[test]
+fn flags_into_u8() {
+    assert_eq!(u8::from(UPLO::Upper), b'U');
+    assert_eq!(u8::from(Transpose::Transpose), b'T');
+    assert_eq!(u8::from(Diag::Unit), b'U');
+    assert_eq!(u8::from(NormType::Infinity), b'I');
+}
+
+#[test]
+fn uplo_opposite() {
+    assert_eq!(UPLO::Upper.opposite(), UPLO::Lower);
+    assert_eq!(UPLO::Lower.opposite(), UPLO::Upper);
+}
+
+#[test]
+fn transpose_compose() {
+    assert_eq!(
+        Transpose::No.compose(Transpose::Hermite),
+        Some(Transpose::Hermite)
+    );
+    assert_eq!(
+        Transpose::Transpose.compose(Transpose::No),
+        Some(Transpose::Transpose)
+    );
+    assert_eq!(
+        Transpose::Transpose.compose(Transpose::Transpose),
+        Some(Transpose::No)
+    );
+    assert_eq!(
+        Transpose::Hermite.compose(Transpose::Hermite),
+        Some(Transpose::No)
+    );
+    assert_eq!(Transpose::Transpose.compose(Transpose::Hermite), None);
+    assert_eq!(Transpose::Hermite.compose(Transpose::Transpose), None);
+}
@@ -0,0 +1,153 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_svals<T: Scalar + Lapack>(m: usize, n: usize) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let k = m.min(n);
+    // geometric decay from 1 to 1e-6
+    let svals: Vec<T::Real> = (0..k)
+        .map(|i| T::real(10.0_f64.powf(-6.0 * i as f64 / (k - 1) as f64)))
+        .collect();
+    let a: Array2<T> =
+        random_with_svals_using((m, n), &svals, Symmetry::General, &mut rng).unwrap();
+    assert_eq!(a.dim(), (m, n));
+    let (_, s, _) = a.svd(false, false).unwrap();
+    let s = s.mapv(T::from_real);
+    let svals = arr1(&svals).mapv(T::from_real);
+    assert_close_l2!(&s, &svals, T::real(1e-4));
+}
+
+fn test_eigs<T: Scalar + Lapack>(n: usize) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let eigs: Vec<T::Real> = (0..n).map(|i| T::real(i as f64 - 2.0)).collect();
+    let a: Array2<T> =
+        random_with_svals_using((n, n), &eigs, Symmetry::Hermitian, &mut rng).unwrap();
+    let ah: Array2<T> = conjugate(&a);
+    assert_close_l2!(&a, &ah, T::real(1e-5));
+    let e = a.eigvalsh(UPLO::Upper).unwrap();
+    let e = e.mapv(T::from_real);
+    let eigs = arr1(&eigs).mapv(T::from_real);
+    assert_close_l2!(&e, &eigs, T::real(1e-4));
+}
+
+fn test_unitary<T: Scalar + Lapack>(n: usize) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let q: Array2<T> = random_unitary_using(n, &mut rng);
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(n), T::real(1e-7));
+    assert_close_l2!(&q.dot(&qh), &Array2::eye(n), T::real(1e-7));
+}
+
+fn test_unitary_unbiased<T: Scalar + Lapack>(n: usize) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let samples = 200;
+    let mut sum = 0.0;
+    let mut positive = 0;
+    for _ in 0..samples {
+        let q: Array2<T> = random_unitary_using(n, &mut rng);
+        for d in q.diag() {
+            let re = num_traits::ToPrimitive::to_f64(&d.re()).unwrap();
+            sum += re;
+            if re > 0.0 {
+                positive += 1;
+            }
+        }
+    }
+    // Diagonal elements of a Haar unitary matrix have zero mean
+    let total = samples * n;
+    assert!((sum / total as f64).abs() < 0.1);
+    let ratio = positive as f64 / total as f64;
+    assert!((0.4..0.6).contains(&ratio));
+}
+
+macro_rules! impl_test {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<random_with_svals_ $scalar>]() {
+                test_svals::<$scalar>(6, 6);
+                test_svals::<$scalar>(6, 4);
+                test_svals::<$scalar>(4, 6);
+            }
+
+            #[test]
+            fn [<random_with_eigs_ $scalar>]() {
+                test_eigs::<$scalar>(5);
+            }
+
+            #[test]
+            fn [<random_unitary_ $scalar>]() {
+                test_unitary::<$scalar>(1);
+                test_unitary::<$scalar>(6);
+            }
+
+            #[test]
+            fn [<random_unitary_unbiased_ $scalar>]() {
+                test_unitary_unbiased::<$scalar>(4);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
+
+#[test]
+fn random_with_svals_shape_mismatch() {
+    let svals = [1.0, 0.5];
+    assert!(random_with_svals::<f64>((3, 3), &svals, Symmetry::General).is_err());
+    assert!(random_with_svals::<f64>((3, 2), &svals, Symmetry::Hermitian).is_err());
+}
+
+#[test]
+fn random_seeded_reproducible() {
+    let a: Array2<f64> = random_seeded((3, 4), 42);
+    let b: Array2<f64> = random_seeded((3, 4), 42);
+    assert_eq!(a, b);
+    let c: Array2<f64> = random_seeded((3, 4), 43);
+    assert_ne!(a, c);
+    let a: Array1<c64> = random_seeded(5, 42);
+    let b: Array1<c64> = random_seeded(5, 42);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn random_orthogonal_real() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let q: Array2<f64> = random_orthogonal_using(5, &mut rng);
+    assert_close_l2!(&q.t().dot(&q), &Array2::eye(5), 1e-7);
+    let det = q.det().unwrap();
+    assert_aclose!(det.abs(), 1.0, 1e-9);
+}
+
+fn test_hermitian_with_eigs<T: Scalar + Lapack>() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let eigs: Vec<T::Real> = [-3.0, -1.0, 0.5, 2.0, 4.0]
+        .iter()
+        .map(|&e| T::real(e))
+        .collect();
+    let a: Array2<T> = random_hermitian_with_eigs_using(&eigs, &mut rng);
+    let ah: Array2<T> = conjugate(&a);
+    assert_eq!(a, ah);
+    let e = a.eigvalsh(UPLO::Upper).unwrap().mapv(T::from_real);
+    let eigs = arr1(&eigs).mapv(T::from_real);
+    assert_close_l2!(&e, &eigs, T::real(1e-9));
+}
+
+#[test]
+fn random_hermitian_with_eigs_f64() {
+    test_hermitian_with_eigs::<f64>();
+}
+
+#[test]
+fn random_hermitian_with_eigs_c64() {
+    test_hermitian_with_eigs::<c64>();
+}
+
+#[test]
+fn random_hermite_exact() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(4, &mut rng);
+    let ah: Array2<c64> = conjugate(&a);
+    assert_eq!(a, ah);
+}
@@ -0,0 +1,77 @@
+use ndarray::*;
+use ndarray_linalg::{
+    krylov::{gmres_with_criterion, StoppingCriterion},
+    *,
+};
+
+fn gmres_nonsymmetric<A: Scalar + Lapack>(restart: usize) {
+    const N: usize = 10;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // well-conditioned nonsymmetric matrix by diagonal shift
+    let a: Array2<A> =
+        random_using((N, N), &mut rng) + Array2::<A>::eye(N).mapv(|e| e.mul_real(A::real(N)));
+    let b: Array1<A> = random_using(N, &mut rng);
+    let expected = a.solve(&b).unwrap();
+
+    let (x, history) = gmres(|v| a.dot(&v), &b, A::real(1e-12), 100, restart).unwrap();
+    assert!(*history.last().unwrap() <= A::real(1e-12));
+    assert!(history
+        .windows(2)
+        .all(|w| w[1] <= w[0] * A::real(1.0 + 1e-12)));
+    assert_close_l2!(&x, &expected, A::real(1e-9));
+}
+
+#[test]
+fn gmres_full_real() {
+    gmres_nonsymmetric::<f64>(10);
+}
+
+#[test]
+fn gmres_full_complex() {
+    gmres_nonsymmetric::<c64>(10);
+}
+
+#[test]
+fn gmres_restarted_real() {
+    gmres_nonsymmetric::<f64>(3);
+}
+
+#[test]
+fn gmres_restarted_complex() {
+    gmres_nonsymmetric::<c64>(3);
+}
+
+#[test]
+fn gmres_max_iter() {
+    const N: usize = 10;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((N, N), &mut rng) + Array2::<f64>::eye(N) * N as f64;
+    let b: Array1<f64> = random_using(N, &mut rng);
+    let (_, history) = gmres(|v| a.dot(&v), &b, 1e-300, 5, 2).unwrap();
+    // initial residual and one entry per iteration
+    assert_eq!(history.len(), 6);
+}
+
+#[test]
+fn gmres_restarted_backward_error() {
+    const N: usize = 10;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // badly scaled system
+    let a: Array2<f64> = (random_using((N, N), &mut rng) + Array2::<f64>::eye(N) * N as f64) * 1e8;
+    let b: Array1<f64> = random_using(N, &mut rng);
+    let a_norm = a.opnorm_fro().unwrap();
+
+    let (x, history) = gmres_with_criterion(
+        |v| a.dot(&v),
+        &b,
+        1e-12,
+        100,
+        3,
+        StoppingCriterion::BackwardError { a_norm },
+    )
+    .unwrap();
+    let r = &b - &a.dot(&x);
+    let backward_error = r.norm_l2() / (a_norm * x.norm_l2() + b.norm_l2());
+    assert!(backward_error < 1e-12);
+    assert_rclose!(*history.last().unwrap(), backward_error, 1e-3);
+}
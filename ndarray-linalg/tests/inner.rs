@@ -25,3 +25,174 @@ fn abs() {
     assert_aclose!(aa.re(), a.norm().powi(2), 1e-5);
     assert_aclose!(aa.im(), 0.0, 1e-5);
 }
+
+macro_rules! test_weighted {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<weighted_uniform_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let x: Array1<$elem> = random_using(5, &mut rng);
+                let y: Array1<$elem> = random_using(5, &mut rng);
+                let w = Array1::<<$elem as Scalar>::Real>::ones(5);
+                assert_rclose!(x.weighted_inner(&y, &w), x.inner(&y), $rtol);
+                let m = Array2::<$elem>::eye(5);
+                assert_rclose!(x.metric_inner(&y, &m), x.inner(&y), $rtol);
+            }
+
+            #[test]
+            fn [<weighted_metric_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let x: Array1<$elem> = random_using(5, &mut rng);
+                let y: Array1<$elem> = random_using(5, &mut rng);
+                let w: Array1<<$elem as Scalar>::Real> = random_using(5, &mut rng);
+                let m = Array2::from_diag(&w.mapv(<$elem>::from_real));
+                assert_rclose!(x.weighted_inner(&y, &w), x.metric_inner(&y, &m), $rtol);
+            }
+
+            #[test]
+            fn [<weighted_bilinear_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let x1: Array1<$elem> = random_using(5, &mut rng);
+                let x2: Array1<$elem> = random_using(5, &mut rng);
+                let y: Array1<$elem> = random_using(5, &mut rng);
+                let w: Array1<<$elem as Scalar>::Real> = random_using(5, &mut rng);
+                let m: Array2<$elem> = random_hpd_using(5, &mut rng);
+                let a = <$elem>::rand(&mut rng);
+                let b = <$elem>::rand(&mut rng);
+                // conjugate-linear in the first argument
+                let x = &x1 * a + &x2 * b;
+                assert_rclose!(
+                    x.weighted_inner(&y, &w),
+                    x1.weighted_inner(&y, &w) * a.conj() + x2.weighted_inner(&y, &w) * b.conj(),
+                    $rtol
+                );
+                assert_rclose!(
+                    x.metric_inner(&y, &m),
+                    x1.metric_inner(&y, &m) * a.conj() + x2.metric_inner(&y, &m) * b.conj(),
+                    $rtol
+                );
+                // linear in the second argument
+                let z = &x1 * a + &x2 * b;
+                assert_rclose!(
+                    y.weighted_inner(&z, &w),
+                    y.weighted_inner(&x1, &w) * a + y.weighted_inner(&x2, &w) * b,
+                    $rtol
+                );
+                assert_rclose!(
+                    y.metric_inner(&z, &m),
+                    y.metric_inner(&x1, &m) * a + y.metric_inner(&x2, &m) * b,
+                    $rtol
+                );
+            }
+        }
+    };
+}
+
+test_weighted!(f64, 1e-9);
+test_weighted!(c64, 1e-9);
+
+macro_rules! test_gram {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<gram_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a_c: Array2<$elem> = random_using((5, 3), &mut rng);
+                let a_f: Array2<$elem> = random_using((5, 3).f(), &mut rng);
+                for a in [a_c, a_f] {
+                    let ah: Array2<$elem> = conjugate(&a);
+                    let g = a.gram();
+                    assert_eq!(g.dim(), (3, 3));
+                    assert_close_l2!(&g, &ah.dot(&a), $rtol);
+                    assert_eq!(g, g.t().mapv(|x| x.conj()));
+                    let gt = a.gram_t();
+                    assert_eq!(gt.dim(), (5, 5));
+                    assert_close_l2!(&gt, &a.dot(&ah), $rtol);
+                    assert_eq!(gt, gt.t().mapv(|x| x.conj()));
+                }
+            }
+
+            #[test]
+            fn [<gram_strided_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((6, 4), &mut rng);
+                let a = a.slice(s![..;2, ..]);
+                let ah: Array2<$elem> = conjugate(&a);
+                assert_close_l2!(&a.gram(), &ah.dot(&a), $rtol);
+            }
+        }
+    };
+}
+
+test_gram!(f64, 1e-9);
+test_gram!(c64, 1e-9);
+
+#[test]
+fn gram_transpose_complex() {
+    let a: Array2<c64> = random((3, 2).f());
+    let l = MatrixLayout::F { col: 2, lda: 3 };
+    let a = a.as_slice_memory_order().unwrap();
+    assert!(c64::gram(l, Transpose::Transpose, a).is_err());
+    assert!(c64::gram(l, Transpose::Hermite, a).is_ok());
+}
+
+macro_rules! test_frobenius {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<frobenius_inner_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((4, 3), &mut rng);
+                let b: Array2<$elem> = random_using((4, 3), &mut rng);
+                let ah: Array2<$elem> = conjugate(&a);
+                let expected = ah.dot(&b).trace().unwrap();
+                assert_rclose!(frobenius_inner(&a, &b).unwrap(), expected, $rtol);
+                assert_rclose!(
+                    frobenius_inner(&a, &a).unwrap().re(),
+                    a.norm_l2().powi(2),
+                    $rtol
+                );
+            }
+
+            #[test]
+            fn [<frobenius_inner_sesquilinear_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a1: Array2<$elem> = random_using((4, 3), &mut rng);
+                let a2: Array2<$elem> = random_using((4, 3), &mut rng);
+                let b: Array2<$elem> = random_using((4, 3), &mut rng);
+                let x = <$elem>::rand(&mut rng);
+                let y = <$elem>::rand(&mut rng);
+                let a = &a1 * x + &a2 * y;
+                assert_rclose!(
+                    frobenius_inner(&a, &b).unwrap(),
+                    frobenius_inner(&a1, &b).unwrap() * x.conj()
+                        + frobenius_inner(&a2, &b).unwrap() * y.conj(),
+                    $rtol
+                );
+                assert_rclose!(
+                    frobenius_inner(&b, &a).unwrap(),
+                    frobenius_inner(&b, &a1).unwrap() * x
+                        + frobenius_inner(&b, &a2).unwrap() * y,
+                    $rtol
+                );
+                // Hermitian symmetry
+                assert_rclose!(
+                    frobenius_inner(&b, &a).unwrap(),
+                    frobenius_inner(&a, &b).unwrap().conj(),
+                    $rtol
+                );
+            }
+        }
+    };
+}
+
+test_frobenius!(f64, 1e-9);
+test_frobenius!(c64, 1e-9);
+
+#[test]
+fn frobenius_inner_shape() {
+    let a: Array2<f64> = Array::zeros((3, 2));
+    let b: Array2<f64> = Array::zeros((2, 3));
+    assert!(frobenius_inner(&a, &b).is_err());
+}
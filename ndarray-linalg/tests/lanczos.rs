@@ -0,0 +1,68 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+/// Hermitian matrix whose extreme eigenvalues `-10` and `20` are well separated
+fn operator<A: Scalar + Lapack>(n: usize) -> Array2<A> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut eigs: Vec<A::Real> = (0..n - 2).map(|i| A::real(i as f64 / n as f64)).collect();
+    eigs.push(A::real(-10.0));
+    eigs.push(A::real(20.0));
+    random_hermitian_with_eigs_using(&eigs, &mut rng)
+}
+
+fn extreme_eigenvalues<A: Scalar + Lapack>(reorthogonalize: bool) {
+    const N: usize = 50;
+    let a: Array2<A> = operator(N);
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let v0: Array1<A> = random_using(N, &mut rng);
+    let (_, t) = if reorthogonalize {
+        lanczos_reorthogonalized(|v| a.dot(&v), &v0, 20, MGS::new(N, A::real(1e-9)))
+    } else {
+        lanczos(|v| a.dot(&v), &v0, 20)
+    };
+    assert_eq!(t.shape(), &[20, 20]);
+    let e = t.eigvalsh(UPLO::Lower).unwrap();
+    assert_aclose!(e[0], A::real(-10.0), A::real(1e-8));
+    assert_aclose!(e[19], A::real(20.0), A::real(1e-8));
+}
+
+#[test]
+fn lanczos_extreme_eigenvalues_real() {
+    extreme_eigenvalues::<f64>(false);
+}
+
+#[test]
+fn lanczos_extreme_eigenvalues_complex() {
+    extreme_eigenvalues::<c64>(false);
+}
+
+#[test]
+fn lanczos_reorthogonalized_extreme_eigenvalues_real() {
+    extreme_eigenvalues::<f64>(true);
+}
+
+#[test]
+fn lanczos_reorthogonalized_extreme_eigenvalues_complex() {
+    extreme_eigenvalues::<c64>(true);
+}
+
+#[test]
+fn lanczos_reorthogonalized_basis() {
+    const N: usize = 30;
+    let a: Array2<c64> = operator(N);
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let v0: Array1<c64> = random_using(N, &mut rng);
+    let (v, t) = lanczos_reorthogonalized(|x| a.dot(&x), &v0, 25, MGS::new(N, 1e-9));
+    let vh = v.t().mapv(|x| x.conj());
+    assert_close_l2!(&vh.dot(&v), &Array2::eye(25), 1e-9);
+    assert_close_l2!(&vh.dot(&a).dot(&v), &t, 1e-9);
+}
+
+#[test]
+fn lanczos_invariant_subspace() {
+    let a = Array2::from_diag(&array![1.0, 2.0, 3.0, 4.0]);
+    let (v, t) = lanczos(|x| a.dot(&x), &array![1.0, 1.0, 0.0, 0.0], 4);
+    assert_eq!(v.shape(), &[4, 2]);
+    let e = t.eigvalsh(UPLO::Lower).unwrap();
+    assert_close_l2!(&e, &array![1.0, 2.0], 1e-12);
+}
@@ -136,3 +136,128 @@ impl_underdetermined!(f32);
 impl_underdetermined!(f64);
 impl_underdetermined!(c32);
 impl_underdetermined!(c64);
+
+#[test]
+fn least_squares_rcond_near_singular() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<f64> = random_using((6, 3), &mut rng);
+    let noise: Array1<f64> = random_using(6, &mut rng);
+    // third column is the first one up to a tiny perturbation
+    let col = &a.column(0) + &(noise * 1e-12);
+    a.column_mut(2).assign(&col);
+    let b: Array1<f64> = random_using(6, &mut rng);
+
+    let default = a.least_squares(&b).unwrap();
+    assert_eq!(default.rank, 3);
+
+    let truncated = a.least_squares_rcond(&b, 1e-8).unwrap();
+    assert_eq!(truncated.rank, 2);
+    let norm_default = default.solution.norm_l2();
+    let norm_truncated = truncated.solution.norm_l2();
+    assert!(norm_truncated < norm_default);
+    assert!(norm_truncated < 1e3);
+
+    // the solution is still the best fit up to the truncated direction
+    let bm: Array2<f64> = b.clone().insert_axis(Axis(1));
+    let truncated_m = a.least_squares_rcond(&bm, 1e-8).unwrap();
+    assert_eq!(truncated_m.rank, 2);
+    assert_close_l2!(&truncated_m.solution.column(0), &truncated.solution, 1e-9);
+}
+
+fn test_qr<T: Scalar + Lapack>(a: Array2<T>) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array1<T> = random_using(a.nrows(), &mut rng);
+    let expected = a.least_squares(&b).unwrap().solution;
+    let x = a.least_squares_qr(&b).unwrap();
+    assert_close_l2!(&x, &expected, T::real(1e-9));
+
+    let b: Array2<T> = random_using((a.nrows(), 2), &mut rng);
+    let expected = a.least_squares(&b).unwrap().solution;
+    let x = a.least_squares_qr(&b).unwrap();
+    assert_close_l2!(&x, &expected, T::real(1e-9));
+}
+
+macro_rules! impl_qr {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_qr_ $scalar _overdetermined>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 3), &mut rng);
+                test_qr(a)
+            }
+
+            #[test]
+            fn [<least_squares_qr_ $scalar _overdetermined_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 3).f(), &mut rng);
+                test_qr(a)
+            }
+
+            #[test]
+            fn [<least_squares_qr_ $scalar _underdetermined>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((3, 5), &mut rng);
+                test_qr(a)
+            }
+
+            #[test]
+            fn [<least_squares_qr_ $scalar _underdetermined_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((3, 5).f(), &mut rng);
+                test_qr(a)
+            }
+
+            #[test]
+            fn [<least_squares_qr_ $scalar _rank_deficient>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let mut a: Array2<$scalar> = random_using((5, 3), &mut rng);
+                a.column_mut(0).fill(<$scalar>::from_real(0.0));
+                let b: Array1<$scalar> = random_using(5, &mut rng);
+                assert!(a.least_squares_qr(&b).is_err());
+                // SVD based solver still works
+                assert_eq!(a.least_squares(&b).unwrap().rank, 2);
+            }
+        }
+    };
+}
+
+impl_qr!(f64);
+impl_qr!(c64);
+
+#[test]
+fn gls_identity() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((6, 3), &mut rng);
+    let b: Array1<f64> = random_using(6, &mut rng);
+    let ols = a.least_squares(&b).unwrap();
+    let res = gls(&a, &b, &Array2::eye(6)).unwrap();
+    assert_close_l2!(&res.solution, &ols.solution, 1e-9);
+}
+
+#[test]
+fn gls_diagonal() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((6, 3), &mut rng);
+    let b: Array2<c64> = random_using((6, 2), &mut rng);
+    let sigma: Array1<f64> = random_using(6, &mut rng);
+    let sigma = sigma.mapv(|s| s + 0.5);
+    let cov = Array2::from_diag(&sigma.mapv(|s| c64::new(s * s, 0.0)));
+    // weighted least squares scaling each row by 1/sigma
+    let w = sigma.mapv(|s| c64::new(1.0 / s, 0.0)).insert_axis(Axis(1));
+    let wls = (&a * &w).least_squares(&(&b * &w)).unwrap();
+    let res = gls(&a, &b, &cov).unwrap();
+    assert_close_l2!(&res.solution, &wls.solution, 1e-9);
+}
+
+#[test]
+fn gls_factorized_upper() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((6, 3), &mut rng);
+    let b: Array1<c64> = random_using(6, &mut rng);
+    let cov: Array2<c64> = random_hpd_using(6, &mut rng);
+    let expected = gls(&a, &b, &cov).unwrap();
+    let f = cov.factorizec(UPLO::Upper).unwrap();
+    let res = gls_factorized(&a, &b, &f).unwrap();
+    assert_close_l2!(&res.solution, &expected.solution, 1e-9);
+}
@@ -0,0 +1,76 @@
+use ndarray::*;
+use ndarray_linalg::{error::LinalgError, *};
+
+fn test_exp_log<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real)
+where
+    T::Complex: Lapack,
+{
+    let x = a.logm().unwrap();
+    let a_c = a.mapv(|a| a.as_c());
+    assert_close_l2!(&expm(&x).unwrap(), &a_c, rtol);
+}
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<logm_hpd_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(4, &mut rng);
+                test_exp_log(&a, 1e-9);
+            }
+
+            #[test]
+            fn [<logm_near_identity_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let e: Array2<$elem> = random_using((4, 4), &mut rng);
+                let a = Array2::<$elem>::eye(4) + e.mapv(|x| x * <$elem>::real(0.1));
+                test_exp_log(&a, 1e-9);
+                test_exp_log(&a.t().to_owned(), 1e-9);
+            }
+
+            #[test]
+            fn [<logm_expm_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // log(exp(B)) = B if the eigenvalues of B have imaginary parts in (-π, π)
+                let b: Array2<$elem> = random_using((4, 4), &mut rng);
+                let a = expm(&b).unwrap();
+                let ans = b.mapv(|x| x.as_c());
+                assert_close_l2!(&a.logm().unwrap(), &ans, 1e-9);
+            }
+
+            #[test]
+            fn [<logm_diagonalizable_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let v: Array2<$elem> = random_regular_using(3, &mut rng);
+                let v_inv = v.inv().unwrap();
+                let e = std::f64::consts::E;
+                let d: Array2<$elem> = from_diag(&[e, e * e, 100.0]).mapv(|x| <$elem>::from_real(x));
+                let d_log: Array2<$elem> = from_diag(&[1.0, 2.0, 100.0f64.ln()]).mapv(|x| <$elem>::from_real(x));
+                let a = v.dot(&d).dot(&v_inv);
+                let ans = v.dot(&d_log).dot(&v_inv).mapv(|x| x.as_c());
+                assert_close_l2!(&a.logm().unwrap(), &ans, 1e-9);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
+
+#[test]
+fn logm_negative_eigenvalue() {
+    let a: Array2<f64> = array![[-1.0, 0.0], [0.0, 1.0]];
+    let x = a.logm().unwrap();
+    let ans = array![
+        [c64::new(0.0, std::f64::consts::PI), c64::new(0.0, 0.0)],
+        [c64::new(0.0, 0.0), c64::new(0.0, 0.0)]
+    ];
+    assert_close_l2!(&x, &ans, 1e-12);
+}
+
+#[test]
+fn logm_singular() {
+    let a: Array2<f64> = array![[0.0, 1.0], [0.0, 2.0]];
+    assert!(matches!(a.logm(), Err(LinalgError::NoLogarithm)));
+}
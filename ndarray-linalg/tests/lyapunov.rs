@@ -0,0 +1,95 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Kronecker product `B ⊗ C`
+fn kron<T: Scalar>(b: &Array2<T>, c: &Array2<T>) -> Array2<T> {
+    let (n, m) = c.dim();
+    Array2::from_shape_fn((b.nrows() * n, b.ncols() * m), |(i, j)| {
+        b[(i / n, j / m)] * c[(i % n, j % m)]
+    })
+}
+
+/// Solve `M vec(X) = vec(Q)` where `vec` stacks the columns
+fn solve_vec<T: Scalar + Lapack>(m: &Array2<T>, q: &Array2<T>) -> Array2<T> {
+    let n = q.nrows();
+    let vec_q: Array1<T> = q.t().iter().cloned().collect();
+    let vec_x = m.solve(&vec_q).unwrap();
+    Array2::from_shape_vec((n, n).f(), vec_x.to_vec()).unwrap()
+}
+
+fn test_lyapunov<T: Scalar + Lapack>(n: usize)
+where
+    T::Complex: Lapack,
+{
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // stable matrix, whose eigenvalues have negative real parts
+    let mut a: Array2<T> = random_using((n, n), &mut rng);
+    for i in 0..n {
+        a[(i, i)] -= T::from_real(T::real(2 * n));
+    }
+    let q: Array2<T> = random_hermite_using(n, &mut rng);
+    let x = solve_lyapunov(&a, &q).unwrap();
+    let ah: Array2<T> = conjugate(&a);
+    assert_close_l2!(&(a.dot(&x) + x.dot(&ah)), &q, T::real(1e-9));
+    let xh: Array2<T> = conjugate(&x);
+    assert_eq!(x, xh);
+
+    let eye = Array2::<T>::eye(n);
+    let m = kron(&eye, &a) + kron(&a.mapv(|x| x.conj()), &eye);
+    assert_close_l2!(&x, &solve_vec(&m, &q), T::real(1e-9));
+
+    // non-Hermitian right hand side
+    let q: Array2<T> = random_using((n, n), &mut rng);
+    let x = solve_lyapunov(&a, &q).unwrap();
+    assert_close_l2!(&(a.dot(&x) + x.dot(&ah)), &q, T::real(1e-9));
+}
+
+fn test_discrete_lyapunov<T: Scalar + Lapack>(n: usize)
+where
+    T::Complex: Lapack,
+{
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // stable matrix, whose spectral radius is less than 1
+    let a: Array2<T> = random_using((n, n), &mut rng);
+    let a = a.mapv_into(|x| x.div_real(T::real(2 * n)));
+    let q: Array2<T> = random_hermite_using(n, &mut rng);
+    let x = solve_discrete_lyapunov(&a, &q).unwrap();
+    let ah: Array2<T> = conjugate(&a);
+    let minus_q = q.mapv(|x| -x);
+    assert_close_l2!(&(a.dot(&x).dot(&ah) - &x), &minus_q, T::real(1e-9));
+    let xh: Array2<T> = conjugate(&x);
+    assert_eq!(x, xh);
+
+    let eye = Array2::<T>::eye(n * n);
+    let m = kron(&a.mapv(|x| x.conj()), &a) - eye;
+    assert_close_l2!(&x, &solve_vec(&m, &minus_q), T::real(1e-9));
+}
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<lyapunov_ $elem>]() {
+                test_lyapunov::<$elem>(1);
+                test_lyapunov::<$elem>(4);
+            }
+
+            #[test]
+            fn [<discrete_lyapunov_ $elem>]() {
+                test_discrete_lyapunov::<$elem>(1);
+                test_discrete_lyapunov::<$elem>(4);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
+
+#[test]
+fn lyapunov_shape_mismatch() {
+    let a = Array2::<f64>::eye(3);
+    let q = Array2::<f64>::eye(2);
+    assert!(solve_lyapunov(&a, &q).is_err());
+    assert!(solve_discrete_lyapunov(&a, &q).is_err());
+}
@@ -84,3 +84,21 @@ fn qr_over_real() {
 fn qr_over_complex() {
     qr_over::<c64>();
 }
+
+/// Orthogonality `|Q^H Q - I|` of the basis for the columns of Hilbert matrix
+fn hilbert_orthogonality(reorthogonalize: bool) -> f64 {
+    const N: usize = 10;
+    let a = Array2::from_shape_fn((N, N), |(i, j)| 1.0 / (i + j + 1) as f64);
+    let ortho = MGS::new(N, 1e-14).reorthogonalize(reorthogonalize);
+    let (q, r) = krylov::qr(a.axis_iter(Axis(1)), ortho, Strategy::Terminate);
+    assert_eq!(q.shape(), &[N, N]);
+    assert_close_l2!(&q.dot(&r), &a, 1e-12);
+    (q.t().dot(&q) - Array2::<f64>::eye(N)).norm_l2()
+}
+
+#[test]
+fn qr_reorthogonalize_ill_conditioned() {
+    // single MGS loses orthogonality in proportion to the condition number ~1e13
+    assert!(hilbert_orthogonality(false) > 1e-6);
+    assert!(hilbert_orthogonality(true) < 1e-12);
+}
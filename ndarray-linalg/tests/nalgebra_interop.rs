@@ -0,0 +1,38 @@
+#![cfg(feature = "nalgebra")]
+
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn nalgebra_roundtrip() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a1: Array2<f64> = random_using((3, 4), &mut rng);
+    let a2: Array2<f64> = random_using((3, 4).f(), &mut rng);
+    for a in [a1, a2] {
+        let m = to_nalgebra(&a.view());
+        assert_eq!(m.shape(), (3, 4));
+        for ((i, j), &x) in a.indexed_iter() {
+            assert_eq!(m[(i, j)], x);
+        }
+        assert_eq!(from_nalgebra(&m), a);
+    }
+}
+
+#[test]
+fn nalgebra_solve() {
+    let m = nalgebra::DMatrix::<c64>::from_row_slice(
+        2,
+        2,
+        &[
+            c64::new(2.0, 0.0),
+            c64::new(1.0, 1.0),
+            c64::new(1.0, -1.0),
+            c64::new(3.0, 0.0),
+        ],
+    );
+    let b: Array1<c64> = array![c64::new(1.0, 0.0), c64::new(0.0, 1.0)];
+    let a = from_nalgebra(&m);
+    assert_eq!(a[(0, 1)], c64::new(1.0, 1.0));
+    let x = a.solve(&b).unwrap();
+    assert_close_l2!(&a.dot(&x), &b, 1e-9);
+}
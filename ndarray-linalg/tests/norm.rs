@@ -0,0 +1,83 @@
+use ndarray::*;
+use ndarray_linalg::{error::LinalgError, *};
+
+#[test]
+fn norm_p_vector() {
+    let x: Array1<f64> = array![3.0, -4.0, 12.0];
+    assert_rclose!(x.norm_p(1.0).unwrap(), 19.0, 1e-12);
+    assert_rclose!(x.norm_p(2.0).unwrap(), 13.0, 1e-12);
+    assert_rclose!(
+        x.norm_p(3.0).unwrap(),
+        (27.0f64 + 64.0 + 1728.0).cbrt(),
+        1e-12
+    );
+    assert_rclose!(x.norm_p(f64::INFINITY).unwrap(), 12.0, 1e-12);
+    // quasi-norm for p < 1
+    assert_rclose!(
+        x.norm_p(0.5).unwrap(),
+        (3.0f64.sqrt() + 2.0 + 12.0f64.sqrt()).powi(2),
+        1e-12
+    );
+}
+
+#[test]
+fn norm_p_special_cases() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let x: Array1<c64> = random_using(5, &mut rng);
+    assert_rclose!(x.norm_p(1.0).unwrap(), x.norm_l1(), 1e-12);
+    assert_rclose!(x.norm_p(2.0).unwrap(), x.norm_l2(), 1e-12);
+    assert_rclose!(x.norm_p(f64::INFINITY).unwrap(), x.norm_max(), 1e-12);
+    // entrywise norm of a matrix
+    let a: Array2<f64> = random_using((3, 4), &mut rng);
+    assert_rclose!(a.norm_p(2.0).unwrap(), a.opnorm_fro().unwrap(), 1e-12);
+    assert_rclose!(a.norm_p(1.0).unwrap(), a.norm_l1(), 1e-12);
+}
+
+#[test]
+fn norm_p_large() {
+    // x^p overflows without scaling
+    let x: Array1<f64> = array![1e200, 1e200];
+    assert_rclose!(x.norm_p(2.0).unwrap(), 2.0f64.sqrt() * 1e200, 1e-12);
+    assert_rclose!(x.norm_p(100.0).unwrap(), 2.0f64.powf(0.01) * 1e200, 1e-12);
+    // and approaches the maximum norm
+    let x: Array1<f64> = array![1.0, 2.0, 3.0];
+    assert_rclose!(x.norm_p(1000.0).unwrap(), 3.0, 1e-3);
+
+    let z: Array1<f64> = Array1::zeros(3);
+    assert_eq!(z.norm_p(3.0).unwrap(), 0.0);
+}
+
+#[test]
+fn norm_p_nonpositive() {
+    let x: Array1<f64> = array![1.0, 2.0];
+    for &p in &[0.0, -1.0, f64::NAN] {
+        assert!(matches!(
+            x.norm_p(p),
+            Err(LinalgError::UnsupportedNormOrder { .. })
+        ));
+    }
+}
+
+#[test]
+fn norm_induced() {
+    let a: Array2<f64> = array![[1.0, -2.0], [3.0, 4.0]];
+    assert_rclose!(a.norm_induced(1.0).unwrap(), 6.0, 1e-12);
+    assert_rclose!(a.norm_induced(f64::INFINITY).unwrap(), 7.0, 1e-12);
+    assert_rclose!(
+        a.norm_induced(2.0).unwrap(),
+        a.opnorm_spectral().unwrap(),
+        1e-12
+    );
+    // the 2-norm of a rank-one matrix `x y^T` is `|x| |y|`
+    let b: Array2<f64> = array![[3.0], [4.0]].dot(&array![[1.0, 2.0, 2.0]]);
+    assert_rclose!(b.norm_induced(2.0).unwrap(), 15.0, 1e-12);
+}
+
+#[test]
+fn norm_induced_unsupported() {
+    let a: Array2<f64> = Array2::eye(2);
+    assert!(matches!(
+        a.norm_induced(3.0),
+        Err(LinalgError::UnsupportedNormOrder { p }) if p == 3.0
+    ));
+}
@@ -0,0 +1,49 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_normest1<T: Scalar + Lapack>(a: &Array2<T>, t: usize) {
+    let ah: Array2<T> = conjugate(a);
+    let est = normest1(a.nrows(), t, |x| a.dot(&x), |x| ah.dot(&x));
+    let exact = a.opnorm_one().unwrap();
+    // The estimate is a lower bound, and usually within a factor of 3
+    assert!(est <= exact * T::real(1.0 + 1e-12));
+    assert!(est >= exact / T::real(3.0));
+}
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<normest1_random_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &n in &[1, 5, 20] {
+                    let a: Array2<$elem> = random_using((n, n), &mut rng);
+                    for &t in &[2, 4] {
+                        test_normest1(&a, t);
+                    }
+                }
+            }
+
+            #[test]
+            fn [<normest1_inverse_ $elem>]() {
+                // |A^{-1}|_1 only through the LU factorization
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_regular_using(10, &mut rng);
+                let f = a.factorize().unwrap();
+                let est = normest1(10, 2, |x| f.solve(&x).unwrap(), |x| f.solve_h(&x).unwrap());
+                let exact = a.inv().unwrap().opnorm_one().unwrap();
+                assert!(est <= exact * (1.0 + 1e-9));
+                assert!(est >= exact / 3.0);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
+
+#[test]
+fn normest1_empty() {
+    let a: Array2<f64> = Array2::zeros((0, 0));
+    assert_eq!(normest1(0, 2, |x| a.dot(&x), |x| a.dot(&x)), 0.0);
+}
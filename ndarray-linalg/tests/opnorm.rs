@@ -51,3 +51,38 @@ fn opnorm_4x3() {
 fn opnorm_4x3_t() {
     test(gen(4, 3, true), 42.0, 24.0, 650.0.sqrt());
 }
+
+fn test_spectral<T: Scalar + Lapack>(m: usize, n: usize) {
+    // U diag(s) V^H with the known singular values s
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let k = m.min(n);
+    let u: Array2<T> = random_unitary_using(m, &mut rng);
+    let v: Array2<T> = random_unitary_using(n, &mut rng);
+    let vh: Array2<T> = conjugate(&v);
+    let mut sigma = Array2::<T>::zeros((m, n));
+    for i in 0..k {
+        sigma[(i, i)] = T::from_real(T::real(1.0 + i as f64));
+    }
+    let a = u.dot(&sigma).dot(&vh);
+    assert_rclose!(
+        a.opnorm_spectral().unwrap(),
+        T::real(k as f64),
+        T::real(1e-9)
+    );
+    let a_t = a.t().to_owned();
+    assert_rclose!(
+        a_t.opnorm_spectral().unwrap(),
+        T::real(k as f64),
+        T::real(1e-9)
+    );
+}
+
+#[test]
+fn opnorm_spectral() {
+    for &(m, n) in &[(3, 3), (3, 5), (5, 3)] {
+        test_spectral::<f64>(m, n);
+        test_spectral::<c64>(m, n);
+    }
+    let a: Array2<f64> = Array2::zeros((0, 0));
+    assert_eq!(a.opnorm_spectral().unwrap(), 0.0);
+}
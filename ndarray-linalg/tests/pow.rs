@@ -0,0 +1,48 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<powi_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_regular_using(4, &mut rng);
+                let mut naive = Array2::<$elem>::eye(4);
+                for n in 0..8 {
+                    assert_close_l2!(&a.powi(n).unwrap(), &naive, 1e-9);
+                    naive = naive.dot(&a);
+                }
+                let a_inv = a.inv().unwrap();
+                let mut naive = a_inv.clone();
+                for n in 1..5 {
+                    assert_close_l2!(&a.powi(-n).unwrap(), &naive, 1e-9);
+                    naive = naive.dot(&a_inv);
+                }
+            }
+
+            #[test]
+            fn [<powf_sqrt_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(4, &mut rng);
+                let x = a.powf(0.5).unwrap();
+                assert_close_l2!(&x, &a.sqrtm().unwrap(), 1e-9);
+                assert_close_l2!(&x.dot(&x), &a.mapv(|x| x.as_c()), 1e-9);
+            }
+
+            #[test]
+            fn [<powf_integer_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(3, &mut rng);
+                let a3 = a.powi(3).unwrap().mapv(|x| x.as_c());
+                assert_close_l2!(&a.powf(3.0).unwrap(), &a3, 1e-9);
+                // A^{1/3} A^{2/3} = A
+                let x = a.powf(1.0 / 3.0).unwrap().dot(&a.powf(2.0 / 3.0).unwrap());
+                assert_close_l2!(&x, &a.mapv(|x| x.as_c()), 1e-9);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
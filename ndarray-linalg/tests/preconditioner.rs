@@ -0,0 +1,116 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+#[test]
+fn jacobi_reduces_iterations() {
+    const N: usize = 20;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // diagonally dominant with widely spread diagonal
+    let d = Array1::from_shape_fn(N, |i| 10f64.powf(4.0 * i as f64 / N as f64));
+    let offdiag: Array2<f64> = random_using((N, N), &mut rng);
+    let a = Array2::from_diag(&d) + offdiag * 0.1;
+    let b: Array1<f64> = random_using(N, &mut rng);
+    let expected = a.solve(&b).unwrap();
+
+    let (x, plain) = gmres(|v| a.dot(&v), &b, 1e-10, 3 * N, N).unwrap();
+    assert_close_l2!(&x, &expected, 1e-8);
+
+    let jacobi = Jacobi::new(&a).unwrap();
+    let (x, precond) = gmres_preconditioned(|v| a.dot(&v), jacobi, &b, 1e-10, 3 * N, N).unwrap();
+    assert_close_l2!(&x, &expected, 1e-8);
+    assert!(*precond.last().unwrap() <= 1e-10);
+    assert!(precond.len() < plain.len());
+}
+
+#[test]
+fn jacobi_not_square() {
+    let a: Array2<f64> = Array2::ones((2, 3));
+    assert!(Jacobi::new(&a).is_err());
+}
+
+#[test]
+fn jacobi_zero_diagonal() {
+    let a: Array2<f64> = array![[1.0, 2.0], [3.0, 0.0]];
+    assert!(matches!(
+        Jacobi::new(&a),
+        Err(error::LinalgError::ZeroDiagonal { index: 1 })
+    ));
+}
+
+fn incomplete_cholesky_dense<A: Scalar + Lapack>() {
+    const N: usize = 6;
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<A> = random_hpd_using(N, &mut rng);
+    let b: Array1<A> = random_using(N, &mut rng);
+
+    // no fill-in is dropped for a dense matrix
+    let ic = IncompleteCholesky::new(&a).unwrap();
+    let l = ic.factor();
+    let lh = l.t().mapv(|x| x.conj());
+    assert_close_l2!(&l.dot(&lh), &a, A::real(1e-9));
+
+    // exact preconditioner converges in one iteration
+    let (x, history) = gmres_preconditioned(|v| a.dot(&v), ic, &b, A::real(1e-9), N, N).unwrap();
+    assert_eq!(history.len(), 2);
+    assert_close_l2!(&a.dot(&x), &b, A::real(1e-9));
+}
+
+#[test]
+fn incomplete_cholesky_dense_real() {
+    incomplete_cholesky_dense::<f64>();
+}
+
+#[test]
+fn incomplete_cholesky_dense_complex() {
+    incomplete_cholesky_dense::<c64>();
+}
+
+#[test]
+fn incomplete_cholesky_sparsity() {
+    // 2D Laplacian has fill-in in the Cholesky factor
+    const M: usize = 4;
+    let n = M * M;
+    let mut a = Array2::<f64>::zeros((n, n));
+    for i in 0..M {
+        for j in 0..M {
+            let k = i * M + j;
+            a[(k, k)] = 4.0;
+            if j + 1 < M {
+                a[(k, k + 1)] = -1.0;
+                a[(k + 1, k)] = -1.0;
+            }
+            if i + 1 < M {
+                a[(k, k + M)] = -1.0;
+                a[(k + M, k)] = -1.0;
+            }
+        }
+    }
+    let ic = IncompleteCholesky::new(&a).unwrap();
+    for ((i, j), &l) in ic.factor().indexed_iter() {
+        if a[(i, j)] == 0.0 || i < j {
+            assert_eq!(l, 0.0);
+        }
+    }
+}
+
+#[test]
+fn incomplete_cholesky_not_positive_definite() {
+    let a = array![[1.0, 2.0], [2.0, 1.0]];
+    match IncompleteCholesky::new(&a) {
+        Err(error::LinalgError::NotPositiveDefinite { minor }) => assert_eq!(minor, 2),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn closure_preconditioner() {
+    let mut calls = 0;
+    {
+        let mut p = |v: ArrayView1<f64>| {
+            calls += 1;
+            v.to_owned()
+        };
+        assert_eq!(p.apply(array![1.0, 2.0].view()), array![1.0, 2.0]);
+    }
+    assert_eq!(calls, 1);
+}
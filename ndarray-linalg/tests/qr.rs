@@ -79,3 +79,238 @@ fn qr_4x3_t() {
     let a = random_using((4, 3).f(), &mut rng);
     test(&a, 4, 3);
 }
+
+fn test_complement<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (m, k) = a.dim();
+    let c = a.orthonormal_complement().unwrap();
+    assert_eq!(c.dim(), (m, m - k));
+    let ch: Array2<T> = conjugate(&c);
+    let ah: Array2<T> = conjugate(a);
+    assert_close_l2!(&ch.dot(&c), &Array::eye(m - k), T::real(1e-7));
+    assert!(ah.dot(&c).iter().all(|x| x.abs() < T::real(1e-7)));
+}
+
+#[test]
+fn orthonormal_complement() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((5, 2), &mut rng);
+    test_complement(&a);
+    let a: Array2<c64> = random_using((5, 3).f(), &mut rng);
+    test_complement(&a);
+    let a: Array2<f64> = random_using((3, 3), &mut rng);
+    test_complement(&a);
+    let a: Array2<f64> = random_using((2, 3), &mut rng);
+    assert!(a.orthonormal_complement().is_err());
+}
+
+fn test_lq<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let k = min(n, m);
+    let (l, q): (Array2<_>, Array2<_>) = a.lq().unwrap();
+    assert_eq!(l.dim(), (n, k));
+    assert_eq!(q.dim(), (k, m));
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&q.dot(&qh), &Array::eye(k), T::real(1e-7));
+    assert_close_l2!(&l.dot(&q), a, T::real(1e-7));
+    assert!(l.is_lower_triangular());
+}
+
+fn test_ql<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let k = min(n, m);
+    let (q, l): (Array2<_>, Array2<_>) = a.ql().unwrap();
+    assert_eq!(q.dim(), (n, k));
+    assert_eq!(l.dim(), (k, m));
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array::eye(k), T::real(1e-7));
+    assert_close_l2!(&q.dot(&l), a, T::real(1e-7));
+    for ((i, j), x) in l.indexed_iter() {
+        if j > i + m - k {
+            assert_eq!(*x, T::zero());
+        }
+    }
+}
+
+macro_rules! test_lq_ql_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<lq_ql_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &(n, m) in &[(3, 3), (3, 5), (5, 3)] {
+                    let a: Array2<$type> = random_using((n, m), &mut rng);
+                    test_lq(&a);
+                    test_ql(&a);
+                    let a: Array2<$type> = random_using((n, m).f(), &mut rng);
+                    test_lq(&a);
+                    test_ql(&a);
+                }
+            }
+
+            #[test]
+            fn [<lq_ql_inplace_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4), &mut rng);
+                let mut q = a.clone();
+                let (l, q) = q.lq_inplace().unwrap();
+                assert_close_l2!(&l.dot(&*q), &a, 1e-7);
+                let mut q = a.clone();
+                let (q, l) = q.ql_inplace().unwrap();
+                assert_close_l2!(&q.dot(&l), &a, 1e-7);
+            }
+        }
+    };
+}
+
+test_lq_ql_impl!(f64);
+test_lq_ql_impl!(c64);
+
+fn test_qr_raw<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (m, n) = a.dim();
+    let k = min(m, n);
+    let (q, r): (Array2<_>, Array2<_>) = a.qr().unwrap();
+    let packed = a.qr_raw().unwrap();
+    assert_eq!(packed.tau.len(), k);
+    assert_close_l2!(&packed.r(), &r, T::real(1e-7));
+
+    // Q applied to the identity from both sides
+    let mut q_full: Array2<T> = Array::eye(m);
+    packed
+        .apply_q(Side::Left, Transpose::No, &mut q_full)
+        .unwrap();
+    assert_close_l2!(&q_full.slice(s![.., ..k]).to_owned(), &q, T::real(1e-7));
+    let mut q_right: Array2<T> = Array::eye(m);
+    packed
+        .apply_q(Side::Right, Transpose::No, &mut q_right)
+        .unwrap();
+    assert_close_l2!(&q_right, &q_full, T::real(1e-7));
+
+    // Q^H Q = I with C-layout operand
+    let mut qhq = q_full.as_standard_layout().to_owned();
+    packed
+        .apply_q(Side::Left, Transpose::Hermite, &mut qhq)
+        .unwrap();
+    assert_close_l2!(&qhq, &Array::eye(m), T::real(1e-7));
+
+    let mut wrong: Array2<T> = Array::eye(m + 1);
+    assert!(packed
+        .apply_q(Side::Left, Transpose::No, &mut wrong)
+        .is_err());
+}
+
+macro_rules! test_qr_raw_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<qr_raw_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &(m, n) in &[(3, 3), (5, 3), (3, 5)] {
+                    let a: Array2<$type> = random_using((m, n), &mut rng);
+                    test_qr_raw(&a);
+                }
+            }
+        }
+    };
+}
+
+test_qr_raw_impl!(f64);
+test_qr_raw_impl!(c64);
+
+fn test_qr_pivot<T: Scalar + Lapack>(a: &Array2<T>, rank: usize) {
+    let (n, m) = a.dim();
+    let k = min(n, m);
+    let (q, r, perm) = a.qr_pivot().unwrap();
+    assert_eq!(q.dim(), (n, k));
+    assert_eq!(r.dim(), (k, m));
+    let mut sorted = perm.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..m).collect::<Vec<_>>());
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array::eye(k), T::real(1e-7));
+    assert_close_l2!(&q.dot(&r), &a.select(Axis(1), &perm), T::real(1e-7));
+    assert!(r.is_upper_triangular());
+    for i in 1..k {
+        assert!(r[(i, i)].abs() <= r[(i - 1, i - 1)].abs() * T::real(1.0 + 1e-9));
+    }
+    assert_eq!(a.rank(T::real(1e-9)).unwrap(), rank);
+}
+
+macro_rules! test_qr_pivot_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<qr_pivot_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 4), &mut rng);
+                test_qr_pivot(&a, 4);
+                let a: Array2<$type> = random_using((3, 5), &mut rng);
+                test_qr_pivot(&a, 3);
+                // rank deficient
+                let u: Array2<$type> = random_using((6, 2), &mut rng);
+                let v: Array2<$type> = random_using((2, 5), &mut rng);
+                test_qr_pivot(&u.dot(&v), 2);
+                let z: Array2<$type> = Array::zeros((3, 3));
+                assert_eq!(z.rank(1e-9).unwrap(), 0);
+            }
+        }
+    };
+}
+
+test_qr_pivot_impl!(f64);
+test_qr_pivot_impl!(c64);
+
+fn test_qr_append<T: Scalar + Lapack>(a: &Array2<T>, row: &Array1<T>, col: &Array1<T>) {
+    let (m, n) = a.dim();
+    let check = |q: &Array2<T>, r: &Array2<T>, a: &Array2<T>| {
+        let (m, n) = a.dim();
+        let k = min(m, n);
+        assert_eq!(q.dim(), (m, k));
+        assert_eq!(r.dim(), (k, n));
+        let qh: Array2<T> = conjugate(q);
+        assert_close_l2!(&qh.dot(q), &Array::eye(k), T::real(1e-9));
+        assert_close_l2!(&q.dot(r), a, T::real(1e-9));
+        assert!(r.is_upper_triangular());
+    };
+
+    let a_row = concatenate![Axis(0), a.view(), row.view().insert_axis(Axis(0))];
+    let (mut q, mut r): (Array2<T>, Array2<T>) = a.qr().unwrap();
+    let mut r_only = r.clone();
+    qr_append_row(Some(&mut q), &mut r, row);
+    check(&q, &r, &a_row);
+    qr_append_row(None, &mut r_only, row);
+    assert_close_l2!(&r_only, &r, T::real(1e-9));
+
+    let a_col = concatenate![Axis(1), a.view(), col.view().insert_axis(Axis(1))];
+    let (mut q, mut r): (Array2<T>, Array2<T>) = a.qr().unwrap();
+    qr_append_column(&mut q, &mut r, col);
+    check(&q, &r, &a_col);
+
+    // a column in the column space of a tall matrix
+    if m > n {
+        let col = a.column(0).to_owned();
+        let a_col = concatenate![Axis(1), a.view(), col.view().insert_axis(Axis(1))];
+        let (mut q, mut r): (Array2<T>, Array2<T>) = a.qr().unwrap();
+        qr_append_column(&mut q, &mut r, &col);
+        check(&q, &r, &a_col);
+    }
+}
+
+macro_rules! test_qr_append_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<qr_append_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &(m, n) in &[(3, 3), (5, 3), (3, 5)] {
+                    let a: Array2<$type> = random_using((m, n), &mut rng);
+                    let row: Array1<$type> = random_using(n, &mut rng);
+                    let col: Array1<$type> = random_using(m, &mut rng);
+                    test_qr_append(&a, &row, &col);
+                }
+            }
+        }
+    };
+}
+
+test_qr_append_impl!(f64);
+test_qr_append_impl!(c64);
@@ -0,0 +1,74 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_qz<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, rtol: T::Real) {
+    let n = a.nrows();
+    let (s, t, q, z) = (a.view(), b.view()).qz().unwrap();
+    let qh = q.t().mapv(|x| x.conj());
+    let zh = z.t().mapv(|x| x.conj());
+    assert_close_l2!(&q.dot(&s).dot(&zh), a, rtol);
+    assert_close_l2!(&q.dot(&t).dot(&zh), b, rtol);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(n), rtol);
+    assert_close_l2!(&zh.dot(&z), &Array2::eye(n), rtol);
+    assert!(t.is_upper_triangular());
+}
+
+/// Sort complex numbers lexicographically by real and imaginary parts
+fn sorted(mut v: Vec<c64>) -> Array1<c64> {
+    v.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+    Array1::from(v)
+}
+
+#[test]
+fn qz_f64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((4, 4), &mut rng);
+    let b: Array2<f64> = random_using((4, 4), &mut rng);
+    test_qz(&a, &b, 1e-9);
+    let a: Array2<f64> = random_using((4, 4).f(), &mut rng);
+    test_qz(&a, &b, 1e-9);
+}
+
+#[test]
+fn qz_c64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((4, 4), &mut rng);
+    let b: Array2<c64> = random_using((4, 4), &mut rng);
+    test_qz(&a, &b, 1e-9);
+    let (s, t, _, _) = (a.view(), b.view()).qz().unwrap();
+    assert!(s.is_upper_triangular());
+
+    // S[i, i] / T[i, i] are the eigenvalues of B^{-1} A
+    let ratio = sorted(s.diag().iter().zip(t.diag()).map(|(s, t)| s / t).collect());
+    let (eigs, _) = b.inv().unwrap().dot(&a).eig().unwrap();
+    assert_close_l2!(&ratio, &sorted(eigs.to_vec()), 1e-9);
+}
+
+#[test]
+fn qz_real_eigenvalues() {
+    // A = B V D V^{-1} has the real generalized eigenvalues D, which gives a
+    // triangular S in the real QZ decomposition
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array2<f64> = random_regular_using(3, &mut rng);
+    let v: Array2<f64> = random_regular_using(3, &mut rng);
+    let a = b
+        .dot(&v)
+        .dot(&from_diag(&[1.0, -2.0, 3.0]))
+        .dot(&v.inv().unwrap());
+    test_qz(&a, &b, 1e-9);
+    let (s, t, _, _) = (a.view(), b.view()).qz().unwrap();
+    assert!(s.is_upper_triangular());
+    let ratio = sorted(
+        s.diag()
+            .iter()
+            .zip(t.diag())
+            .map(|(s, t)| c64::new(s / t, 0.0))
+            .collect(),
+    );
+    let ans = sorted(vec![
+        c64::new(-2.0, 0.0),
+        c64::new(1.0, 0.0),
+        c64::new(3.0, 0.0),
+    ]);
+    assert_close_l2!(&ratio, &ans, 1e-9);
+}
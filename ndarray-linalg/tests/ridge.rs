@@ -0,0 +1,33 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Leave-one-out error by refitting without each observation
+fn loocv_naive(x: &Array2<f64>, y: &Array1<f64>, lambda: f64) -> f64 {
+    let n = x.nrows();
+    let mut press = 0.0;
+    for i in 0..n {
+        let rows: Vec<usize> = (0..n).filter(|&j| j != i).collect();
+        let xi = x.select(Axis(0), &rows);
+        let yi = y.select(Axis(0), &rows);
+        let gram = xi.t().dot(&xi) + Array2::<f64>::eye(x.ncols()) * lambda;
+        let beta = gram.solve(&xi.t().dot(&yi)).unwrap();
+        let e = y[i] - x.row(i).dot(&beta);
+        press += e * e;
+    }
+    press / n as f64
+}
+
+#[test]
+fn ridge_loocv_naive() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for &(n, p) in &[(10, 3), (6, 8)] {
+        let x: Array2<f64> = random_using((n, p), &mut rng);
+        let y: Array1<f64> = random_using(n, &mut rng);
+        let lambdas = [1e-2, 0.1, 1.0, 10.0];
+        let loocv = ridge_loocv(&x.view(), &y.view(), &lambdas).unwrap();
+        assert_eq!(loocv.len(), lambdas.len());
+        for (&lambda, &cv) in lambdas.iter().zip(loocv.iter()) {
+            assert_rclose!(cv, loocv_naive(&x, &y, lambda), 1e-9);
+        }
+    }
+}
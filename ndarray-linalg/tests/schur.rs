@@ -0,0 +1,65 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_schur<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real)
+where
+    T::Complex: Lapack,
+{
+    let n = a.nrows();
+    let (q, t) = a.schur().unwrap();
+    let qh = q.t().mapv(|x| x.conj());
+    assert_close_l2!(&q.dot(&t).dot(&qh), a, rtol);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(n), rtol);
+    // quasi-triangular: no two consecutive non-zero sub-diagonal elements
+    for i in 0..n {
+        for j in 0..i.saturating_sub(1) {
+            assert_eq!(t[(i, j)], T::zero());
+        }
+    }
+    for i in 2..n {
+        assert!(t[(i, i - 1)].is_zero() || t[(i - 1, i - 2)].is_zero());
+    }
+
+    let (q, t) = a.schur_complex().unwrap();
+    let qh = q.t().mapv(|x| x.conj());
+    let a_c = a.mapv(|x| x.as_c());
+    assert_close_l2!(&q.dot(&t).dot(&qh), &a_c, rtol);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(n), rtol);
+    assert!(t.is_upper_triangular());
+}
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<schur_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((5, 5), &mut rng);
+                test_schur(&a, 1e-9);
+            }
+
+            #[test]
+            fn [<schur_t_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((5, 5).f(), &mut rng);
+                test_schur(&a, 1e-9);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
+
+#[test]
+fn schur_rotation() {
+    // eigenvalues ±i gives a 2x2 block in the real Schur form
+    let a: Array2<f64> = array![[0.0, -2.0], [2.0, 0.0]];
+    let (_, t) = a.schur().unwrap();
+    assert!(t[(1, 0)].abs() > 1.0);
+    let (_, t) = a.schur_complex().unwrap();
+    let mut eigs: Vec<f64> = t.diag().iter().map(|e| e.im).collect();
+    eigs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_rclose!(eigs[0], -2.0, 1e-12);
+    assert_rclose!(eigs[1], 2.0, 1e-12);
+}
@@ -1,7 +1,8 @@
 use ndarray::prelude::*;
 use ndarray_linalg::{
-    assert_aclose, assert_close_l2, c32, c64, random_hpd_using, random_using, solve::*,
-    OperationNorm, Scalar,
+    assert_aclose, assert_close_l2, c32, c64, error::LinalgError, random_hpd_using,
+    random_regular_using, random_unitary_using, random_using, solve::*, Norm, OperationNorm,
+    Scalar,
 };
 
 macro_rules! test_solve {
@@ -280,3 +281,202 @@ fn rcond_identity() {
         rcond_identity!(c32, rows, 1e-3);
     }
 }
+
+#[test]
+fn solve_orthogonal() {
+    macro_rules! solve_orthogonal {
+        ($elem:ty, $rtol:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let q: Array2<$elem> = random_unitary_using(4, &mut rng);
+            let b: Array1<$elem> = random_using(4, &mut rng);
+            let x = q.solve_orthogonal(&b, Some($rtol)).unwrap();
+            assert_close_l2!(&q.dot(&x), &b, $rtol);
+            let qinv = q.inv_orthogonal(None).unwrap();
+            assert_close_l2!(&qinv.dot(&q), &Array2::eye(4), $rtol);
+
+            let a: Array2<$elem> = random_using((4, 4), &mut rng);
+            assert!(!a.is_orthogonal($rtol).unwrap());
+            assert!(a.solve_orthogonal(&b, Some($rtol)).is_err());
+            assert!(a.inv_orthogonal(Some($rtol)).is_err());
+        };
+    }
+    solve_orthogonal!(f64, 1e-9);
+    solve_orthogonal!(f32, 1e-4);
+    solve_orthogonal!(c64, 1e-9);
+    solve_orthogonal!(c32, 1e-4);
+}
+
+#[test]
+fn solve_equilibrated() {
+    macro_rules! solve_equilibrated {
+        ($elem:ty, $rtol:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let a: Array2<$elem> = random_regular_using(4, &mut rng);
+            let scales = [1e-6, 1.0, 1e3, 1e6];
+            // badly scaled rows and columns
+            let a = Array2::from_shape_fn((4, 4), |(i, j)| {
+                a[(i, j)] * <$elem>::real(scales[i] * scales[3 - j])
+            });
+            let x: Array1<$elem> = random_using(4, &mut rng);
+            let b = a.dot(&x);
+            let y = a.solve_equilibrated(&b).unwrap();
+            assert_close_l2!(&y, &x, $rtol);
+            let a_f = a.t().as_standard_layout().reversed_axes().to_owned();
+            let y = a_f.solve_equilibrated(&b).unwrap();
+            assert_close_l2!(&y, &x, $rtol);
+        };
+    }
+    solve_equilibrated!(f64, 1e-7);
+    solve_equilibrated!(c64, 1e-7);
+}
+
+fn test_solve_multi_rhs<T: Scalar + ndarray_linalg::Lapack>(
+    a: &Array2<T>,
+    b: &Array2<T>,
+    rtol: T::Real,
+) {
+    let f = a.factorize().unwrap();
+    let x = a.solve(b).unwrap();
+    let x_t = f.solve_t(b).unwrap();
+    let x_h = f.solve_h(b).unwrap();
+    for (j, col) in b.axis_iter(Axis(1)).enumerate() {
+        let col = col.to_owned();
+        assert_close_l2!(&x.column(j), &f.solve(&col).unwrap(), rtol);
+        assert_close_l2!(&x_t.column(j), &f.solve_t(&col).unwrap(), rtol);
+        assert_close_l2!(&x_h.column(j), &f.solve_h(&col).unwrap(), rtol);
+    }
+    assert_close_l2!(&f.solve_into(b.clone()).unwrap(), &x, rtol);
+    let mut b_ = b.clone();
+    a.solve_inplace(&mut b_).unwrap();
+    assert_close_l2!(&b_, &x, rtol);
+    assert_close_l2!(&a.solve_t_into(b.clone()).unwrap(), &x_t, rtol);
+    let mut b_ = b.clone();
+    a.solve_h_inplace(&mut b_).unwrap();
+    assert_close_l2!(&b_, &x_h, rtol);
+}
+
+#[test]
+fn solve_multi_rhs() {
+    macro_rules! solve_multi_rhs {
+        ($elem:ty, $rtol:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let a: Array2<$elem> = random_regular_using(4, &mut rng);
+            let b: Array2<$elem> = random_using((4, 3), &mut rng);
+            test_solve_multi_rhs(&a, &b, $rtol);
+            let b: Array2<$elem> = random_using((4, 3).f(), &mut rng);
+            test_solve_multi_rhs(&a, &b, $rtol);
+            let a: Array2<$elem> = random_regular_using(4, &mut rng).reversed_axes();
+            test_solve_multi_rhs(&a, &b, $rtol);
+        };
+    }
+    solve_multi_rhs!(f64, 1e-12);
+    solve_multi_rhs!(f32, 1e-5);
+    solve_multi_rhs!(c64, 1e-12);
+    solve_multi_rhs!(c32, 1e-5);
+}
+
+#[should_panic]
+#[test]
+fn solve_multi_rhs_shape_mismatch() {
+    let a: Array2<f64> = Array2::eye(3);
+    let b: Array2<f64> = Array2::zeros((2, 2));
+    let _ = a.solve(&b);
+}
+
+#[test]
+fn solve_refine_hilbert() {
+    macro_rules! solve_refine_hilbert {
+        ($elem:ty, $eps:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let n = 8;
+            let a = Array2::<$elem>::from_shape_fn((n, n), |(i, j)| {
+                <$elem>::from_real(1. / (i as f64 + j as f64 + 1.))
+            });
+            let x_true: Array1<$elem> = random_using(n, &mut rng);
+            let b = a.dot(&x_true);
+            let f = a.factorize().unwrap();
+            let x0 = f.solve(&b).unwrap();
+            let (x1, ferr, berr) = f.solve_refine(&a, &b).unwrap();
+            let residual0 = (&b - &a.dot(&x0)).norm_max();
+            let residual1 = (&b - &a.dot(&x1)).norm_max();
+            assert!(residual1 <= residual0 + $eps * b.norm_max());
+            assert!((0.0..10.0 * $eps).contains(&berr));
+            // the forward error bound is conservative
+            let err = (&x1 - &x_true).norm_max() / x1.norm_max();
+            assert!(ferr >= 0.0 && err <= 10.0 * ferr);
+
+            let (x2, ferr2, berr2) = a.solve_refine(&b).unwrap();
+            assert_close_l2!(&x2, &x1, 1e-12);
+            assert_aclose!(ferr2, ferr, 1e-12);
+            assert_aclose!(berr2, berr, 1e-12);
+
+            // the original matrix may have a different layout from the factors
+            let a_f = a.t().as_standard_layout().reversed_axes().to_owned();
+            let (x3, _, _) = f.solve_refine(&a_f, &b).unwrap();
+            assert_close_l2!(&x3, &x1, 1e-12);
+        };
+    }
+    solve_refine_hilbert!(f64, f64::EPSILON);
+    solve_refine_hilbert!(c64, f64::EPSILON);
+}
+
+#[test]
+fn solve_with_residual() {
+    macro_rules! solve_with_residual {
+        ($elem:ty, $atol:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let a: Array2<$elem> = random_regular_using(4, &mut rng);
+            let b: Array1<$elem> = random_using(4, &mut rng);
+            let (x, r) = a.solve_with_residual(&b).unwrap();
+            assert_close_l2!(&x, &a.solve(&b).unwrap(), 1e-12);
+            assert!(r.norm_max() < $atol);
+
+            let b: Array2<$elem> = random_using((4, 2), &mut rng);
+            let (x, r) = a.solve_with_residual(&b).unwrap();
+            assert_close_l2!(&x, &a.solve(&b).unwrap(), 1e-12);
+            assert!(r.norm_max() < $atol);
+        };
+    }
+    solve_with_residual!(f64, 1e-12);
+    solve_with_residual!(c64, 1e-12);
+}
+
+#[test]
+fn solve_with_residual_singular() {
+    // The second pivot of the LU factorization is exactly zero
+    let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0]];
+    let b: Array1<f64> = array![1.0, 0.0];
+    assert!(matches!(
+        a.solve_with_residual(&b),
+        Err(LinalgError::Lapack(
+            lax::error::Error::LapackComputationalFailure { return_code: 2 }
+        ))
+    ));
+}
+
+#[test]
+fn solve_right() {
+    macro_rules! solve_right {
+        ($elem:ty, $rtol:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let a: Array2<$elem> = random_using((4, 4), &mut rng);
+            let f = a.factorize().unwrap();
+            let b_c: Array2<$elem> = random_using((3, 4), &mut rng);
+            let b_f: Array2<$elem> = random_using((3, 4).f(), &mut rng);
+            for b in [b_c, b_f] {
+                // x A = b <=> A^T x^T = b^T
+                let x_manual = a.t().solve(&b.t()).unwrap().reversed_axes();
+                let x = a.solve_right(&b).unwrap();
+                assert_close_l2!(&x, &x_manual, $rtol);
+                assert_close_l2!(&x.dot(&a), &b, $rtol);
+                assert_close_l2!(&f.solve_right(&b).unwrap(), &x, $rtol);
+                assert_close_l2!(&f.solve_right_into(b.clone()).unwrap(), &x, $rtol);
+            }
+            let b: Array1<$elem> = random_using(4, &mut rng);
+            let x = f.solve_right(&b).unwrap();
+            assert_close_l2!(&x.dot(&a), &b, $rtol);
+        };
+    }
+    solve_right!(f64, 1e-9);
+    solve_right!(c64, 1e-9);
+}
@@ -68,3 +68,92 @@ fn solveh_random_t() {
     let y = f.solveh_into(b).unwrap();
     assert_close_l2!(&x, &y, 1e-7);
 }
+
+macro_rules! solveh_multi_rhs {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<solveh_multi_rhs_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // symmetric/Hermitian indefinite matrix
+                let a: Array2<$elem> = random_hermite_using(4, &mut rng);
+                // row-major, column-major, and non-contiguous right hand sides
+                let b_c: Array2<$elem> = random_using((4, 5), &mut rng);
+                let b_f: Array2<$elem> = random_using((4, 5).f(), &mut rng);
+                let b_s: Array2<$elem> = random_using((4, 10), &mut rng);
+                let f = a.factorizeh().unwrap();
+                for b in [b_c.view(), b_f.view(), b_s.slice(s![.., ..;2])] {
+                    let x = f.solveh(&b).unwrap();
+                    for (j, col) in b.axis_iter(Axis(1)).enumerate() {
+                        let xj = a.solveh(&col).unwrap();
+                        assert_close_l2!(&x.column(j), &xj, $rtol);
+                    }
+                    assert_close_l2!(&a.dot(&x), &b, $rtol);
+                    assert_close_l2!(&f.solveh_into(b.to_owned()).unwrap(), &x, $rtol);
+
+                    let mut y = b.to_owned();
+                    a.solveh_inplace(&mut y).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+                }
+            }
+        }
+    };
+}
+solveh_multi_rhs!(f64, 1e-9);
+solveh_multi_rhs!(c64, 1e-9);
+
+macro_rules! invh_uplo {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<invh_uplo_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(4, &mut rng);
+                let mut a_f: Array2<$elem> = Array2::zeros((4, 4).f());
+                a_f.assign(&a);
+                let eye: Array2<$elem> = Array2::eye(4);
+                for a in [a.view(), a_f.view()] {
+                    assert_close_l2!(&a.invh().unwrap().dot(&a), &eye, $rtol);
+                    for uplo in [UPLO::Upper, UPLO::Lower] {
+                        // overwrite the unused triangle
+                        let mut b = a.to_owned();
+                        for ((i, j), x) in b.indexed_iter_mut() {
+                            if (uplo == UPLO::Upper && i > j) || (uplo == UPLO::Lower && i < j) {
+                                *x = <$elem>::from_real(100.0);
+                            }
+                        }
+                        let inv = b.factorizeh_with(uplo).unwrap().invh_into().unwrap();
+                        assert_close_l2!(&a.dot(&inv), &eye, $rtol);
+                        assert_close_l2!(&inv, &inv.t().mapv(|x| x.conj()), $rtol);
+                    }
+                }
+            }
+        }
+    };
+}
+invh_uplo!(f64, 1e-9);
+invh_uplo!(c64, 1e-9);
+
+#[test]
+fn solveh_into_c_layout_c64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(4, &mut rng);
+    assert!(a.is_standard_layout());
+    // Only the upper triangle is used, as it is displayed
+    let mut upper = a.clone();
+    for ((i, j), x) in upper.indexed_iter_mut() {
+        if i > j {
+            *x = c64::new(100.0, 100.0);
+        }
+    }
+    let b: Array1<c64> = random_using(4, &mut rng);
+    let f = upper.clone().factorizeh_into().unwrap();
+    let x = f.solveh(&b).unwrap();
+    assert_close_l2!(&a.dot(&x), &b, 1e-9);
+    let inv = f.invh_into().unwrap();
+    assert_close_l2!(&a.dot(&inv), &Array2::eye(4), 1e-9);
+    let x = upper.solveh_into(b.clone()).unwrap();
+    assert_close_l2!(&a.dot(&x), &b, 1e-9);
+    let inv = upper.invh_into().unwrap();
+    assert_close_l2!(&a.dot(&inv), &Array2::eye(4), 1e-9);
+}
@@ -0,0 +1,126 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_geometric_mean<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>) {
+    let rtol = T::real(1e-7);
+    let g = spd_geometric_mean(&a.view(), &b.view(), T::real(0.5)).unwrap();
+    let g_rev = spd_geometric_mean(&b.view(), &a.view(), T::real(0.5)).unwrap();
+    assert_close_l2!(&g, &g_rev, rtol);
+    // Riccati equation
+    assert_close_l2!(&g.dot(&a.inv().unwrap()).dot(&g), b, rtol);
+    // Hermitian positive definite
+    let gh: Array2<T> = conjugate(&g);
+    assert_close_l2!(&gh, &g, rtol);
+    assert!(g.cholesky(UPLO::Lower).is_ok());
+
+    let g0 = spd_geometric_mean(&a.view(), &b.view(), T::real(0.0)).unwrap();
+    assert_close_l2!(&g0, a, rtol);
+    let g1 = spd_geometric_mean(&a.view(), &b.view(), T::real(1.0)).unwrap();
+    assert_close_l2!(&g1, b, rtol);
+}
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<spd_geometric_mean_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(4, &mut rng);
+                let b: Array2<$elem> = random_hpd_using(4, &mut rng);
+                test_geometric_mean(&a, &b);
+                test_geometric_mean(&a.reversed_axes(), &b);
+            }
+
+            #[test]
+            fn [<spd_geometric_mean_not_pd_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(3, &mut rng);
+                let b: Array2<$elem> = -&a;
+                assert!(spd_geometric_mean(&a.view(), &b.view(), 0.5).is_err());
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
+
+macro_rules! impl_karcher_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<spd_karcher_mean_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(3, &mut rng);
+                let b: Array2<$elem> = random_hpd_using(3, &mut rng);
+                let c: Array2<$elem> = random_hpd_using(3, &mut rng);
+
+                // Reduces to the geometric mean for two matrices
+                let (x, iter) = spd_karcher_mean(&[a.view(), b.view()], 1e-10, 100).unwrap();
+                assert!(iter < 100);
+                let g = spd_geometric_mean(&a.view(), &b.view(), 0.5).unwrap();
+                assert_close_l2!(&x, &g, 1e-7);
+
+                // Invariant under permutation and congruence
+                let mats = [a.view(), b.view(), c.view()];
+                let (x, iter) = spd_karcher_mean(&mats, 1e-10, 100).unwrap();
+                assert!(iter < 100);
+                let (x_perm, _) = spd_karcher_mean(&[c.view(), a.view(), b.view()], 1e-10, 100).unwrap();
+                assert_close_l2!(&x_perm, &x, 1e-7);
+
+                let w: Array2<$elem> = random_regular_using(3, &mut rng);
+                let wh: Array2<$elem> = conjugate(&w);
+                let congruent: Vec<_> = mats.iter().map(|m| w.dot(m).dot(&wh)).collect();
+                let views: Vec<_> = congruent.iter().map(|m| m.view()).collect();
+                let (xw, _) = spd_karcher_mean(&views, 1e-10, 100).unwrap();
+                assert_close_l2!(&xw, &w.dot(&x).dot(&wh), 1e-7);
+            }
+        }
+    };
+}
+
+impl_karcher_test!(f64);
+impl_karcher_test!(c64);
+
+macro_rules! impl_median_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<spd_geometric_median_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(3, &mut rng);
+                let b: Array2<$elem> = random_hpd_using(3, &mut rng);
+                let c: Array2<$elem> = random_hpd_using(3, &mut rng);
+
+                // Invariant under permutation and congruence
+                let mats = [a.view(), b.view(), c.view()];
+                let x = spd_geometric_median(&mats, 1e-12, 1000).unwrap();
+                let x_perm = spd_geometric_median(&[c.view(), a.view(), b.view()], 1e-12, 1000).unwrap();
+                assert_close_l2!(&x_perm, &x, 1e-7);
+
+                let w: Array2<$elem> = random_regular_using(3, &mut rng);
+                let wh: Array2<$elem> = conjugate(&w);
+                let congruent: Vec<_> = mats.iter().map(|m| w.dot(m).dot(&wh)).collect();
+                let views: Vec<_> = congruent.iter().map(|m| m.view()).collect();
+                let xw = spd_geometric_median(&views, 1e-12, 1000).unwrap();
+                assert_close_l2!(&xw, &w.dot(&x).dot(&wh), 1e-7);
+            }
+
+            #[test]
+            fn [<spd_geometric_median_outlier_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(3, &mut rng);
+                let outlier: Array2<$elem> = random_hpd_using::<$elem, OwnedRepr<_>, _>(3, &mut rng) * 1e3;
+                // The median is the majority, while the mean is moved by the outlier
+                let mats = [a.view(), a.view(), a.view(), outlier.view()];
+                let x = spd_geometric_median(&mats, 1e-12, 1000).unwrap();
+                assert_close_l2!(&x, &a, 1e-7);
+                let (mean, _) = spd_karcher_mean(&mats, 1e-12, 1000).unwrap();
+                assert!((&mean - &a).norm_l2() > 1e-1 * a.norm_l2());
+            }
+        }
+    };
+}
+
+impl_median_test!(f64);
+impl_median_test!(c64);
@@ -0,0 +1,48 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Two disconnected triangles
+fn two_triangles() -> Array2<f64> {
+    let mut w = Array2::zeros((6, 6));
+    for &(i, j) in &[(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5)] {
+        w[(i, j)] = 1.0;
+        w[(j, i)] = 1.0;
+    }
+    w
+}
+
+#[test]
+fn normalized_laplacian_fixed() {
+    let w = array![[0.0, 1.0], [1.0, 0.0]];
+    let l = normalized_laplacian(&w).unwrap();
+    assert_close_l2!(&l, &array![[1.0, -1.0], [-1.0, 1.0]], 1e-12);
+}
+
+#[test]
+fn normalized_laplacian_isolated() {
+    let w = array![[0.0, 2.0, 0.0], [2.0, 0.0, 0.0], [0.0, 0.0, 0.0]];
+    let l = normalized_laplacian(&w).unwrap();
+    assert_close_l2!(
+        &l,
+        &array![[1.0, -1.0, 0.0], [-1.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        1e-12
+    );
+}
+
+#[test]
+fn spectral_embedding_components() {
+    let w = two_triangles();
+    let l = normalized_laplacian(&w).unwrap();
+    let v = spectral_embedding(&w, 2).unwrap();
+    assert_eq!(v.dim(), (6, 2));
+    // The embedding spans the null space of the Laplacian
+    assert!(l.dot(&v).norm_max() < 1e-9);
+    assert_close_l2!(&v.t().dot(&v), &Array2::eye(2), 1e-9);
+}
+
+#[should_panic]
+#[test]
+fn spectral_embedding_too_large() {
+    let w = two_triangles();
+    let _ = spectral_embedding(&w, 7);
+}
@@ -0,0 +1,76 @@
+use ndarray::*;
+use ndarray_linalg::{error::LinalgError, *};
+
+fn test_square<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real)
+where
+    T::Complex: Lapack,
+{
+    let x = a.sqrtm().unwrap();
+    let a_c = a.mapv(|a| a.as_c());
+    assert_close_l2!(&x.dot(&x), &a_c, rtol);
+}
+
+macro_rules! impl_test {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<sqrtm_random_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((4, 4), &mut rng);
+                test_square(&a, 1e-9);
+                test_square(&a.t().to_owned(), 1e-9);
+                let a: Array2<$elem> = random_using((3, 3).f(), &mut rng);
+                test_square(&a, 1e-9);
+            }
+
+            #[test]
+            fn [<sqrtm_diagonalizable_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let v: Array2<$elem> = random_regular_using(3, &mut rng);
+                let v_inv = v.inv().unwrap();
+                let d: Array2<$elem> = from_diag(&[4.0, 9.0, 0.25]).mapv(|x| <$elem>::from_real(x));
+                let d_sqrt: Array2<$elem> = from_diag(&[2.0, 3.0, 0.5]).mapv(|x| <$elem>::from_real(x));
+                let a = v.dot(&d).dot(&v_inv);
+                let ans = v.dot(&d_sqrt).dot(&v_inv).mapv(|x| x.as_c());
+                assert_close_l2!(&a.sqrtm().unwrap(), &ans, 1e-9);
+            }
+
+            #[test]
+            fn [<sqrtm_expm_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // sqrt(exp(B)) = exp(B / 2) for small B
+                let b: Array2<$elem> = random_using((4, 4), &mut rng);
+                let b = b.mapv(|x| x * <$elem>::real(0.5));
+                let a = expm(&b).unwrap();
+                let ans = expm(&b.mapv(|x| x * <$elem>::real(0.5))).unwrap().mapv(|x| x.as_c());
+                assert_close_l2!(&a.sqrtm().unwrap(), &ans, 1e-9);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
+
+#[test]
+fn sqrtm_negative_eigenvalue() {
+    let a: Array2<f64> = array![[-4.0, 0.0], [0.0, 1.0]];
+    let x = a.sqrtm().unwrap();
+    let ans = array![
+        [c64::new(0.0, 2.0), c64::new(0.0, 0.0)],
+        [c64::new(0.0, 0.0), c64::new(1.0, 0.0)]
+    ];
+    assert_close_l2!(&x, &ans, 1e-12);
+    let a: Array2<f64> = array![[1.0, 2.0], [3.0, -4.0]];
+    test_square(&a, 1e-9);
+}
+
+#[test]
+fn sqrtm_singular() {
+    // Zero eigenvalue without Jordan block has the square root
+    let a: Array2<f64> = array![[0.0, 0.0], [0.0, 4.0]];
+    test_square(&a, 1e-12);
+    // Defective zero eigenvalue has no square root
+    let a: Array2<f64> = array![[0.0, 1.0], [0.0, 0.0]];
+    assert!(matches!(a.sqrtm(), Err(LinalgError::NoSquareRoot)));
+}
@@ -0,0 +1,73 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! test_covariance {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<covariance_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let (n, d) = (50, 4);
+                let xs: Array2<$elem> = random_using((n, d), &mut rng);
+                let mut acc = CovarianceAccumulator::new(d);
+                acc.extend(&xs);
+                assert_eq!(acc.count(), n);
+
+                // Batch computation
+                let mean = xs.mean_axis(Axis(0)).unwrap();
+                let centered = &xs - &mean;
+                let ch: Array2<$elem> = conjugate(&centered);
+                let cov = ch.dot(&centered).mapv(|x| x / <$elem>::from_real((n - 1) as f64));
+                assert_close_l2!(acc.mean(), &mean, $rtol);
+                assert_close_l2!(&acc.covariance(), &cov, $rtol);
+
+                let l = acc.cholesky().unwrap();
+                let lh: Array2<$elem> = conjugate(&l);
+                assert_close_l2!(&l.dot(&lh), &cov, $rtol);
+            }
+
+            #[test]
+            fn [<covariance_cholesky_update_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let (n, d) = (30, 3);
+                let xs: Array2<$elem> = random_using((n, d), &mut rng);
+                let mut acc = CovarianceAccumulator::new(d);
+                acc.extend(&xs.slice(s![..10, ..]));
+                // Request the factor in the middle, which is updated afterwards
+                acc.cholesky().unwrap();
+                acc.extend(&xs.slice(s![10.., ..]));
+                let l = acc.cholesky().unwrap();
+                let lh: Array2<$elem> = conjugate(&l);
+                assert_close_l2!(&l.dot(&lh), &acc.covariance(), $rtol);
+                let l_batch = acc.covariance().cholesky(UPLO::Lower).unwrap();
+                assert_close_l2!(&l, &l_batch, $rtol);
+            }
+        }
+    };
+}
+
+test_covariance!(f64, 1e-9);
+test_covariance!(c64, 1e-9);
+
+#[test]
+fn covariance_too_few() {
+    let mut acc = CovarianceAccumulator::<f64>::new(2);
+    assert!(matches!(
+        acc.cholesky(),
+        Err(error::LinalgError::NotEnoughSamples {
+            count: 0,
+            required: 2
+        })
+    ));
+    acc.push(&arr1(&[1.0, 2.0]));
+    assert!(matches!(
+        acc.cholesky(),
+        Err(error::LinalgError::NotEnoughSamples {
+            count: 1,
+            required: 2
+        })
+    ));
+    assert_eq!(acc.mean(), &arr1(&[1.0, 2.0]));
+    acc.clear();
+    assert_eq!(acc.count(), 0);
+}
@@ -0,0 +1,73 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn operator<T: Scalar + Lapack>(u: &Array2<T>, eigs: &[f64]) -> Array2<T> {
+    let d = from_diag(
+        &eigs
+            .iter()
+            .map(|&e| T::from_real(T::real(e)))
+            .collect::<Vec<_>>(),
+    );
+    let uh: Array2<T> = conjugate(u);
+    u.dot(&d).dot(&uh)
+}
+
+fn projector<T: Scalar>(q: &ArrayView2<T>) -> Array2<T> {
+    let qh: Array2<T> = conjugate(q);
+    q.dot(&qh)
+}
+
+fn test_tracker<T: Scalar + Lapack>() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let eigs = [10.0, 5.0, 1.0, 0.1, 0.01];
+    let u: Array2<T> = random_unitary_using(5, &mut rng);
+    let a = operator(&u, &eigs);
+
+    let q0: Array2<T> = random_using((5, 2), &mut rng);
+    let mut tracker = SubspaceTracker::new(q0).unwrap();
+    for _ in 0..60 {
+        tracker.update(|x| a.dot(&x)).unwrap();
+    }
+    let q = tracker.eigenspace();
+    assert_eq!(q.dim(), (5, 2));
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(2), T::real(1e-9));
+    let u2 = u.slice(s![.., ..2]);
+    assert_close_l2!(&projector(&q), &projector(&u2), T::real(1e-7));
+
+    // Follow a slowly rotated operator
+    let mut u_rot = u.clone();
+    for _ in 0..10 {
+        let b: Array2<T> = random_using((5, 5), &mut rng);
+        let mut rot = Array2::<T>::eye(5);
+        rot.scaled_add(T::from_real(T::real(1e-2)), &b);
+        let (rot, _) = rot.qr_into().unwrap();
+        u_rot = rot.dot(&u_rot);
+        let a = operator(&u_rot, &eigs);
+        for _ in 0..20 {
+            tracker.update(|x| a.dot(&x)).unwrap();
+        }
+    }
+    let u2 = u_rot.slice(s![.., ..2]);
+    assert_close_l2!(
+        &projector(&tracker.eigenspace()),
+        &projector(&u2),
+        T::real(1e-7)
+    );
+}
+
+#[test]
+fn subspace_tracker_f64() {
+    test_tracker::<f64>();
+}
+
+#[test]
+fn subspace_tracker_c64() {
+    test_tracker::<c64>();
+}
+
+#[test]
+fn subspace_tracker_too_many_columns() {
+    let q: Array2<f64> = Array2::zeros((2, 3));
+    assert!(SubspaceTracker::new(q).is_err());
+}
@@ -92,3 +92,243 @@ test_svd_impl!(c64, test, 3, 4);
 test_svd_impl!(c64, test_no_vt, 3, 4);
 test_svd_impl!(c64, test_no_u, 3, 4);
 test_svd_impl!(c64, test_diag_only, 3, 4);
+
+fn test_truncate_rank<T: Scalar + Lapack>(a: &Array2<T>, k: usize) {
+    let (_, s, _) = a.svd(false, false).unwrap();
+    let (approx, err) = a.truncate_rank_with_error(k).unwrap();
+    let (_, s_approx, _) = approx.svd(false, false).unwrap();
+    for (i, &s) in s_approx.iter().enumerate().skip(k) {
+        assert!(
+            s < T::real(1e-7) * s_approx[0],
+            "rank is larger than {}: s[{}] = {}",
+            k,
+            i,
+            s
+        );
+    }
+    assert_rclose!(err.spectral, s[k], T::real(1e-9));
+    let diff = a - &approx;
+    assert_rclose!(diff.opnorm_fro().unwrap(), err.frobenius, T::real(1e-7));
+}
+
+macro_rules! test_truncate_rank_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<truncate_rank_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 3), &mut rng);
+                test_truncate_rank(&a, 1);
+                test_truncate_rank(&a, 2);
+                let a: Array2<$type> = random_using((3, 4).f(), &mut rng);
+                test_truncate_rank(&a, 2);
+            }
+        }
+    };
+}
+
+test_truncate_rank_impl!(f64);
+test_truncate_rank_impl!(c64);
+
+#[test]
+fn denoise_lowrank_batch_f64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let stack: Array3<f64> = random_using((3, 5, 4), &mut rng);
+    let out = denoise_lowrank_batch(&stack.view(), 2).unwrap();
+    assert_eq!(out.dim(), stack.dim());
+    for (mat, out) in stack.outer_iter().zip(out.outer_iter()) {
+        assert_close_l2!(&out.to_owned(), &mat.truncate_rank(2).unwrap(), 1e-9);
+    }
+}
+
+#[test]
+fn denoise_lowrank_batch_c64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let stack: Array3<c64> = random_using((2, 3, 4), &mut rng);
+    let out = denoise_lowrank_batch(&stack.view(), 1).unwrap();
+    for (mat, out) in stack.outer_iter().zip(out.outer_iter()) {
+        assert_close_l2!(&out.to_owned(), &mat.truncate_rank(1).unwrap(), 1e-9);
+    }
+}
+
+fn test_pinv_operator<T: Scalar + Lapack>(a: &Array2<T>) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let (n, m) = a.dim();
+    let pinv = a.pinv_operator(None).unwrap();
+    for _ in 0..3 {
+        let y: Array1<T> = random_using(m, &mut rng);
+        let b = a.dot(&y);
+        let x = pinv(b.view());
+        assert_eq!(x.len(), m);
+        // A A^+ A = A and A^+ A A^+ = A^+
+        assert_close_l2!(&a.dot(&x), &b, T::real(1e-9));
+        assert_close_l2!(&pinv(a.dot(&x).view()), &x, T::real(1e-9));
+    }
+    // A^+ b is the minimum norm least squares solution
+    let b: Array1<T> = random_using(n, &mut rng);
+    let x = pinv(b.view());
+    let ah: Array2<T> = conjugate(a);
+    let r = &b - &a.dot(&x);
+    assert!(ah.dot(&r).norm_l2() < T::real(1e-9) * b.norm_l2());
+}
+
+macro_rules! test_pinv_operator_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<pinv_operator_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 3), &mut rng);
+                test_pinv_operator(&a);
+                let a: Array2<$type> = random_using((3, 5).f(), &mut rng);
+                test_pinv_operator(&a);
+                // rank deficient
+                let u: Array2<$type> = random_using((5, 2), &mut rng);
+                let v: Array2<$type> = random_using((2, 4), &mut rng);
+                test_pinv_operator(&u.dot(&v));
+            }
+        }
+    };
+}
+
+test_pinv_operator_impl!(f64);
+test_pinv_operator_impl!(c64);
+
+fn test_pinv<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let ap = a.pinv(None).unwrap();
+    assert_eq!(ap.dim(), (m, n));
+    // Moore-Penrose conditions
+    assert_close_l2!(&a.dot(&ap).dot(a), a, T::real(1e-9));
+    assert_close_l2!(&ap.dot(a).dot(&ap), &ap, T::real(1e-9));
+    let aap = a.dot(&ap);
+    let aap_h: Array2<T> = conjugate(&aap);
+    assert_close_l2!(&aap_h, &aap, T::real(1e-9));
+    let apa = ap.dot(a);
+    let apa_h: Array2<T> = conjugate(&apa);
+    assert_close_l2!(&apa_h, &apa, T::real(1e-9));
+}
+
+macro_rules! test_pinv_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<pinv_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // tall
+                let a: Array2<$type> = random_using((5, 3), &mut rng);
+                test_pinv(&a);
+                // wide
+                let a: Array2<$type> = random_using((3, 5).f(), &mut rng);
+                test_pinv(&a);
+                // rank deficient
+                let u: Array2<$type> = random_using((5, 2), &mut rng);
+                let v: Array2<$type> = random_using((2, 4), &mut rng);
+                test_pinv(&u.dot(&v));
+                // square and regular
+                let a: Array2<$type> = random_regular_using(4, &mut rng);
+                assert_close_l2!(&a.pinv(None).unwrap(), &a.inv().unwrap(), 1e-9);
+            }
+        }
+    };
+}
+
+test_pinv_impl!(f64);
+test_pinv_impl!(c64);
+
+fn test_subspace<T: Scalar + Lapack>(a: &Array2<T>, rank: usize) {
+    let (n, m) = a.dim();
+    let null = a.null_space(None).unwrap();
+    assert_eq!(null.dim(), (m, m - rank));
+    assert_close_l2!(&a.dot(&null), &Array2::zeros((n, m - rank)), T::real(1e-9));
+    let range = a.range(None).unwrap();
+    assert_eq!(range.dim(), (n, rank));
+    let eye: Array2<T> = Array2::eye(rank);
+    assert_close_l2!(
+        &conjugate::<T, _, OwnedRepr<T>>(&range).dot(&range),
+        &eye,
+        T::real(1e-9)
+    );
+    // same projector as the left singular vectors of non-zero singular values
+    let (u, _, _) = a.svd(true, false).unwrap();
+    let u = u.unwrap().slice_move(s![.., ..rank]);
+    let p0 = u.dot(&conjugate::<T, _, OwnedRepr<T>>(&u));
+    let p = range.dot(&conjugate::<T, _, OwnedRepr<T>>(&range));
+    assert_close_l2!(&p, &p0, T::real(1e-9));
+    // A = P A
+    assert_close_l2!(&p.dot(a), a, T::real(1e-9));
+}
+
+macro_rules! test_subspace_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<null_space_range_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // rank deficient
+                let u: Array2<$type> = random_using((5, 2), &mut rng);
+                let v: Array2<$type> = random_using((2, 4), &mut rng);
+                test_subspace(&u.dot(&v), 2);
+                // full column rank, trivial null space
+                let a: Array2<$type> = random_using((5, 3), &mut rng);
+                test_subspace(&a, 3);
+                // wide
+                let a: Array2<$type> = random_using((3, 5).f(), &mut rng);
+                test_subspace(&a, 3);
+                // zero matrix, trivial range
+                test_subspace(&Array2::<$type>::zeros((3, 4)), 0);
+            }
+        }
+    };
+}
+
+test_subspace_impl!(f64);
+test_subspace_impl!(c64);
+
+macro_rules! test_smallest_singular_value_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<smallest_singular_value_est_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &shape in &[(4, 4), (6, 4)] {
+                    let a: Array2<$type> = random_using(shape, &mut rng);
+                    let (_, s, _) = a.svd(false, false).unwrap();
+                    let est = a.smallest_singular_value_est(100, 1e-12).unwrap();
+                    assert_rclose!(est, s[s.len() - 1], 1e-6);
+                }
+                let a: Array2<$type> = random_using((3, 4), &mut rng);
+                assert!(a.smallest_singular_value_est(100, 1e-12).is_err());
+            }
+        }
+    };
+}
+
+test_smallest_singular_value_impl!(f64);
+test_smallest_singular_value_impl!(c64);
+
+macro_rules! test_effective_rank_impl {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<effective_rank_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // Equal singular values give the rank itself
+                let q: Array2<$type> = random_unitary_using(4, &mut rng);
+                assert_rclose!(q.effective_rank().unwrap(), 4.0, 1e-9);
+                let u: Array2<$type> = random_using((5, 2), &mut rng);
+                let v: Array2<$type> = random_using((2, 4), &mut rng);
+                let r = u.dot(&v).effective_rank().unwrap();
+                assert!((1.0 - 1e-9..=2.0 + 1e-9).contains(&r));
+                let a: Array2<$type> = random_using((3, 5), &mut rng);
+                let r = a.effective_rank().unwrap();
+                assert!((1.0..=3.0).contains(&r));
+                let z: Array2<$type> = Array2::zeros((3, 3));
+                assert_eq!(z.effective_rank().unwrap(), 0.0);
+            }
+        }
+    };
+}
+
+test_effective_rank_impl!(f64);
+test_effective_rank_impl!(c64);
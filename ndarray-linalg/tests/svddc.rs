@@ -81,3 +81,106 @@ test_svd_impl!(f64, 3, 4);
 test_svd_impl!(c64, 3, 3);
 test_svd_impl!(c64, 4, 3);
 test_svd_impl!(c64, 3, 4);
+
+fn test_thin<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let k = n.min(m);
+    let (u, s, vt) = a.svd_thin().unwrap();
+    assert_eq!(u.dim(), (n, k));
+    assert_eq!(s.len(), k);
+    assert_eq!(vt.dim(), (k, m));
+    let sm = Array2::from_diag(&s.mapv(T::from_real));
+    assert_close_l2!(&u.dot(&sm).dot(&vt), a, T::real(1e-7));
+    let (u2, s2, vt2) = a.clone().svd_thin_into().unwrap();
+    assert_eq!(u2.dim(), (n, k));
+    assert_eq!(vt2.dim(), (k, m));
+    assert_close_l2!(
+        &s2.mapv(T::from_real),
+        &s.mapv(T::from_real),
+        T::real(1e-12)
+    );
+}
+
+macro_rules! test_svd_thin_impl {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<svd_thin_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // tall
+                let a: Array2<$scalar> = random_using((20, 3), &mut rng);
+                test_thin(&a);
+                let a: Array2<$scalar> = random_using((20, 3).f(), &mut rng);
+                test_thin(&a);
+                // wide
+                let a: Array2<$scalar> = random_using((3, 20), &mut rng);
+                test_thin(&a);
+                let a: Array2<$scalar> = random_using((3, 20).f(), &mut rng);
+                test_thin(&a);
+            }
+        }
+    };
+}
+
+test_svd_thin_impl!(f64);
+test_svd_thin_impl!(c64);
+
+fn test_flags<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let k = n.min(m);
+    let (_, s0, _) = a.svd_thin().unwrap();
+    let s0 = s0.mapv(T::from_real);
+
+    let (u, s, vt) = a.svddc_flags(true, false).unwrap();
+    assert!(vt.is_none());
+    let u = u.unwrap();
+    assert_eq!(u.dim(), (n, k));
+    assert_close_l2!(&s.mapv(T::from_real), &s0, T::real(1e-9));
+    // U^H A = Σ V^H has the same row norms as the singular values
+    let uh: Array2<T> = conjugate(&u);
+    let sv = uh.dot(a);
+    let norms: Array1<T> = sv.outer_iter().map(|r| T::from_real(r.norm_l2())).collect();
+    assert_close_l2!(&norms, &s0, T::real(1e-9));
+    assert_close_l2!(&u.dot(&sv), a, T::real(1e-9));
+
+    let (u, s, vt) = a.svddc_flags(false, true).unwrap();
+    assert!(u.is_none());
+    let vt = vt.unwrap();
+    assert_eq!(vt.dim(), (k, m));
+    assert_close_l2!(&s.mapv(T::from_real), &s0, T::real(1e-9));
+    // A V = U Σ has the same column norms as the singular values
+    let v: Array2<T> = conjugate(&vt);
+    let us = a.dot(&v);
+    let norms: Array1<T> = us
+        .axis_iter(Axis(1))
+        .map(|c| T::from_real(c.norm_l2()))
+        .collect();
+    assert_close_l2!(&norms, &s0, T::real(1e-9));
+    assert_close_l2!(&us.dot(&vt), a, T::real(1e-9));
+
+    let (u, _, vt) = a.clone().svddc_flags_into(true, true).unwrap();
+    assert_eq!(u.unwrap().dim(), (n, k));
+    assert_eq!(vt.unwrap().dim(), (k, m));
+    let (u, _, vt) = a.svddc_flags(false, false).unwrap();
+    assert!(u.is_none() && vt.is_none());
+}
+
+macro_rules! test_svddc_flags_impl {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<svddc_flags_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for shape in [(6, 3), (3, 6), (4, 4)] {
+                    let a: Array2<$scalar> = random_using(shape, &mut rng);
+                    test_flags(&a);
+                    let a: Array2<$scalar> = random_using(shape.f(), &mut rng);
+                    test_flags(&a);
+                }
+            }
+        }
+    };
+}
+
+test_svddc_flags_impl!(f64);
+test_svddc_flags_impl!(c64);
@@ -7,3 +7,42 @@ fn trace() {
     let a: Array2<f64> = random_using((3, 3), &mut rng);
     assert_rclose!(a.trace().unwrap(), a[(0, 0)] + a[(1, 1)] + a[(2, 2)], 1e-7);
 }
+
+macro_rules! test_trace_product {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<trace_product_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((3, 5), &mut rng);
+                let b: Array2<$elem> = random_using((5, 3), &mut rng);
+                let expected: $elem = a.dot(&b).diag().sum();
+                assert_rclose!(trace_product(&a, &b).unwrap(), expected, $rtol);
+                // tr(AB) = tr(BA)
+                assert_rclose!(trace_product(&b, &a).unwrap(), expected, $rtol);
+                // non-standard layout
+                let bt = b.t().to_owned();
+                assert_rclose!(trace_product(&a, &bt.t()).unwrap(), expected, $rtol);
+            }
+        }
+    };
+}
+
+test_trace_product!(f64, 1e-9);
+test_trace_product!(c64, 1e-9);
+
+#[test]
+fn trace_product_shape_mismatch() {
+    let a: Array2<f64> = Array2::zeros((3, 5));
+    let b: Array2<f64> = Array2::zeros((3, 5));
+    assert!(trace_product(&a, &b).is_err());
+}
+
+#[test]
+fn trace_product_outer() {
+    // The full product would be a 100000 x 100000 matrix
+    let n = 100_000;
+    let a: Array2<f64> = Array2::ones((n, 1));
+    let b: Array2<f64> = Array2::from_elem((1, n), 2.0);
+    assert_rclose!(trace_product(&a, &b).unwrap(), 2.0 * n as f64, 1e-12);
+}
@@ -130,3 +130,85 @@ fn triangular_2d_upper_t_bt() {
     let a: Array2<f64> = random_using((3, 3).f(), &mut rng).into_triangular(UPLO::Upper);
     test2d(UPLO::Upper, &a, &b, 1e-7);
 }
+
+#[test]
+fn is_triangular() {
+    let a = array![[1.0, 2.0], [0.0, 3.0]];
+    assert!(a.is_upper_triangular());
+    assert!(!a.is_lower_triangular());
+    assert!(a.t().is_lower_triangular());
+    let d: Array2<f64> = Array2::eye(3);
+    assert!(d.is_upper_triangular() && d.is_lower_triangular());
+}
+
+#[test]
+fn solve_auto_triangular() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((3, 3), &mut rng);
+    let b: Array1<f64> = random_using(3, &mut rng);
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let a = a.clone().into_triangular(uplo);
+        let ans = a.solve(&b).unwrap();
+        let x = a.solve_triangular_if(&b).unwrap().unwrap();
+        assert_close_l2!(&x, &ans, 1e-7);
+        let x = a.solve_auto(&b).unwrap();
+        assert_close_l2!(&x, &ans, 1e-7);
+    }
+}
+
+#[test]
+fn solve_auto_general() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_regular_using(3, &mut rng);
+    let b: Array1<f64> = random_using(3, &mut rng);
+    assert!(a.solve_triangular_if(&b).unwrap().is_none());
+    assert_close_l2!(&a.solve_auto(&b).unwrap(), &a.solve(&b).unwrap(), 1e-7);
+}
+
+#[test]
+fn solve_triangular_side() {
+    macro_rules! solve_triangular_side {
+        ($elem:ty, $rtol:expr) => {
+            let n = 4;
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let a: Array2<$elem> = random_using((n, n), &mut rng);
+            // keep away from singular
+            let a = a + &Array2::<$elem>::eye(n).mapv(|x| x * <$elem>::from_real(4.0));
+            let b_left: Array2<$elem> = random_using((n, 2), &mut rng);
+            let b_right: Array2<$elem> = random_using((2, n), &mut rng);
+            for uplo in [UPLO::Upper, UPLO::Lower] {
+                let a = a.clone().into_triangular(uplo);
+                for trans in [Transpose::No, Transpose::Transpose, Transpose::Hermite] {
+                    let op_a: Array2<$elem> = match trans {
+                        Transpose::No => a.clone(),
+                        Transpose::Transpose => a.t().to_owned(),
+                        Transpose::Hermite => a.t().mapv(|x| x.conj()),
+                    };
+                    let op_a_inv = op_a.inv().unwrap();
+
+                    let mut a_f = Array2::zeros((n, n).f());
+                    a_f.assign(&a);
+                    for a in [a.view(), a_f.view()] {
+                        let x = a
+                            .solve_triangular_side(Side::Left, uplo, Diag::NonUnit, trans, &b_left)
+                            .unwrap();
+                        assert_close_l2!(&x, &op_a_inv.dot(&b_left), $rtol);
+
+                        let x = a
+                            .solve_triangular_side(
+                                Side::Right,
+                                uplo,
+                                Diag::NonUnit,
+                                trans,
+                                &b_right,
+                            )
+                            .unwrap();
+                        assert_close_l2!(&x, &b_right.dot(&op_a_inv), $rtol);
+                    }
+                }
+            }
+        };
+    }
+    solve_triangular_side!(f64, 1e-9);
+    solve_triangular_side!(c64, 1e-9);
+}
@@ -42,6 +42,24 @@ fn opnorm_tridiagonal() {
     assert_aclose!(a.opnorm_fro().unwrap(), t.opnorm_fro().unwrap(), 1e-7);
 }
 
+#[test]
+fn opnorm_spectral_tridiagonal() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<f64> = random_using((4, 4), &mut rng);
+    a[[0, 2]] = 0.0;
+    a[[0, 3]] = 0.0;
+    a[[1, 3]] = 0.0;
+    a[[2, 0]] = 0.0;
+    a[[3, 0]] = 0.0;
+    a[[3, 1]] = 0.0;
+    let t = a.extract_tridiagonal().unwrap();
+    assert_aclose!(
+        a.opnorm_spectral().unwrap(),
+        t.opnorm_spectral().unwrap(),
+        1e-7
+    );
+}
+
 #[test]
 fn solve_tridiagonal_f64() {
     // https://www.nag-j.co.jp/lapack/dgttrs.htm
@@ -0,0 +1,37 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test<T: Scalar + Lapack>(a: &Array2<T>, uplo: UPLO, rtol: T::Real) {
+    let (q, t) = a.tridiagonalize(uplo).unwrap();
+    let t = t.mapv(T::from_real);
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(a.nrows()), rtol);
+    assert_close_l2!(&q.dot(&t).dot(&qh), a, rtol);
+    for ((i, j), &t) in t.indexed_iter() {
+        if i.abs_diff(j) > 1 {
+            assert_eq!(t, T::zero());
+        }
+    }
+}
+
+macro_rules! impl_test {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<tridiagonalize_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(4, &mut rng);
+                test(&a, UPLO::Upper, $rtol);
+                test(&a, UPLO::Lower, $rtol);
+                let a = a.reversed_axes();
+                test(&a, UPLO::Upper, $rtol);
+                test(&a, UPLO::Lower, $rtol);
+            }
+        }
+    };
+}
+
+impl_test!(f64, 1e-9);
+impl_test!(f32, 1e-4);
+impl_test!(c64, 1e-9);
+impl_test!(c32, 1e-4);
@@ -0,0 +1,36 @@
+use ndarray::*;
+use ndarray_linalg::{error::LinalgError, *};
+
+#[test]
+fn finite() {
+    let a: Array2<f64> = array![[1.0, 2.0], [3.0, 4.0]];
+    assert!(a.validate_finite().is_ok());
+}
+
+#[test]
+fn nan() {
+    let a: Array2<f64> = array![[1.0, 2.0], [f64::NAN, 4.0]];
+    match a.validate_finite() {
+        Err(LinalgError::InvalidInput { index }) => assert_eq!(index, vec![1, 0]),
+        _ => panic!("NaN must be detected"),
+    }
+}
+
+#[test]
+fn complex_inf() {
+    let a: Array1<c64> = array![c64::new(1.0, 0.0), c64::new(0.0, f64::INFINITY)];
+    match a.validate_finite() {
+        Err(LinalgError::InvalidInput { index }) => assert_eq!(index, vec![1]),
+        _ => panic!("Infinity must be detected"),
+    }
+}
+
+#[cfg(feature = "strict-input")]
+#[test]
+fn strict_input_cholesky() {
+    let a: Array2<f64> = array![[1.0, f64::INFINITY], [0.0, 1.0]];
+    match a.cholesky(UPLO::Upper) {
+        Err(LinalgError::InvalidInput { index }) => assert_eq!(index, vec![0, 1]),
+        _ => panic!("Infinity must be detected"),
+    }
+}